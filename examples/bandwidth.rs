@@ -32,19 +32,19 @@ impl Iterator for Generator {
 }
 
 // Returns MB/s
-fn encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
+fn encoder_bandwidth<const ECC_LEN: usize>(data_len: usize) -> f32 {
      // Measure encoding bandwidth
     let (tx, thr_rx) = mpsc::channel();
     let (thr_tx, rx) = mpsc::channel();
     
     thread::spawn(move || {
         let generator = Generator::new();
-        let mut encoder = Encoder::<33>::new(ecc_len);
+        let mut encoder = Encoder::<ECC_LEN>::new();
 
         let buffer: Vec<u8> = generator.take(data_len).collect();
         let mut bytes = 0;
         while thr_rx.try_recv().is_err() {
-            encoder.encode(&buffer);
+            encoder.encode(&buffer).unwrap();
             bytes += data_len;
         }
 
@@ -60,18 +60,18 @@ fn encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
 }
 
 // Returns MB/s
-fn decoder_bandwidth(data_len: usize, ecc_len: usize, errors: usize) -> f32 {
+fn decoder_bandwidth<const ECC_LEN: usize>(data_len: usize, errors: usize) -> f32 {
      // Measure decoder bandwidth
     let (tx, thr_rx) = mpsc::channel();
     let (thr_tx, rx) = mpsc::channel();
     
     thread::spawn(move || {
         let generator = Generator::new();
-        let mut encoder = Encoder::<33>::new(ecc_len);
-        let decoder = Decoder::new(ecc_len);
+        let mut encoder = Encoder::<ECC_LEN>::new();
+        let decoder = Decoder::new(ECC_LEN);
 
         let buffer: Vec<u8> = generator.take(data_len).collect();
-        let mut encoded = encoder.encode(&buffer);
+        let encoded = encoder.encode(&buffer).unwrap();
         let mut message = buffer.clone();
         message.extend_from_slice(&encoded[..]);
         for x in message.iter_mut().take(errors) {
@@ -81,7 +81,7 @@ fn decoder_bandwidth(data_len: usize, ecc_len: usize, errors: usize) -> f32 {
         let mut bytes = 0;
         while thr_rx.try_recv().is_err() {
             if decoder.is_corrupted(&message) {
-                decoder.correct(&mut message, None).unwrap();
+                decoder.correct(&message, None).unwrap();
             }            
             bytes += data_len;
         }
@@ -122,14 +122,22 @@ fn main() {
         let ecc_len = case.1;
 
         BenchResult {
-            data_len: data_len,
-            ecc_len: ecc_len,
+            data_len,
+            ecc_len,
             encoder: EncoderResult {
-                bandwidth: encoder_bandwidth(data_len, ecc_len),
+                bandwidth: match ecc_len {
+                    4 => encoder_bandwidth::<4>(data_len),
+                    16 => encoder_bandwidth::<16>(data_len),
+                    _ => encoder_bandwidth::<32>(data_len),
+                },
             },
             decoder: (0..(ecc_len / 2) + 1).map(|e| DecoderResult {
                 errors: e,
-                bandwidth: decoder_bandwidth(data_len, ecc_len, e)
+                bandwidth: match ecc_len {
+                    4 => decoder_bandwidth::<4>(data_len, e),
+                    16 => decoder_bandwidth::<16>(data_len, e),
+                    _ => decoder_bandwidth::<32>(data_len, e),
+                }
             }).collect()
         }
     }).collect();
@@ -10,11 +10,11 @@ fn main() {
     let ecc_len = 8;
 
     // Create encoder and decoder with 
-    let mut enc = Encoder::<9>::new(ecc_len);
+    let mut enc = Encoder::<8>::new();
     let dec = Decoder::new(ecc_len);
 
     // Encode data
-    let encoded = enc.encode(&data[..]);
+    let encoded = enc.encode(&data[..]).unwrap();
 
     // Simulate some transmission errors
     let mut corrupted = Vec::new();
@@ -26,7 +26,7 @@ fn main() {
 
     // Try to recover data
     let known_erasures = [0];
-    let recovered = dec.correct(&mut corrupted, Some(&known_erasures)).unwrap();
+    let recovered = dec.correct(&corrupted, Some(&known_erasures)).unwrap();
 
     let orig_str = std::str::from_utf8(data).unwrap();
     let recv_str = std::str::from_utf8(recovered.data()).unwrap();
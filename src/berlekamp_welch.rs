@@ -0,0 +1,224 @@
+//! Berlekamp-Welch decoder.
+//!
+//! Encoded block is viewed as a generalized Reed-Solomon code: byte at position `p` of an
+//! `n` bytes long block equals `v(b) * f(b)` for `b = 2^-(n - 1 - p)`, a fixed column multiplier
+//! `v` and a message polynomial `f` of degree less than data length. Errors are then found by
+//! solving a linear system instead of the key equation.
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::buffer::Buffer;
+use crate::decoder::DecoderError;
+use crate::gf;
+use crate::gf::poly_math::*;
+use crate::gf::poly::Polynom;
+
+type Result<T> = core::result::Result<T, DecoderError>;
+
+/// Reed-Solomon decoder based on the Berlekamp-Welch algorithm.
+///
+/// It's considerably slower than `Decoder` and needs heap memory, but follows textbook
+/// linear algebra formulation, which is useful for teaching and for cross-checking results.
+#[derive(Debug, Copy, Clone)]
+pub struct BerlekampWelchDecoder {
+    ecc_len: usize,
+}
+
+impl BerlekampWelchDecoder {
+    /// Constructs a new `BerlekampWelchDecoder`.
+    pub fn new(ecc_len: usize) -> Self {
+        BerlekampWelchDecoder { ecc_len }
+    }
+
+    /// Decodes block-encoded message and returns `Buffer` with corrected message and ecc offset.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::BerlekampWelchDecoder;
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = BerlekampWelchDecoder::new(4);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[2] = 1;
+    /// message[3] = 2;
+    ///
+    /// let corrected = decoder.correct(&message, None).unwrap();
+    /// assert_eq!(&[1, 2, 3, 4], corrected.data())
+    /// ```
+    pub fn correct(&self, msg: &[u8], erase_pos: Option<&[u8]>) -> Result<Buffer> {
+        let n = msg.len();
+        if n > 255 {
+            return Err(DecoderError::MessageTooLong);
+        }
+
+        let data_len = n.checked_sub(self.ecc_len).ok_or(DecoderError::TooManyErrors)?;
+        let erase_pos = erase_pos.unwrap_or(&[]);
+
+        let point = |p: usize| evaluation_point(n, p);
+        let multiplier = |b: u8| column_multiplier(n, self.ecc_len, b);
+
+        // Erased bytes are simply left out of the system
+        let known: Vec<(usize, u8, u8)> = (0..n)
+            .filter(|p| !erase_pos.contains(&(*p as u8)))
+            .map(|p| {
+                let b = point(p);
+                (p, b, gf::div(msg[p], multiplier(b)))
+            })
+            .collect();
+
+        if known.len() < data_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+        let e = (known.len() - data_len) / 2;
+
+        // Q(b) = y * E(b) for every known point, where E(x) = x^e + e_{e-1} x^{e-1} + ... + e_0
+        // is the error locator. Unknowns are coefficients of Q followed by e_0..e_{e-1}.
+        let cols = 2 * e + data_len;
+        let mut system: Vec<Vec<u8>> = known.iter()
+            .map(|(_, b, y)| {
+                let mut row = vec![0; cols + 1];
+                let mut bj = 1;
+                for j in 0..(e + data_len) {
+                    row[j] = bj;
+                    if j < e {
+                        row[e + data_len + j] = gf::mul(*y, bj);
+                    }
+                    bj = gf::mul(bj, *b);
+                }
+                row[cols] = gf::mul(*y, gf::pow(*b, e as i32));
+                row
+            })
+            .collect();
+
+        let solution = solve(&mut system, cols).ok_or(DecoderError::TooManyErrors)?;
+
+        // f = Q / E, both converted to highest power first
+        let mut q: Polynom = Polynom::from(&solution[..e + data_len]).reverse();
+        let mut err_loc: Polynom = Polynom::from(&solution[e + data_len..]);
+        err_loc.push(1);
+        let err_loc = err_loc.reverse();
+
+        if q.is_empty() {
+            q.push(0);
+        }
+        let (f, remainder) = q.div(&err_loc);
+        if remainder.iter().any(|x| *x != 0) {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        let mut corrected: Polynom = Polynom::with_length(n);
+        for (p, x) in corrected.iter_mut().enumerate() {
+            let b = point(p);
+            *x = gf::mul(multiplier(b), f.eval(b));
+        }
+
+        let errors = known.iter().filter(|(p, _, _)| corrected[*p] != msg[*p]).count();
+        if errors > e {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        Ok(Buffer::from_polynom(corrected, data_len))
+    }
+}
+
+/// Evaluation point of position `p` in an `n` bytes long block.
+pub(crate) fn evaluation_point(n: usize, p: usize) -> u8 {
+    gf::pow(2, -((n - 1 - p) as i32))
+}
+
+/// Column multiplier `v(b)` of an `n` bytes long block with `ecc_len` ecc bytes.
+pub(crate) fn column_multiplier(n: usize, ecc_len: usize, b: u8) -> u8 {
+    // Positions a shortened block doesn't have are zeros of f
+    let mut v = gf::pow(b, ecc_len as i32);
+    for u in n..255 {
+        v = gf::mul(v, b ^ gf::pow(2, -(u as i32)));
+    }
+    v
+}
+
+/// Gaussian elimination of an augmented matrix with `cols` unknowns,
+/// free unknowns of an underdetermined system are set to zero.
+fn solve(system: &mut [Vec<u8>], cols: usize) -> Option<Vec<u8>> {
+    let mut pivots = Vec::new();
+
+    for col in 0..cols {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..system.len()).find(|r| system[*r][col] != 0) else {
+            continue;
+        };
+        system.swap(rank, pivot);
+
+        let inv = gf::inverse(system[rank][col]);
+        system[rank].scale_assign(inv);
+
+        let pivot_row = system[rank].clone();
+        for (r, row) in system.iter_mut().enumerate() {
+            let coef = row[col];
+            if r != rank && coef != 0 {
+                for (x, p) in row[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                    *x ^= gf::mul(*p, coef);
+                }
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    // Inconsistent system
+    if system[pivots.len()..].iter().any(|row| row[cols] != 0) {
+        return None;
+    }
+
+    let mut solution = vec![0; cols];
+    for (row, col) in pivots.iter().enumerate() {
+        solution[*col] = system[row][cols];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decoder, Encoder};
+
+    #[test]
+    fn correct() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        let decoder = BerlekampWelchDecoder::new(8);
+        assert_eq!(orig, decoder.correct(&orig, None).unwrap()[..]);
+
+        for errors in 0..6 {
+            for erasures in 0..4 {
+                let mut corrupted = orig.clone();
+                let erase_pos: Vec<u8> = (0..erasures as u8).map(|x| x * 3 + 2).collect();
+                for x in erase_pos.iter() {
+                    corrupted[*x as usize] = 0;
+                }
+                for i in 0..errors {
+                    corrupted[17 - i * 2] ^= 0xa5;
+                }
+
+                let result = decoder.correct(&corrupted, Some(&erase_pos));
+                if 2 * errors + erasures <= 8 {
+                    assert_eq!(orig, result.unwrap()[..]);
+                    assert_eq!(orig, Decoder::new(8).correct(&corrupted, Some(&erase_pos)).unwrap()[..]);
+                }
+            }
+        }
+
+        assert_eq!(Some(DecoderError::MessageTooLong), decoder.correct(&[0; 256], None).err());
+        assert_eq!(Some(DecoderError::TooManyErrors), decoder.correct(&[0; 7], None).err());
+    }
+}
@@ -0,0 +1,225 @@
+use core::slice::Chunks;
+use crate::buffer::Buffer;
+use crate::encoder::Encoder;
+#[cfg(feature = "std")]
+use crate::encoder::EncoderError;
+use crate::gf::{GaloisField, Gf2p8};
+
+/// Encoder for messages of any length, split into codewords of up to `255 - ECC_LEN` data bytes.
+///
+/// The last codeword is shortened to the remaining data. [`Decoder::decode_blocks`](crate::Decoder::decode_blocks)
+/// reassembles the message.
+#[derive(Debug)]
+pub struct BlockEncoder<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    encoder: Encoder<ECC_LEN, F>,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> BlockEncoder<ECC_LEN, F> {
+    /// Data bytes of a full codeword
+    pub const DATA_LEN: usize = F::ORDER - ECC_LEN;
+
+    /// Constructs a new `BlockEncoder`.
+    ///
+    /// # Panics
+    /// If `ECC_LEN` leaves no room for data in a codeword.
+    pub fn new() -> Self {
+        assert!(ECC_LEN < F::ORDER, "no room for data in a codeword");
+        Self {
+            encoder: Encoder::new(),
+        }
+    }
+
+    /// Returns an iterator over the codewords of `data`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{BlockEncoder, Decoder};
+    ///
+    /// let data = [7; 600];
+    /// let encoder = BlockEncoder::<8>::new();
+    ///
+    /// let mut encoded = Vec::new();
+    /// for codeword in encoder.encode(&data) {
+    ///     encoded.extend_from_slice(&codeword);
+    /// }
+    /// assert_eq!(encoder.encoded_len(600), encoded.len());
+    ///
+    /// encoded[0] = 0;
+    /// encoded[300] = 0;
+    ///
+    /// let mut decoded = [0; 600];
+    /// let len = Decoder::new(8).decode_blocks(&encoded, &mut decoded).unwrap();
+    /// assert_eq!(data[..], decoded[..len]);
+    /// ```
+    pub fn encode<'a>(&'a self, data: &'a [u8]) -> Codewords<'a, ECC_LEN, F> {
+        Codewords {
+            encoder: &self.encoder,
+            chunks: data.chunks(Self::DATA_LEN),
+        }
+    }
+
+    /// Length of `data_len` bytes once encoded
+    pub fn encoded_len(&self, data_len: usize) -> usize {
+        data_len + data_len.div_ceil(Self::DATA_LEN) * ECC_LEN
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8> + Sync> BlockEncoder<ECC_LEN, F> {
+    /// Encodes `data` into `out` like [`encode`](Self::encode), spreading the codewords over
+    /// all available cores. `out` must be exactly [`encoded_len`](Self::encoded_len) bytes long.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{BlockEncoder, BlockLayout};
+    ///
+    /// let data = [7; 1000];
+    /// let encoder = BlockEncoder::<8>::new();
+    ///
+    /// let mut encoded = vec![0; encoder.encoded_len(data.len())];
+    /// encoder.encode_parallel(&data, BlockLayout::Sequential, &mut encoded).unwrap();
+    ///
+    /// let expected: Vec<u8> = encoder.encode(&data).flat_map(|codeword| codeword.to_vec()).collect();
+    /// assert_eq!(expected, encoded);
+    /// ```
+    pub fn encode_parallel(&self, data: &[u8], layout: BlockLayout, out: &mut [u8]) -> Result<(), EncoderError> {
+        if out.len() != self.encoded_len(data.len()) {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+
+        match layout {
+            BlockLayout::Sequential => self.encode_threads(data, out),
+            BlockLayout::Interleaved => {
+                let mut sequential = std::vec![0; out.len()];
+                self.encode_threads(data, &mut sequential);
+                interleave(&sequential, F::ORDER, out);
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_threads(&self, data: &[u8], out: &mut [u8]) {
+        let blocks = data.len().div_ceil(Self::DATA_LEN);
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let per_thread = blocks.div_ceil(threads).max(1);
+
+        std::thread::scope(|scope| {
+            let data_chunks = data.chunks(per_thread * Self::DATA_LEN);
+            let out_chunks = out.chunks_mut(per_thread * F::ORDER);
+            for (data, out) in data_chunks.zip(out_chunks) {
+                scope.spawn(move || {
+                    let data_chunks = data.chunks(Self::DATA_LEN);
+                    let out_chunks = out.chunks_mut(F::ORDER);
+                    for (data, codeword) in data_chunks.zip(out_chunks) {
+                        let (data_out, ecc) = codeword.split_at_mut(data.len());
+                        data_out.copy_from_slice(data);
+                        unwrap_unchecked!(self.encoder.encode_to_slice(data, ecc));
+                    }
+                });
+            }
+        });
+    }
+}
+
+/// Arrangement of codewords produced by [`BlockEncoder::encode_parallel`]
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum BlockLayout {
+    /// Codewords one after another
+    #[default]
+    Sequential,
+    /// Byte `i` of every codeword before byte `i + 1` of any, so that a burst error is spread
+    /// over several codewords. The shortened last codeword is left out once it runs out of bytes.
+    Interleaved,
+}
+
+/// Rearranges codewords of `codeword_len` bytes laid out one after another into `out` by
+/// columns, the last one may be shorter
+#[cfg(feature = "std")]
+fn interleave(sequential: &[u8], codeword_len: usize, out: &mut [u8]) {
+    let codewords: std::vec::Vec<&[u8]> = sequential.chunks(codeword_len).collect();
+    let mut out = out.iter_mut();
+    for i in 0..codeword_len {
+        for codeword in codewords.iter().filter(|codeword| i < codeword.len()) {
+            *unwrap_unchecked!(out.next()) = codeword[i];
+        }
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Default for BlockEncoder<ECC_LEN, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator returned by [`BlockEncoder::encode`]
+#[derive(Debug)]
+pub struct Codewords<'a, const ECC_LEN: usize, F: GaloisField<Elem = u8>> {
+    encoder: &'a Encoder<ECC_LEN, F>,
+    chunks: Chunks<'a, u8>,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Iterator for Codewords<'_, ECC_LEN, F> {
+    type Item = Buffer;
+
+    fn next(&mut self) -> Option<Buffer> {
+        let chunk = self.chunks.next()?;
+        Some(unwrap_unchecked!(self.encoder.encode_buffer(chunk)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+    use super::BlockEncoder;
+
+    #[test]
+    fn encode() {
+        crate::gf::init_test_tables();
+        let data: [u8; 502] = core::array::from_fn(|i| (i * 3) as u8);
+        let encoder = BlockEncoder::<4>::new();
+        let mut single = crate::Encoder::<4>::new();
+
+        let codewords: Vec<_> = encoder.encode(&data).collect();
+        assert_eq!(2, codewords.len());
+        assert_eq!(&data[..251], codewords[0].data());
+        assert_eq!(single.encode(&data[..251]).unwrap()[..], *codewords[0].ecc());
+        assert_eq!(&data[251..], codewords[1].data());
+        assert_eq!(single.encode(&data[251..]).unwrap()[..], *codewords[1].ecc());
+
+        assert_eq!(0, encoder.encode(&[]).count());
+        assert_eq!(0, encoder.encoded_len(0));
+        assert_eq!(255, encoder.encoded_len(251));
+        assert_eq!(260, encoder.encoded_len(252));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_parallel() {
+        use super::BlockLayout;
+        crate::gf::init_test_tables();
+
+        let data: Vec<u8> = (0..5000).map(|i| (i * 7) as u8).collect();
+        let encoder = BlockEncoder::<16>::new();
+        let sequential: Vec<u8> = encoder.encode(&data).flat_map(|codeword| codeword.to_vec()).collect();
+
+        let mut out = std::vec![0; encoder.encoded_len(data.len())];
+        encoder.encode_parallel(&data, BlockLayout::Sequential, &mut out).unwrap();
+        assert_eq!(sequential, out);
+
+        encoder.encode_parallel(&data, BlockLayout::Interleaved, &mut out).unwrap();
+        let codewords: Vec<&[u8]> = sequential.chunks(255).collect();
+        assert_eq!(21, codewords.len());
+        assert_eq!(&[codewords[0][0], codewords[1][0], codewords[2][0]], &out[..3]);
+        assert_eq!(codewords[0][1], out[21]);
+        assert_eq!(codewords[19].last(), out.last());
+
+        assert_eq!(Err(crate::EncoderError::BufferLenMismatch), encoder.encode_parallel(&data, BlockLayout::Sequential, &mut out[1..]));
+    }
+}
@@ -12,6 +12,7 @@ use core::ops::{Deref, DerefMut};
 /// assert_eq!(&[3, 4], buffer.ecc());
 /// ```
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     poly: Polynom,
     data_len: usize,
@@ -21,8 +22,8 @@ impl Buffer {
     /// Create buffer from internal polynom
     pub fn from_polynom(poly: Polynom, data_len: usize) -> Self {
         Buffer {
-            poly: poly,
-            data_len: data_len,
+            poly,
+            data_len,
         }
     }
 
@@ -30,7 +31,7 @@ impl Buffer {
     pub fn from_slice(slice: &[u8], data_len: usize) -> Self {
         Buffer {
             poly: Polynom::from(slice),
-            data_len: data_len,
+            data_len,
         }
     }
 
@@ -0,0 +1,427 @@
+//! CCSDS (TM Synchronization and Channel Coding, 131.0-B) Reed-Solomon code.
+//!
+//! The code works over GF(2^8) generated by 0x187 with roots `2^(11 * j)` for
+//! `j = 128 - E .. 128 + E`, where `E = ECC_LEN / 2`. Symbols on the link are in
+//! Berlekamp's dual basis rather than the conventional polynomial basis; [`to_dual_basis`]
+//! and [`from_dual_basis`] convert between the two.
+use heapless::Vec;
+use crate::encoder::EncoderError;
+use crate::gf::Gf2p8;
+
+/// Field used by CCSDS
+pub type Field = Gf2p8<0x187>;
+
+/// Power of the primitive element between consecutive generator roots
+pub const PRIM: u8 = 11;
+
+/// First consecutive root of the generator polynomial for `ecc_len` parity bytes,
+/// 112 for the usual `ecc_len = 32`
+pub const fn fcr(ecc_len: usize) -> u8 {
+    (128 - ecc_len / 2) as u8
+}
+
+/// Rows of the conventional to dual basis transform, from the least significant input bit
+const TAL: [u8; 8] = [0x7b, 0xaf, 0x99, 0xfa, 0x86, 0xec, 0xef, 0x8d];
+
+const fn make_dual_basis_table() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut x = 0;
+    while x < 256 {
+        let mut bit = 0;
+        while bit < 8 {
+            if x & (1 << bit) != 0 {
+                table[x] ^= TAL[bit];
+            }
+            bit += 1;
+        }
+        x += 1;
+    }
+    table
+}
+
+const fn invert_table(table: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0; 256];
+    let mut x = 0;
+    while x < 256 {
+        inverse[table[x] as usize] = x as u8;
+        x += 1;
+    }
+    inverse
+}
+
+/// Conventional to dual basis lookup table
+pub static TO_DUAL_BASIS: [u8; 256] = make_dual_basis_table();
+
+/// Dual to conventional basis lookup table
+pub static FROM_DUAL_BASIS: [u8; 256] = invert_table(&make_dual_basis_table());
+
+/// Converts a symbol from the conventional to the dual basis
+#[inline]
+pub fn to_dual_basis(x: u8) -> u8 {
+    TO_DUAL_BASIS[x as usize]
+}
+
+/// Converts a symbol from the dual to the conventional basis
+#[inline]
+pub fn from_dual_basis(x: u8) -> u8 {
+    FROM_DUAL_BASIS[x as usize]
+}
+
+/// Period of the pseudo-randomizer sequence in bytes
+pub const PN_PERIOD: usize = 255;
+
+/// Bytes of the pseudo-randomizer sequence `h(x) = x^8 + x^7 + x^5 + x^3 + 1`, all ones at the
+/// start, most significant bit first
+const fn make_pn_sequence() -> [u8; PN_PERIOD] {
+    let mut sequence = [0; PN_PERIOD];
+    // Bit 7 holds the next output bit, bit 0 the latest one
+    let mut state: u8 = 0xff;
+    let mut i = 0;
+    while i < PN_PERIOD {
+        let mut bit = 0;
+        while bit < 8 {
+            sequence[i] = sequence[i] << 1 | state >> 7;
+            state = state << 1 | ((state ^ state >> 2 ^ state >> 4 ^ state >> 7) & 1);
+            bit += 1;
+        }
+        i += 1;
+    }
+    sequence
+}
+
+/// One period of the pseudo-randomizer sequence
+pub static PN_SEQUENCE: [u8; PN_PERIOD] = make_pn_sequence();
+
+/// Pseudo-randomizer stage, XORing the PN sequence into a codeblock after the RS encoder and
+/// removing it before the decoder. Randomizing and derandomizing are the same operation.
+///
+/// The sequence restarts at every codeblock, the attached sync marker is left out. A
+/// `Randomizer` carries its position so a codeblock can be passed in pieces.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::ccsds::{Codec, Randomizer};
+///
+/// let codec = Codec::<32>::new(2);
+/// let data = [0; 2 * 223];
+/// let mut codeblock = [0; 2 * 255];
+/// codec.encode(&data, &mut codeblock).unwrap();
+///
+/// let mut randomizer = Randomizer::new();
+/// randomizer.apply(&mut codeblock);
+/// assert_eq!([0xff, 0x48, 0x0e, 0xc0], codeblock[..4]);
+///
+/// // Receiver
+/// codeblock[7] ^= 1;
+/// randomizer.reset();
+/// randomizer.apply(&mut codeblock);
+/// assert_eq!(1, codec.decode(&mut codeblock).unwrap());
+/// assert_eq!(data, codeblock[..2 * 223]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Randomizer {
+    position: usize,
+}
+
+impl Randomizer {
+    /// Constructs a randomizer at the start of a codeblock
+    pub const fn new() -> Self {
+        Randomizer { position: 0 }
+    }
+
+    /// XORs the next bytes of the sequence into `bytes`
+    pub fn apply(&mut self, bytes: &mut [u8]) {
+        for x in bytes.iter_mut() {
+            *x ^= PN_SEQUENCE[self.position];
+            self.position = (self.position + 1) % PN_PERIOD;
+        }
+    }
+
+    /// Goes back to the start of the sequence for the next codeblock
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+/// Randomizes or derandomizes a whole codeblock
+pub fn randomize(codeblock: &mut [u8]) {
+    Randomizer::new().apply(codeblock);
+}
+
+/// Encoder taking and producing dual basis symbols, bit-exact with CCSDS
+///
+/// Decode by converting the received block with [`from_dual_basis`] and passing it to
+/// `Decoder::<Field>::with_field(ECC_LEN).fcr(fcr(ECC_LEN)).prim(PRIM)`.
+#[derive(Debug)]
+pub struct Encoder<const ECC_LEN: usize> {
+    encoder: crate::Encoder<ECC_LEN, Field>,
+}
+
+impl<const ECC_LEN: usize> Encoder<ECC_LEN> {
+    /// Constructs a new `Encoder`, usually `Encoder::<32>` or `Encoder::<16>`.
+    ///
+    /// # Panics
+    /// If `ECC_LEN` is odd or larger than 254.
+    pub fn new() -> Self {
+        assert!(ECC_LEN.is_multiple_of(2) && ECC_LEN < 255, "ECC_LEN must be even and less than 255");
+        Self {
+            encoder: crate::Encoder::with_roots(fcr(ECC_LEN), PRIM),
+        }
+    }
+
+    /// Encodes dual basis `data` and returns its `ECC_LEN` dual basis parity bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::ccsds;
+    ///
+    /// let encoder = ccsds::Encoder::<32>::new();
+    /// let parity = encoder.encode(&[0; 223]).unwrap();
+    /// assert_eq!([0; 32], parity[..]);
+    /// ```
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8, ECC_LEN>, EncoderError> {
+        let mut ecc = [0; ECC_LEN];
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(unwrap_unchecked!(Vec::from_slice(&ecc)))
+    }
+
+    /// Encodes dual basis `data` and writes its dual basis parity bytes into `ecc_out`, which
+    /// must be exactly `ECC_LEN` bytes long.
+    pub fn encode_to_slice(&self, data: &[u8], ecc_out: &mut [u8]) -> Result<(), EncoderError> {
+        if data.len() + ECC_LEN > 255 {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        let mut conventional = [0; 255];
+        for (c, x) in conventional.iter_mut().zip(data.iter()) {
+            *c = from_dual_basis(*x);
+        }
+        self.encoder.encode_to_slice(&conventional[..data.len()], ecc_out)?;
+        for x in ecc_out.iter_mut() {
+            *x = to_dual_basis(*x);
+        }
+        Ok(())
+    }
+}
+
+impl<const ECC_LEN: usize> Default for Encoder<ECC_LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Codec of whole CCSDS codeblocks: `depth` interleaved codewords of dual basis symbols, so
+/// that a burst of up to `depth * ECC_LEN / 2` damaged bytes is corrected.
+///
+/// Symbol `j` of codeword `c` is byte `j * depth + c` of the codeblock. Data shorter than
+/// `depth * (255 - ECC_LEN)` bytes is virtually filled, i.e. the codewords are shortened.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::ccsds::Codec;
+///
+/// let codec = Codec::<32>::new(4);
+/// let data = [0x55; 4 * 223];
+///
+/// let mut codeblock = [0; 4 * 255];
+/// codec.encode(&data, &mut codeblock).unwrap();
+///
+/// // 64 consecutive bytes, 16 in every codeword
+/// codeblock[100..164].fill(0);
+/// assert_eq!(64, codec.decode(&mut codeblock).unwrap());
+/// assert_eq!(data[..], codeblock[..4 * 223]);
+/// ```
+#[derive(Debug)]
+pub struct Codec<const ECC_LEN: usize = 32> {
+    encoder: Encoder<ECC_LEN>,
+    #[cfg(feature = "decoder")]
+    decoder: crate::Decoder<Field>,
+    depth: usize,
+}
+
+impl<const ECC_LEN: usize> Codec<ECC_LEN> {
+    /// Constructs a new `Codec` with interleaving `depth`, one of 1 to 8 allowed by the standard.
+    ///
+    /// # Panics
+    /// If `depth` is not in `1..=8`, or `ECC_LEN` is odd or larger than 254.
+    pub fn new(depth: usize) -> Self {
+        assert!((1..=8).contains(&depth), "interleaving depth must be 1 to 8");
+        Self {
+            encoder: Encoder::new(),
+            #[cfg(feature = "decoder")]
+            decoder: crate::Decoder::<Field>::with_field(ECC_LEN).fcr(fcr(ECC_LEN)).prim(PRIM),
+            depth,
+        }
+    }
+
+    /// Interleaving depth
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Length of the codeblock carrying `data_len` bytes
+    pub fn codeblock_len(&self, data_len: usize) -> usize {
+        data_len + self.depth * ECC_LEN
+    }
+
+    /// Encodes dual basis `data` into a codeblock of [`codeblock_len`](Self::codeblock_len)
+    /// bytes, the data followed by the interleaved parity.
+    ///
+    /// Errors with `InvalidBlockLength` if the data length is not a multiple of the depth.
+    pub fn encode(&self, data: &[u8], codeblock: &mut [u8]) -> Result<(), EncoderError> {
+        if codeblock.len() != self.codeblock_len(data.len()) {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+        if !data.len().is_multiple_of(self.depth) {
+            return Err(EncoderError::InvalidBlockLength);
+        }
+        let data_len = data.len() / self.depth;
+        if data_len + ECC_LEN > 255 {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        let (data_out, parity_out) = codeblock.split_at_mut(data.len());
+        data_out.copy_from_slice(data);
+
+        let mut codeword = [0; 255];
+        let mut parity = [0; ECC_LEN];
+        for c in 0..self.depth {
+            for (x, y) in codeword.iter_mut().zip(data[c..].iter().step_by(self.depth)) {
+                *x = *y;
+            }
+            self.encoder.encode_to_slice(&codeword[..data_len], &mut parity)?;
+            for (x, y) in parity_out[c..].iter_mut().step_by(self.depth).zip(parity.iter()) {
+                *x = *y;
+            }
+        }
+        Ok(())
+    }
+
+    /// Corrects a codeblock of dual basis symbols in place and returns the number of corrected
+    /// bytes. The codeblock is left untouched if any of its codewords can't be corrected.
+    ///
+    /// # Panics
+    /// If the codeblock length is not a multiple of the depth.
+    #[cfg(feature = "decoder")]
+    pub fn decode(&self, codeblock: &mut [u8]) -> Result<usize, crate::DecoderError> {
+        assert!(codeblock.len().is_multiple_of(self.depth), "codeblock length must be a multiple of the depth");
+        let len = codeblock.len() / self.depth;
+        if len > 255 {
+            return Err(crate::DecoderError::MessageTooLong);
+        }
+
+        let mut codewords = [[0; 255]; 8];
+        let mut corrected = 0;
+        for (c, codeword) in codewords[..self.depth].iter_mut().enumerate() {
+            for (x, y) in codeword.iter_mut().zip(codeblock[c..].iter().step_by(self.depth)) {
+                *x = from_dual_basis(*y);
+            }
+            corrected += self.decoder.correct_in_place(&mut codeword[..len], None)?;
+        }
+
+        for (c, codeword) in codewords[..self.depth].iter().enumerate() {
+            for (x, y) in codeblock[c..].iter_mut().step_by(self.depth).zip(codeword[..len].iter()) {
+                *x = to_dual_basis(*y);
+            }
+        }
+        Ok(corrected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pn_sequence() {
+        assert_eq!([0xff, 0x48, 0x0e, 0xc0, 0x9a, 0x0d, 0x70, 0xbc, 0x8e, 0x2c], PN_SEQUENCE[..10]);
+        // Maximal length sequence: 128 ones in each of its 8 bit periods
+        assert_eq!(128 * 8, PN_SEQUENCE.iter().map(|x| x.count_ones()).sum::<u32>());
+
+        let mut block = [0; 300];
+        let mut randomizer = Randomizer::new();
+        randomizer.apply(&mut block[..100]);
+        randomizer.apply(&mut block[100..]);
+        assert_eq!(PN_SEQUENCE[..45], block[PN_PERIOD..]);
+
+        randomize(&mut block);
+        assert_eq!([0; 300], block);
+    }
+
+    #[test]
+    fn dual_basis() {
+        assert_eq!(0x00, to_dual_basis(0x00));
+        assert_eq!(0x7b, to_dual_basis(0x01));
+        assert_eq!(0xaf, to_dual_basis(0x02));
+        assert_eq!(0xd4, to_dual_basis(0x03));
+        assert_eq!(0x8d, to_dual_basis(0x80));
+        for x in 0..=255 {
+            assert_eq!(x, from_dual_basis(to_dual_basis(x)));
+        }
+    }
+
+    #[test]
+    fn generator_is_self_reciprocal() {
+        let generator = crate::encoder::field_generator_poly::<Field>(32, fcr(32), PRIM);
+        assert_eq!(33, generator.len());
+        for i in 0..=32 {
+            assert_eq!(generator[i], generator[32 - i]);
+        }
+    }
+
+    #[cfg(feature = "decoder")]
+    #[test]
+    fn roundtrip() {
+        let data: [u8; 223] = core::array::from_fn(|i| (i * 7) as u8);
+        let parity = Encoder::<32>::new().encode(&data).unwrap();
+
+        let mut block = [0; 255];
+        block[..223].copy_from_slice(&data);
+        block[223..].copy_from_slice(&parity);
+        block[5] ^= 0xff;
+        block[200] ^= 0x11;
+        block[240] = 0;
+
+        for x in block.iter_mut() {
+            *x = from_dual_basis(*x);
+        }
+        let decoder = crate::Decoder::<Field>::with_field(32).fcr(fcr(32)).prim(PRIM);
+        let corrected = decoder.correct(&block, None).unwrap();
+        for (x, y) in corrected.data().iter().zip(data.iter()) {
+            assert_eq!(*y, to_dual_basis(*x));
+        }
+    }
+
+    #[cfg(feature = "decoder")]
+    #[test]
+    fn interleaved() {
+        let data: [u8; 5 * 200] = core::array::from_fn(|i| (i * 13) as u8);
+        let codec = Codec::<32>::new(5);
+        let mut codeblock = [0; 5 * 232];
+        codec.encode(&data, &mut codeblock).unwrap();
+
+        let encoder = Encoder::<32>::new();
+        let codeword: [u8; 200] = core::array::from_fn(|j| data[j * 5 + 2]);
+        let parity = encoder.encode(&codeword).unwrap();
+        for (j, x) in parity.iter().enumerate() {
+            assert_eq!(*x, codeblock[1000 + j * 5 + 2]);
+        }
+
+        let mut corrupted = codeblock;
+        corrupted[300..380].iter_mut().for_each(|x| *x ^= 0xff);
+        assert_eq!(80, codec.decode(&mut corrupted).unwrap());
+        assert_eq!(codeblock, corrupted);
+
+        corrupted[300..385].iter_mut().for_each(|x| *x ^= 0xff);
+        let damaged = corrupted;
+        assert!(codec.decode(&mut corrupted).is_err());
+        assert_eq!(damaged, corrupted);
+
+        let mut single = [0; 255];
+        Codec::<32>::new(1).encode(&[7; 223], &mut single).unwrap();
+        assert_eq!(encoder.encode(&[7; 223]).unwrap()[..], single[223..]);
+        assert_eq!(Err(EncoderError::InvalidBlockLength), codec.encode(&data[1..], &mut codeblock[1..]));
+        assert_eq!(Err(EncoderError::BufferLenMismatch), codec.encode(&data, &mut codeblock[1..]));
+        assert_eq!(Err(EncoderError::MessageTooLong), codec.encode(&[0; 5 * 224], &mut [0; 5 * 256]));
+    }
+}
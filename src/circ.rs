@@ -0,0 +1,326 @@
+//! Cross-interleaved Reed-Solomon code (CIRC) of the compact disc.
+//!
+//! Every frame of 24 data bytes (six stereo 16-bit samples) goes through
+//!
+//! 1. a delay of 2 frames on the even-numbered 16-bit words, moved to the front of the frame,
+//! 2. the C2 RS(28, 24) encoder, whose 4 Q parity bytes are placed in the middle of the frame,
+//! 3. delay lines interleaving byte `i` of the C2 codeword by `4 * i` frames,
+//! 4. the C1 RS(32, 28) encoder appending 4 P parity bytes,
+//! 5. a delay of 1 frame on the odd bytes, and inversion of the parity bytes.
+//!
+//! Both codes are shortened from RS(255, 251) over the 0x11d field with roots `2^0..2^3`.
+//! [`Decoder`] undoes the steps in reverse: C1 corrects single errors and flags the frame
+//! otherwise, the flags follow the bytes through the deinterleaver and become C2 erasures, so a
+//! burst wiping out whole C1 frames is spread over many C2 codewords. Bytes C2 can't correct are
+//! reported to the caller, CD players conceal them by interpolation.
+//!
+//! The whole chain delays data by [`LATENCY`] frames.
+use crate::Encoder as RsEncoder;
+use crate::Decoder as RsDecoder;
+
+/// Data bytes of a frame
+pub const DATA_LEN: usize = 24;
+/// Bytes of a C2 codeword
+pub const C2_LEN: usize = 28;
+/// Bytes of an encoded frame, a C1 codeword
+pub const FRAME_LEN: usize = 32;
+/// Frames between data entering the [`Encoder`] and leaving the [`Decoder`]
+pub const LATENCY: usize = 2 + INTERLEAVE_DELAY * (C2_LEN - 1) + 1;
+
+/// Delay step of the interleaver between consecutive bytes of a C2 codeword
+const INTERLEAVE_DELAY: usize = 4;
+/// Frames kept by the interleaver
+const INTERLEAVE_DEPTH: usize = INTERLEAVE_DELAY * (C2_LEN - 1) + 1;
+/// Positions of the Q parity in a C2 codeword
+const Q_POS: [u8; 4] = [12, 13, 14, 15];
+
+/// Ring of `DEPTH` frames of `N` symbols, symbol `i` of a pushed frame is read back after a
+/// per-symbol delay shorter than `DEPTH`
+#[derive(Debug, Clone)]
+struct Delays<T, const N: usize, const DEPTH: usize> {
+    frames: [[T; N]; DEPTH],
+    head: usize,
+}
+
+impl<T: Copy + Default, const N: usize, const DEPTH: usize> Delays<T, N, DEPTH> {
+    fn new() -> Self {
+        Delays {
+            frames: [[T::default(); N]; DEPTH],
+            head: 0,
+        }
+    }
+
+    /// Stores `frame` and returns the frame of delayed symbols
+    fn push(&mut self, frame: &[T; N], delay: impl Fn(usize) -> usize) -> [T; N] {
+        self.frames[self.head] = *frame;
+        let mut out = [T::default(); N];
+        for (i, x) in out.iter_mut().enumerate() {
+            *x = self.frames[(self.head + DEPTH - delay(i)) % DEPTH][i];
+        }
+        self.head = (self.head + 1) % DEPTH;
+        out
+    }
+}
+
+/// Index in a data frame of byte `pos` of a C2 codeword, even-numbered words first
+fn data_index(pos: usize) -> usize {
+    let (pos, word) = if pos >= 16 { (pos - 16, 1) } else { (pos, 0) };
+    4 * (pos / 2) + 2 * word + pos % 2
+}
+
+/// Whether byte `pos` of a frame is a parity byte
+fn is_parity(pos: usize) -> bool {
+    Q_POS.contains(&(pos as u8)) || pos >= C2_LEN
+}
+
+/// CIRC encoder of a stream of frames
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::circ::{Decoder, Encoder, LATENCY};
+///
+/// let mut encoder = Encoder::new();
+/// let mut decoder = Decoder::new();
+///
+/// let mut out = [0; 24];
+/// for n in 0..LATENCY + 10 {
+///     let mut frame = encoder.encode(&[n as u8; 24]);
+///     // A scratch wiping out most of a frame
+///     if n == 50 {
+///         frame[..30].fill(0);
+///     }
+///     assert_eq!(0, decoder.decode(&frame, &mut out));
+///     if n >= LATENCY {
+///         assert_eq!([(n - LATENCY) as u8; 24], out);
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Encoder {
+    c1: RsEncoder<4>,
+    c2: RsDecoder,
+    words: Delays<u8, DATA_LEN, 3>,
+    interleave: Delays<u8, C2_LEN, INTERLEAVE_DEPTH>,
+    odd: Delays<u8, FRAME_LEN, 2>,
+}
+
+impl Encoder {
+    /// Constructs a new `Encoder` with delay lines filled with zero frames
+    pub fn new() -> Self {
+        Encoder {
+            c1: RsEncoder::new(),
+            c2: RsDecoder::new(4),
+            words: Delays::new(),
+            interleave: Delays::new(),
+            odd: Delays::new(),
+        }
+    }
+
+    /// Encodes the next data frame and returns the next encoded frame
+    pub fn encode(&mut self, data: &[u8; DATA_LEN]) -> [u8; FRAME_LEN] {
+        let delayed = self.words.push(data, |i| if i % 4 < 2 { 2 } else { 0 });
+
+        // Q parity in the middle is whatever fills the erasures of a valid codeword
+        let mut c2 = [0; C2_LEN];
+        for (pos, x) in c2.iter_mut().enumerate() {
+            if !Q_POS.contains(&(pos as u8)) {
+                *x = delayed[data_index(pos)];
+            }
+        }
+        self.c2.correct_in_place(&mut c2, Some(&Q_POS))
+            .expect("4 erasures and no errors are always correctable with 4 ecc bytes");
+
+        let c2 = self.interleave.push(&c2, |i| INTERLEAVE_DELAY * i);
+        let mut c1 = [0; FRAME_LEN];
+        let (data, parity) = c1.split_at_mut(C2_LEN);
+        data.copy_from_slice(&c2);
+        unwrap_unchecked!(self.c1.encode_to_slice(&c2, parity));
+
+        let mut frame = self.odd.push(&c1, |i| i % 2);
+        for (pos, x) in frame.iter_mut().enumerate() {
+            if is_parity(pos) {
+                *x = !*x;
+            }
+        }
+        frame
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CIRC decoder of a stream of frames
+#[derive(Debug)]
+pub struct Decoder {
+    c1: RsDecoder,
+    c2: RsDecoder,
+    even: Delays<u8, FRAME_LEN, 2>,
+    deinterleave: Delays<u8, C2_LEN, INTERLEAVE_DEPTH>,
+    flags: Delays<bool, C2_LEN, INTERLEAVE_DEPTH>,
+    words: Delays<u8, DATA_LEN, 3>,
+    word_flags: Delays<bool, DATA_LEN, 3>,
+}
+
+impl Decoder {
+    /// Constructs a new `Decoder` with delay lines filled with zero frames
+    pub fn new() -> Self {
+        Decoder {
+            c1: RsDecoder::new(4).limit_corrections(1),
+            c2: RsDecoder::new(4),
+            even: Delays::new(),
+            deinterleave: Delays::new(),
+            flags: Delays::new(),
+            words: Delays::new(),
+            word_flags: Delays::new(),
+        }
+    }
+
+    /// Decodes the next encoded frame into `out`, the data frame [`LATENCY`] frames behind.
+    ///
+    /// Returns a mask of the bytes of `out` that couldn't be corrected, bit `i` for byte `i`.
+    pub fn decode(&mut self, frame: &[u8; FRAME_LEN], out: &mut [u8; DATA_LEN]) -> u32 {
+        let mut frame = *frame;
+        for (pos, x) in frame.iter_mut().enumerate() {
+            if is_parity(pos) {
+                *x = !*x;
+            }
+        }
+        let mut c1 = self.even.push(&frame, |i| 1 - i % 2);
+        let flag = self.c1.correct_in_place(&mut c1, None).is_err();
+
+        let mut c2 = [0; C2_LEN];
+        c2.copy_from_slice(&c1[..C2_LEN]);
+        let mut c2 = self.deinterleave.push(&c2, |i| INTERLEAVE_DELAY * (C2_LEN - 1 - i));
+        let mut flags = self.flags.push(&[flag; C2_LEN], |i| INTERLEAVE_DELAY * (C2_LEN - 1 - i));
+
+        let mut erasures = heapless::Vec::<u8, C2_LEN>::new();
+        for (pos, flag) in flags.iter().enumerate() {
+            if *flag {
+                unwrap_unchecked!(erasures.push(pos as u8));
+            }
+        }
+        if erasures.len() <= 4 && self.c2.correct_in_place(&mut c2, Some(&erasures)).is_ok() {
+            flags = [false; C2_LEN];
+        } else if erasures.is_empty() {
+            flags = [true; C2_LEN];
+        }
+
+        let mut data = [0; DATA_LEN];
+        let mut data_flags = [false; DATA_LEN];
+        for pos in (0..C2_LEN).filter(|pos| !Q_POS.contains(&(*pos as u8))) {
+            data[data_index(pos)] = c2[pos];
+            data_flags[data_index(pos)] = flags[pos];
+        }
+        let odd_words = |i: usize| if i % 4 < 2 { 0 } else { 2 };
+        *out = self.words.push(&data, odd_words);
+        let data_flags = self.word_flags.push(&data_flags, odd_words);
+
+        data_flags.iter().enumerate().fold(0, |mask, (i, flag)| mask | (*flag as u32) << i)
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        crate::gf::init_test_tables();
+        let mut indices = [0; DATA_LEN];
+        for (pos, index) in (0..12).chain(16..28).zip(indices.iter_mut()) {
+            *index = data_index(pos);
+        }
+        assert_eq!([0, 1, 4, 5, 8, 9], indices[..6]);
+        assert_eq!([2, 3, 6, 7, 10, 11], indices[12..18]);
+        indices.sort();
+        assert!(indices.iter().enumerate().all(|(i, x)| i == *x));
+
+        // C1 codeword 1 is made of the even bytes of frame 1 and the odd bytes of frame 2
+        let mut encoder = Encoder::new();
+        let frame = encoder.encode(&[1; DATA_LEN]);
+        assert_eq!([0, !0], [frame[1], frame[31]]);
+        let mut c1 = [0; FRAME_LEN];
+        for n in 1..=2 {
+            let frame = encoder.encode(&[1; DATA_LEN]);
+            for pos in 0..FRAME_LEN {
+                if pos % 2 == n - 1 {
+                    c1[pos] = if is_parity(pos) { !frame[pos] } else { frame[pos] };
+                }
+            }
+        }
+        assert!(!RsDecoder::new(4).is_corrupted(&c1));
+    }
+
+    fn stream(frames: usize) -> std::vec::Vec<[u8; DATA_LEN]> {
+        (0..frames)
+            .map(|n| core::array::from_fn(|i| (n * 37 + i * 11) as u8))
+            .collect()
+    }
+
+    #[test]
+    fn roundtrip() {
+        crate::gf::init_test_tables();
+        let data = stream(3 * LATENCY);
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+
+        let mut out = [0; DATA_LEN];
+        for (n, frame) in data.iter().enumerate() {
+            let encoded = encoder.encode(frame);
+            assert_eq!(0, decoder.decode(&encoded, &mut out));
+            if n >= LATENCY {
+                assert_eq!(data[n - LATENCY], out);
+            } else {
+                assert_eq!([0; DATA_LEN], out);
+            }
+        }
+    }
+
+    #[test]
+    fn burst() {
+        crate::gf::init_test_tables();
+        let data = stream(3 * LATENCY);
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+        let mut out = [0; DATA_LEN];
+
+        // 4 consecutive lost frames are erasures spread over distinct C2 codewords
+        for (n, frame) in data.iter().enumerate() {
+            let mut encoded = encoder.encode(frame);
+            if (150..154).contains(&n) {
+                encoded = [0x55; FRAME_LEN];
+            }
+            assert_eq!(0, decoder.decode(&encoded, &mut out));
+            if n >= LATENCY {
+                assert_eq!(data[n - LATENCY], out);
+            }
+        }
+
+        // A burst too long for C2 is flagged rather than passed on silently
+        let mut flagged = 0;
+        for (n, frame) in data.iter().enumerate() {
+            let mut encoded = encoder.encode(frame);
+            if (100..140).contains(&n) {
+                encoded = [0x55; FRAME_LEN];
+            }
+            let mask = decoder.decode(&encoded, &mut out);
+            flagged += mask.count_ones();
+            if n >= LATENCY {
+                for i in (0..DATA_LEN).filter(|i| mask & (1 << i) == 0) {
+                    assert_eq!(data[n - LATENCY][i], out[i]);
+                }
+            }
+        }
+        assert!(flagged > 0);
+    }
+}
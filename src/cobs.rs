@@ -0,0 +1,270 @@
+//! Consistent Overhead Byte Stuffing (COBS) of RS codewords for zero-delimited serial links.
+//!
+//! COBS removes every zero byte from a block at the cost of one byte per 254, so a single zero
+//! can mark the end of each frame on a UART. [`CobsRsCodec`] appends the parity to the payload,
+//! stuffs the codeword and adds the delimiter, and the other way round on reception.
+//!
+//! Stuffing goes after the encoder, so a damaged byte on the link may also break the stuffing
+//! around it: a code byte pointing past the end of the frame is reported as
+//! [`InvalidEncoding`](CobsError::InvalidEncoding), other damage ends up as byte errors for the
+//! decoder, several of them when a code byte moves the zeros of its run.
+use crate::encoder::Encoder;
+#[cfg(feature = "decoder")]
+use crate::{Decoder, DecoderError};
+
+/// Largest code byte, a run of 254 non-zero bytes without an implied zero
+const MAX_CODE: u8 = 0xff;
+
+/// Stuffing errors
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CobsError {
+    /// Payload doesn't fit a single codeword together with ecc
+    PayloadTooLong,
+    /// Output buffer can't fit the result
+    BufferTooSmall,
+    /// Stuffed block contains a zero or a code byte pointing past its end
+    InvalidEncoding,
+    /// Codeword can't be corrected
+    #[cfg(feature = "decoder")]
+    Uncorrectable(DecoderError),
+}
+
+#[cfg(feature = "decoder")]
+impl From<DecoderError> for CobsError {
+    fn from(err: DecoderError) -> Self {
+        CobsError::Uncorrectable(err)
+    }
+}
+
+/// Longest stuffed form of `len` bytes, without the delimiter
+pub const fn max_encoded_len(len: usize) -> usize {
+    len + len / 254 + 1
+}
+
+/// Stuffs `input` into `out` and returns the stuffed length, no delimiter is added.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::cobs;
+///
+/// let mut out = [0; 8];
+/// assert_eq!(6, cobs::encode(&[0x11, 0x22, 0x00, 0x33, 0x00], &mut out).unwrap());
+/// assert_eq!([0x03, 0x11, 0x22, 0x02, 0x33, 0x01], out[..6]);
+/// ```
+pub fn encode(input: &[u8], out: &mut [u8]) -> Result<usize, CobsError> {
+    if out.len() < max_encoded_len(input.len()) {
+        return Err(CobsError::BufferTooSmall);
+    }
+
+    let mut code_pos = 0;
+    let mut code = 1;
+    let mut pos = 1;
+    for x in input.iter() {
+        if *x != 0 {
+            out[pos] = *x;
+            pos += 1;
+            code += 1;
+        }
+        if *x == 0 || code == MAX_CODE {
+            out[code_pos] = code;
+            code_pos = pos;
+            pos += 1;
+            code = 1;
+        }
+    }
+    out[code_pos] = code;
+    Ok(pos)
+}
+
+/// Unstuffs `input`, without the delimiter, into `out` and returns the unstuffed length.
+pub fn decode(input: &[u8], out: &mut [u8]) -> Result<usize, CobsError> {
+    let mut pos = 0;
+    let mut len = 0;
+    while pos < input.len() {
+        let code = input[pos];
+        let end = pos + code as usize;
+        if code == 0 || end > input.len() {
+            return Err(CobsError::InvalidEncoding);
+        }
+
+        let run = &input[pos + 1..end];
+        if run.contains(&0) {
+            return Err(CobsError::InvalidEncoding);
+        }
+        let implied_zero = code != MAX_CODE && end < input.len();
+        if out.len() < len + run.len() + implied_zero as usize {
+            return Err(CobsError::BufferTooSmall);
+        }
+        out[len..len + run.len()].copy_from_slice(run);
+        len += run.len();
+        if implied_zero {
+            out[len] = 0;
+            len += 1;
+        }
+        pos = end;
+    }
+    Ok(len)
+}
+
+/// RS codec of zero-delimited frames: `COBS([payload][parity]) 0x00`
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::cobs::CobsRsCodec;
+///
+/// let codec = CobsRsCodec::<6>::new();
+/// let mut frame = [0; 64];
+/// let len = codec.encode(&[0, 1, 0, 2], &mut frame).unwrap();
+/// assert!(!frame[..len - 1].contains(&0));
+/// assert_eq!(0, frame[len - 1]);
+///
+/// frame[2] ^= 0x44;
+/// let mut payload = [0; 16];
+/// let payload_len = codec.decode(&frame[..len], &mut payload).unwrap();
+/// assert_eq!([0, 1, 0, 2], payload[..payload_len]);
+/// ```
+#[derive(Debug)]
+pub struct CobsRsCodec<const ECC_LEN: usize> {
+    encoder: Encoder<ECC_LEN>,
+    #[cfg(feature = "decoder")]
+    decoder: Decoder,
+}
+
+impl<const ECC_LEN: usize> CobsRsCodec<ECC_LEN> {
+    /// Longest payload of a frame
+    pub const MAX_PAYLOAD_LEN: usize = 255 - ECC_LEN;
+
+    /// Longest frame, delimiter included
+    pub const MAX_FRAME_LEN: usize = max_encoded_len(255) + 1;
+
+    /// Constructs a new codec
+    pub fn new() -> Self {
+        CobsRsCodec {
+            encoder: Encoder::new(),
+            #[cfg(feature = "decoder")]
+            decoder: Decoder::new(ECC_LEN),
+        }
+    }
+
+    /// Encodes and stuffs `payload` into `out`, followed by the zero delimiter, and returns
+    /// the frame length
+    pub fn encode(&self, payload: &[u8], out: &mut [u8]) -> Result<usize, CobsError> {
+        if payload.len() > Self::MAX_PAYLOAD_LEN {
+            return Err(CobsError::PayloadTooLong);
+        }
+
+        let mut codeword = [0; 255];
+        let len = payload.len() + ECC_LEN;
+        let (data, ecc) = codeword[..len].split_at_mut(payload.len());
+        data.copy_from_slice(payload);
+        unwrap_unchecked!(self.encoder.encode_to_slice(data, ecc));
+
+        let stuffed = encode(&codeword[..len], out)?;
+        if out.len() == stuffed {
+            return Err(CobsError::BufferTooSmall);
+        }
+        out[stuffed] = 0;
+        Ok(stuffed + 1)
+    }
+
+    /// Unstuffs and corrects a received frame, with or without its delimiter, writes the
+    /// payload into `out` and returns the payload length
+    #[cfg(feature = "decoder")]
+    pub fn decode(&self, frame: &[u8], out: &mut [u8]) -> Result<usize, CobsError> {
+        let frame = frame.strip_suffix(&[0]).unwrap_or(frame);
+        if frame.len() > max_encoded_len(255) {
+            return Err(CobsError::InvalidEncoding);
+        }
+
+        let mut codeword = [0; 256];
+        let len = decode(frame, &mut codeword)?;
+        if len > 255 || len < ECC_LEN {
+            return Err(CobsError::InvalidEncoding);
+        }
+        let codeword = &mut codeword[..len];
+        self.decoder.correct_in_place(codeword, None)?;
+
+        let payload = &codeword[..len - ECC_LEN];
+        if out.len() < payload.len() {
+            return Err(CobsError::BufferTooSmall);
+        }
+        out[..payload.len()].copy_from_slice(payload);
+        Ok(payload.len())
+    }
+}
+
+impl<const ECC_LEN: usize> Default for CobsRsCodec<ECC_LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(input: &[u8]) -> std::vec::Vec<u8> {
+        let mut stuffed = std::vec![0; max_encoded_len(input.len())];
+        let len = encode(input, &mut stuffed).unwrap();
+        stuffed.truncate(len);
+        assert!(!stuffed.contains(&0));
+
+        let mut out = std::vec![0; input.len()];
+        assert_eq!(input.len(), decode(&stuffed, &mut out).unwrap());
+        assert_eq!(input, &out[..]);
+        stuffed
+    }
+
+    #[test]
+    fn stuffing() {
+        // Examples of the COBS paper and its common test vectors
+        assert_eq!([0x01], roundtrip(&[])[..]);
+        assert_eq!([0x01, 0x01], roundtrip(&[0x00])[..]);
+        assert_eq!([0x01, 0x01, 0x01], roundtrip(&[0x00, 0x00])[..]);
+        assert_eq!([0x03, 0x11, 0x22, 0x02, 0x33], roundtrip(&[0x11, 0x22, 0x00, 0x33])[..]);
+        assert_eq!([0x02, 0x11, 0x01, 0x01, 0x01], roundtrip(&[0x11, 0x00, 0x00, 0x00])[..]);
+
+        let run: std::vec::Vec<u8> = (1..=254).collect();
+        let stuffed = roundtrip(&run);
+        assert_eq!((0xff, 256), (stuffed[0], stuffed.len()));
+        let mut longer = run.clone();
+        longer.push(0);
+        longer.extend_from_slice(&run);
+        roundtrip(&longer);
+
+        let mut out = [0; 8];
+        assert_eq!(Err(CobsError::InvalidEncoding), decode(&[0x03, 0x11], &mut out));
+        assert_eq!(Err(CobsError::InvalidEncoding), decode(&[0x03, 0x11, 0x00], &mut out));
+        assert_eq!(Err(CobsError::BufferTooSmall), decode(&[0x05, 1, 2, 3, 4], &mut out[..3]));
+        assert_eq!(Err(CobsError::BufferTooSmall), encode(&[1, 2, 3], &mut out[..3]));
+    }
+
+    #[test]
+    #[cfg(feature = "decoder")]
+    fn codec() {
+        crate::gf::init_test_tables();
+        let codec = CobsRsCodec::<8>::new();
+        let mut frame = [0; CobsRsCodec::<8>::MAX_FRAME_LEN];
+        let mut out = [0; 255];
+
+        for payload_len in [0, 1, 100, 247] {
+            let payload: std::vec::Vec<u8> = (0..payload_len).map(|i| (i % 3) as u8).collect();
+            let len = codec.encode(&payload, &mut frame).unwrap();
+            assert!(!frame[..len - 1].contains(&0));
+
+            // A damaged byte which isn't a code byte keeps the stuffing intact
+            let mut code_pos = 0;
+            while frame[code_pos] == 1 {
+                code_pos += 1;
+            }
+            frame[code_pos + 1] = frame[code_pos + 1] % 254 + 1;
+            assert_eq!(payload_len, codec.decode(&frame[..len], &mut out).unwrap());
+            assert_eq!(&payload[..], &out[..payload_len]);
+        }
+
+        assert_eq!(Err(CobsError::PayloadTooLong), codec.encode(&[0; 248], &mut frame));
+        assert_eq!(Err(CobsError::BufferTooSmall), codec.encode(&[1; 10], &mut frame[..19]));
+        assert_eq!(Err(CobsError::InvalidEncoding), codec.decode(&[0x02, 0x01, 0x00], &mut out));
+    }
+}
@@ -1,22 +1,115 @@
-use core;
+use core::marker::PhantomData;
 use crate::gf::poly_math::*;
 use crate::gf::poly::Polynom;
-use crate::gf;
+use crate::gf::{GaloisField, Gf2p8};
 use crate::buffer::Buffer;
+use crate::encoder::Layout;
+use heapless::Vec;
 
 /// Decoder error
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DecoderError {
     /// Message is unrecoverably corrupted
     TooManyErrors,
+    /// Message is longer than a single block
+    MessageTooLong,
+    /// Output buffer can't fit decoded data
+    BufferTooSmall,
+    /// Message is correctable, but needs more corrections than allowed by
+    /// `Decoder::limit_corrections`
+    TooManyCorrections,
+    /// Erasure position lies outside of the message, or erasure flags don't match its length
+    InvalidErasure,
 }
 
 type Result<T> = core::result::Result<T, DecoderError>;
 
+/// Rejects erasure positions outside of a message of `msg_len` bytes
+#[inline]
+fn check_erasures(erase_pos: &[u8], msg_len: usize) -> Result<()> {
+    if erase_pos.iter().any(|pos| *pos as usize >= msg_len) {
+        return Err(DecoderError::InvalidErasure);
+    }
+    Ok(())
+}
+
+/// Errata positions and magnitudes
+type Corrections = (Polynom, Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }>);
+
+/// Details of corrections applied by `Decoder::correct_with_report`
+#[derive(Debug, Clone)]
+pub struct CorrectionReport {
+    positions: Polynom,
+    magnitudes: Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }>,
+    erasures: usize,
+    ecc_len: usize,
+}
+
+impl CorrectionReport {
+    /// Positions of corrected symbols, erasures come last
+    pub fn positions(&self) -> &[u8] {
+        &self.positions
+    }
+
+    /// Values XOR-ed into the symbols at `positions()`
+    pub fn magnitudes(&self) -> &[u8] {
+        &self.magnitudes
+    }
+
+    /// Number of symbols corrected at known erasure positions
+    pub fn erasure_count(&self) -> usize {
+        self.erasures
+    }
+
+    /// Number of symbols corrected at positions found by the decoder
+    pub fn error_count(&self) -> usize {
+        self.positions.len() - self.erasures
+    }
+
+    /// Returns `true` if correction used all of the redundancy, so no further errors
+    /// could have been detected and the result may be a miscorrection
+    pub fn budget_exhausted(&self) -> bool {
+        2 * self.error_count() + self.erasures >= self.ecc_len
+    }
+}
+
+/// Block state reported by `Decoder::check`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlockStatus {
+    /// Block is not damaged
+    Clean,
+    /// Block is damaged, but the given number of symbols could be corrected
+    Correctable(usize),
+    /// Block is damaged beyond repair
+    Uncorrectable,
+}
+
+/// Algorithm used to find the error locator polynomial
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyEquationSolver {
+    /// Berlekamp-Massey algorithm
+    #[default]
+    BerlekampMassey,
+    /// Sugiyama's extended Euclidean algorithm
+    Euclidean,
+}
+
 /// Reed-Solomon BCH decoder
+///
+/// Works over the default 0x11d field unless another byte field is given as `F`.
 #[derive(Debug, Copy, Clone)]
-pub struct Decoder {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Decoder<F: GaloisField<Elem = u8> = Gf2p8> {
     ecc_len: usize,
+    solver: KeyEquationSolver,
+    max_corrections: usize,
+    /// Power of the first consecutive root of the generator polynomial
+    fcr: u8,
+    /// Power of 2 generating the roots
+    prim: u8,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    field: PhantomData<F>,
 }
 
 impl Decoder {
@@ -29,7 +122,129 @@ impl Decoder {
     /// let decoder = Decoder::new(8);
     /// ```
     pub fn new(ecc_len: usize) -> Self {
-        Decoder { ecc_len: ecc_len }
+        Self::with_solver(ecc_len, KeyEquationSolver::default())
+    }
+
+    /// Constructs a new `Decoder` which uses given key equation `solver`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Decoder, KeyEquationSolver};
+    ///
+    /// let decoder = Decoder::with_solver(8, KeyEquationSolver::Euclidean);
+    /// ```
+    pub fn with_solver(ecc_len: usize, solver: KeyEquationSolver) -> Self {
+        Decoder { ecc_len, solver, max_corrections: ecc_len, fcr: 0, prim: 1, field: PhantomData }
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> Decoder<F> {
+    /// Constructs a new `Decoder` over field `F`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Decoder, Encoder};
+    /// use reed_solomon::gf::Gf2p8;
+    ///
+    /// let mut encoder = Encoder::<4, Gf2p8<0x187>>::new();
+    /// let decoder = Decoder::<Gf2p8<0x187>>::with_field(4);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[1] = 0;
+    /// assert_eq!(&[1, 2, 3, 4], decoder.correct(&message, None).unwrap().data());
+    /// ```
+    pub fn with_field(ecc_len: usize) -> Self {
+        Decoder { ecc_len, solver: KeyEquationSolver::default(), max_corrections: ecc_len, fcr: 0, prim: 1, field: PhantomData }
+    }
+
+    /// Caps the number of symbols (erasures included) the decoder is allowed to correct.
+    ///
+    /// Heavily damaged blocks are likely to be miscorrected into another valid codeword,
+    /// refusing to correct close to `ecc_len / 2` errors reduces this probability.
+    /// Such blocks are rejected with `DecoderError::TooManyCorrections`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Decoder, DecoderError, Encoder};
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4).limit_corrections(1);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[0] = 0;
+    /// assert!(decoder.correct(&message, None).is_ok());
+    ///
+    /// message[1] = 0;
+    /// assert_eq!(Some(DecoderError::TooManyCorrections), decoder.correct(&message, None).err());
+    /// ```
+    pub fn limit_corrections(mut self, max_corrections: usize) -> Self {
+        self.max_corrections = max_corrections;
+        self
+    }
+
+    /// Sets the first consecutive root of the generator polynomial to `2^fcr`, it has to match
+    /// the one of the encoder, e.g. [`Encoder::with_fcr`](crate::Encoder::with_fcr).
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Decoder, Encoder};
+    ///
+    /// let mut encoder = Encoder::<4>::with_fcr(1);
+    /// let decoder = Decoder::new(4).fcr(1);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[0] = 0;
+    /// assert_eq!(&[1, 2, 3, 4], decoder.correct(&message, None).unwrap().data());
+    /// ```
+    pub fn fcr(mut self, fcr: u8) -> Self {
+        self.fcr = fcr;
+        self
+    }
+
+    /// Sets the element generating consecutive roots of the generator polynomial to `2^prim`,
+    /// it has to match the one of the encoder, e.g. [`Encoder::with_roots`](crate::Encoder::with_roots).
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Decoder, Encoder};
+    ///
+    /// // CCSDS roots without the dual basis
+    /// let mut encoder = Encoder::<32>::with_roots(112, 11);
+    /// let decoder = Decoder::new(32).fcr(112).prim(11);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[0] = 0;
+    /// message[20] = 0;
+    /// assert_eq!(&[1, 2, 3, 4], decoder.correct(&message, None).unwrap().data());
+    /// ```
+    ///
+    /// # Panics
+    /// If `2^prim` doesn't generate the whole field, i.e. `prim` and the field order have a
+    /// common factor.
+    pub fn prim(mut self, prim: u8) -> Self {
+        assert!(crate::encoder::power_inverse(prim, F::ORDER).is_some(), "2^prim must be a primitive element");
+        self.prim = prim;
+        self
+    }
+
+    /// Number of ecc bytes per codeword
+    pub fn ecc_len(&self) -> usize {
+        self.ecc_len
     }
 
     /// Decodes block-encoded message and returns `Buffer` with corrected message and ecc offset.
@@ -37,15 +252,16 @@ impl Decoder {
     ///
     /// # Example
     /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
     /// use reed_solomon::Encoder;
     /// use reed_solomon::Decoder;
     ///
     /// // Create encoder and decoder
-    /// let mut encoder = Encoder::<5>::new(4);
+    /// let mut encoder = Encoder::<4>::new();
     /// let decoder = Decoder::new(4);
     ///
     /// // Encode message
-    /// let mut encoded = encoder.encode(&[1, 2, 3, 4]);
+    /// let mut encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
     /// let mut message = vec![1, 2, 3, 4];
     /// message.extend_from_slice(&encoded[..]);
     ///
@@ -66,62 +282,368 @@ impl Decoder {
                              msg: &[u8],
                              erase_pos: Option<&[u8]>)
                              -> Result<(Buffer, usize)> {
-       let mut msg = Buffer::from_slice(msg, msg.len() - self.ecc_len);
+        let mut msg = self.buffer(msg)?;
+
+        // Positions outside of the message are rejected by `correct_in_place`
+        for e_pos in erase_pos.unwrap_or(&[]) {
+            if let Some(x) = msg.get_mut(*e_pos as usize) {
+                *x = 0;
+            }
+        }
+
+        let fixed = self.correct_in_place(&mut msg, erase_pos)?;
+        Ok((msg, fixed))
+    }
+
+    /// Corrects block-encoded message in place and returns the number of corrected symbols.
+    ///
+    /// Message is left untouched if it can't be corrected.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // Corrupt message
+    /// message[2] = 1;
+    /// message[3] = 2;
+    ///
+    /// assert_eq!(2, decoder.correct_in_place(&mut message, None).unwrap());
+    /// assert_eq!(&[1, 2, 3, 4], &message[..4]);
+    /// ```
+    pub fn correct_in_place(&self, msg: &mut [u8], erase_pos: Option<&[u8]>) -> Result<usize> {
+        let Some((err_pos, magnitudes)) = self.find_corrections(msg, erase_pos.unwrap_or(&[]))? else {
+            return Ok(0);
+        };
+
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            uncheck_mut!(msg[*pos as usize]) ^= *magnitude;
+        }
+
+        Ok(magnitudes.len())
+    }
+
+    /// Corrects block-encoded message in place like `correct_in_place`, and returns
+    /// a report of the applied corrections.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // Corrupt message
+    /// message[2] ^= 0x10;
+    ///
+    /// let report = decoder.correct_with_report(&mut message, None).unwrap();
+    /// assert_eq!(&[2], report.positions());
+    /// assert_eq!(&[0x10], report.magnitudes());
+    /// assert!(!report.budget_exhausted());
+    /// ```
+    pub fn correct_with_report(&self, msg: &mut [u8], erase_pos: Option<&[u8]>) -> Result<CorrectionReport> {
+        let erase_pos = erase_pos.unwrap_or(&[]);
+        let (err_pos, magnitudes) = self.find_corrections(msg, erase_pos)?
+            .unwrap_or_else(|| (Polynom::new(), Vec::new()));
+
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            uncheck_mut!(msg[*pos as usize]) ^= *magnitude;
+        }
+
+        Ok(CorrectionReport {
+            erasures: if err_pos.is_empty() { 0 } else { erase_pos.len() },
+            positions: err_pos,
+            magnitudes,
+            ecc_len: self.ecc_len,
+        })
+    }
+
+    /// Decodes block-encoded message and writes corrected data (without ecc) into `out`,
+    /// leaving `msg` untouched.
+    ///
+    /// A message not longer than ecc is reported as `TooManyErrors`, since it was cut.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // Corrupt message
+    /// message[2] = 1;
+    ///
+    /// let mut data = [0; 4];
+    /// decoder.decode_to_slice(&message, None, &mut data).unwrap();
+    /// assert_eq!([1, 2, 3, 4], data);
+    /// ```
+    pub fn decode_to_slice(&self, msg: &[u8], erase_pos: Option<&[u8]>, out: &mut [u8]) -> Result<()> {
+        if msg.len() <= self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+        let data_len = msg.len() - self.ecc_len;
+        if out.len() < data_len {
+            return Err(DecoderError::BufferTooSmall);
+        }
+
+        let corrections = self.find_corrections(msg, erase_pos.unwrap_or(&[]))?;
 
-        assert!(msg.len() < 256);
+        out[..data_len].copy_from_slice(&msg[..data_len]);
+        let Some((err_pos, magnitudes)) = corrections else {
+            return Ok(());
+        };
 
-        let erase_pos = if let Some(erase_pos) = erase_pos {
-            for e_pos in erase_pos {
-                msg[*e_pos as usize] = 0;
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            if let Some(x) = out[..data_len].get_mut(*pos as usize) {
+                *x ^= *magnitude;
             }
-            erase_pos
-        } else {
-            &[]
+        }
+
+        Ok(())
+    }
+
+    /// Decodes a codeword arranged as `layout` by [`Encoder::encode_with_layout`](crate::Encoder::encode_with_layout),
+    /// writes its data to `out` and returns the data length.
+    ///
+    /// Erasure positions are counted from the beginning of `msg` as it is laid out. A message not
+    /// longer than ecc is reported as `TooManyErrors`, like by `decode_to_slice`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Decoder, Encoder, Layout};
+    ///
+    /// let encoder = Encoder::<8>::new();
+    /// let decoder = Decoder::new(8);
+    ///
+    /// let mut codeword = [0; 19];
+    /// encoder.encode_with_layout(b"Hello World", Layout::NonSystematic, &mut codeword).unwrap();
+    /// codeword[3] = 0;
+    ///
+    /// let mut data = [0; 11];
+    /// assert_eq!(Ok(11), decoder.decode_with_layout(&codeword, Layout::NonSystematic, None, &mut data));
+    /// assert_eq!(b"Hello World", &data);
+    /// ```
+    pub fn decode_with_layout(&self, msg: &[u8], layout: Layout, erase_pos: Option<&[u8]>, out: &mut [u8]) -> Result<usize> {
+        if msg.len() <= self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+        let data_len = msg.len() - self.ecc_len;
+        if out.len() < data_len {
+            return Err(DecoderError::BufferTooSmall);
+        }
+
+        match layout {
+            Layout::DataFirst => self.decode_to_slice(msg, erase_pos, out)?,
+            Layout::ParityFirst => {
+                let mut block: Polynom = Polynom::from(&msg[self.ecc_len..]);
+                for x in msg[..self.ecc_len].iter() {
+                    block.push(*x);
+                }
+
+                // Checked before moving, a position past the end could land inside the block
+                check_erasures(erase_pos.unwrap_or(&[]), msg.len())?;
+                let mut erasures: Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }> = Vec::new();
+                for pos in erase_pos.unwrap_or(&[]) {
+                    let pos = *pos as usize;
+                    let pos = if pos < self.ecc_len { data_len + pos } else { pos - self.ecc_len };
+                    erasures.push(pos as u8).map_err(|_| DecoderError::TooManyErrors)?;
+                }
+                self.decode_to_slice(&block, Some(&erasures), out)?;
+            }
+            Layout::NonSystematic => {
+                let corrected = self.correct(msg, erase_pos)?;
+                let generator = crate::encoder::field_generator_poly::<F>(self.ecc_len, self.fcr, self.prim);
+                let (data, _) = Polynom::<F>::from(&corrected[..]).div(&generator);
+                out[..data_len].copy_from_slice(&data);
+            }
+        }
+        Ok(data_len)
+    }
+
+    /// Decodes a message encoded by [`BlockEncoder`](crate::BlockEncoder): codewords of
+    /// 255 bytes, the last one possibly shorter. Data of all codewords is written to `out`
+    /// and its length is returned.
+    ///
+    /// A last codeword not longer than ecc is reported as `TooManyErrors`, since it was cut.
+    pub fn decode_blocks(&self, msg: &[u8], out: &mut [u8]) -> Result<usize> {
+        let mut data_len = 0;
+        for codeword in msg.chunks(F::ORDER) {
+            if codeword.len() <= self.ecc_len {
+                return Err(DecoderError::TooManyErrors);
+            }
+
+            let out = out.get_mut(data_len..).ok_or(DecoderError::BufferTooSmall)?;
+            self.decode_to_slice(codeword, None, out)?;
+            data_len += codeword.len() - self.ecc_len;
+        }
+        Ok(data_len)
+    }
+
+    /// Corrects a block split into `data` and `ecc` parts in place (e.g. NAND flash page and
+    /// its spare area) and returns the number of corrected symbols.
+    ///
+    /// Erasure positions are counted from the beginning of `data` as if `ecc` followed it.
+    /// Both parts are left untouched if the block can't be corrected.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    /// let mut ecc = [0; 4];
+    /// ecc.copy_from_slice(&encoder.encode(&data).unwrap());
+    ///
+    /// data[1] = 0;
+    /// ecc[2] = 0;
+    ///
+    /// assert_eq!(2, decoder.correct_split_in_place(&mut data, &mut ecc, None).unwrap());
+    /// assert_eq!([1, 2, 3, 4], data);
+    /// ```
+    pub fn correct_split_in_place(&self,
+                                  data: &mut [u8],
+                                  ecc: &mut [u8],
+                                  erase_pos: Option<&[u8]>)
+                                  -> Result<usize> {
+        let Some((err_pos, magnitudes)) = self.find_split_corrections(data, ecc, erase_pos.unwrap_or(&[]))? else {
+            return Ok(0);
         };
 
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            let pos = *pos as usize;
+            match pos.checked_sub(data.len()) {
+                None => data[pos] ^= *magnitude,
+                Some(pos) => ecc[pos] ^= *magnitude,
+            }
+        }
+
+        Ok(magnitudes.len())
+    }
+
+    /// Copies a received message into a `Buffer`, rejecting messages that can't be a codeword
+    fn buffer(&self, msg: &[u8]) -> Result<Buffer> {
+        if msg.len() > F::ORDER {
+            return Err(DecoderError::MessageTooLong);
+        }
+        let data_len = msg.len().checked_sub(self.ecc_len).ok_or(DecoderError::TooManyErrors)?;
+        Ok(Buffer::from_slice(msg, data_len))
+    }
+
+    /// Locates errata in `msg` and returns their positions and magnitudes,
+    /// or `None` if the message is not damaged
+    fn find_corrections(&self,
+                        msg: &[u8],
+                        erase_pos: &[u8])
+                        -> Result<Option<Corrections>> {
+        self.find_split_corrections(msg, &[], erase_pos)
+    }
+
+    /// Same as `find_corrections` for a block split into `data` and `ecc` parts
+    fn find_split_corrections(&self,
+                              data: &[u8],
+                              ecc: &[u8],
+                              erase_pos: &[u8])
+                              -> Result<Option<Corrections>> {
+        let msg_len = data.len() + ecc.len();
+        if msg_len > F::ORDER {
+            return Err(DecoderError::MessageTooLong);
+        }
+
         if erase_pos.len() > self.ecc_len {
             return Err(DecoderError::TooManyErrors);
         }
+        check_erasures(erase_pos, msg_len)?;
 
-        let synd = self.calc_syndromes(&msg);
+        // index 0 is a pad for mathematical precision
+        let mut synd = Polynom::<F>::with_length(self.ecc_len + 1);
+        synd[1..].copy_from_slice(&self.syndromes(data, ecc));
+        self.find_corrections_from_syndromes(&synd, erase_pos, msg_len)
+    }
 
-        // No errors
+    fn find_corrections_from_syndromes(&self,
+                                       synd: &[u8],
+                                       erase_pos: &[u8],
+                                       msg_len: usize)
+                                       -> Result<Option<Corrections>> {
+        // No errors, skip building locator and evaluator polynomials
         if synd.iter().all(|x| *x == 0) {
-            return Ok((msg,0));
+            return Ok(None);
         }
 
-        let fsynd = self.forney_syndromes(&synd, erase_pos, msg.len());
+        let fsynd = self.forney_syndromes(synd, erase_pos, msg_len);
         let err_loc = self.find_error_locator(&fsynd, None, erase_pos.len())?;
-        let mut err_pos = self.find_errors(&err_loc.reverse(), msg.len())?;
+        let mut err_pos = self.find_errors(&err_loc, msg_len)?;
 
         // Append erase_pos to err_pos
         for x in erase_pos.iter() {
             err_pos.push(*x);
         }
 
-        let (msg_out, fixed) = self.correct_errata(&msg, &synd, &err_pos);
+        let magnitudes = self.errata_magnitudes(msg_len, synd, &err_pos)?;
 
-        // Check output message correctness
-        if self.is_corrupted(&msg_out) {
-            Err(DecoderError::TooManyErrors)
-        } else {
-            Ok((Buffer::from_polynom(msg_out, msg.len() - self.ecc_len), fixed))
+        // Check output message correctness: the corrections must cancel out all syndromes
+        for (i, s) in synd.iter().skip(1).enumerate() {
+            let x = F::pow(2, self.root_power(i) as i32);
+            let e = err_pos.iter().zip(magnitudes.iter()).fold(0, |acc, (pos, magnitude)| {
+                acc ^ F::mul(*magnitude, F::pow(x, (msg_len - 1 - *pos as usize) as i32))
+            });
+            if e != *s {
+                return Err(DecoderError::TooManyErrors);
+            }
         }
+
+        if err_pos.len() > self.max_corrections {
+            return Err(DecoderError::TooManyCorrections);
+        }
+
+        Ok(Some((err_pos, magnitudes)))
     }
 
     /// Decodes block-encoded message and returns `Buffer` with corrected message and ecc offset.
     ///
     /// # Example
     /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
     /// use reed_solomon::Encoder;
     /// use reed_solomon::Decoder;
     ///
     /// // Create encoder and decoder
-    /// let mut encoder = Encoder::<5>::new(4);
+    /// let mut encoder = Encoder::<4>::new();
     /// let decoder = Decoder::new(4);
     ///
     /// // Encode message
-    /// let mut encoded = encoder.encode(&[1, 2, 3, 4]);
+    /// let mut encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
     /// let mut message = vec![1, 2, 3, 4];
     /// message.extend_from_slice(&encoded[..]);
     /// // Corrupt message
@@ -144,19 +666,206 @@ impl Decoder {
         self.correct_err_count(msg, erase_pos).map(|(r,_)| r)
      }
 
+    /// Decodes a message whose last `punctured` ecc bytes were dropped by
+    /// [`Encoder::encode_punctured`](crate::Encoder::encode_punctured). They are restored as
+    /// erasures, so each costs one ecc byte of the correction capability.
+    ///
+    /// The returned buffer holds the whole codeword, including the restored ecc bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Decoder, Encoder};
+    ///
+    /// let encoder = Encoder::<8>::new();
+    /// let decoder = Decoder::new(8);
+    ///
+    /// let mut message = b"Hello World".to_vec();
+    /// message.extend_from_slice(&encoder.encode_punctured(b"Hello World", 2).unwrap());
+    /// assert_eq!(17, message.len());
+    ///
+    /// message[0] = 0;
+    /// message[5] = 0;
+    ///
+    /// let corrected = decoder.correct_punctured(&message, 2, None).unwrap();
+    /// assert_eq!(b"Hello World", corrected.data());
+    /// ```
+    pub fn correct_punctured(&self, msg: &[u8], punctured: usize, erase_pos: Option<&[u8]>) -> Result<Buffer> {
+        let erase_pos = erase_pos.unwrap_or(&[]);
+        let len = msg.len() + punctured;
+        if len > F::ORDER {
+            return Err(DecoderError::MessageTooLong);
+        }
+        if punctured + erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        let data_len = len.checked_sub(self.ecc_len).ok_or(DecoderError::TooManyErrors)?;
+        let mut full = Buffer::from_slice(msg, data_len);
+        full.set_length(len);
+
+        let mut erasures: Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }> = Vec::new();
+        for pos in erase_pos.iter().copied().chain(msg.len() as u8..len as u8) {
+            erasures.push(pos).map_err(|_| DecoderError::TooManyErrors)?;
+        }
+        self.correct(&full, Some(&erasures))
+    }
+
+    /// Decodes block-encoded message with known erasure positions and no other errors,
+    /// which allows recovering up to `ecc_len` erased bytes instead of `ecc_len / 2` errors.
+    ///
+    /// Returns error if the message has errors outside of `erase_pos`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // Lose 4 bytes
+    /// let erasures = [0, 1, 2, 3];
+    /// for e in erasures.iter() {
+    ///     message[*e as usize] = 0;
+    /// }
+    ///
+    /// let corrected = decoder.correct_erasures(&message, &erasures).unwrap();
+    /// assert_eq!(&[1, 2, 3, 4], corrected.data())
+    /// ```
+    pub fn correct_erasures(&self, msg: &[u8], erase_pos: &[u8]) -> Result<Buffer> {
+        let mut msg = self.buffer(msg)?;
+
+        if erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+        check_erasures(erase_pos, msg.len())?;
+
+        for e_pos in erase_pos {
+            msg[*e_pos as usize] = 0;
+        }
+
+        let synd = self.calc_syndromes(&msg);
+
+        // No errors
+        if synd.iter().all(|x| *x == 0) {
+            return Ok(msg);
+        }
+
+        let (msg_out, _) = self.correct_errata(&msg, &synd, erase_pos)?;
+
+        // Check output message correctness
+        if self.is_corrupted(&msg_out) {
+            Err(DecoderError::TooManyErrors)
+        } else if erase_pos.len() > self.max_corrections {
+            Err(DecoderError::TooManyCorrections)
+        } else {
+            Ok(Buffer::from_polynom(msg_out, msg.len() - self.ecc_len))
+        }
+    }
+
+    /// Decodes block-encoded message treating symbols flagged in `erased` as erasures,
+    /// e.g. low-confidence symbols reported by a soft-decision demodulator.
+    ///
+    /// `erased` holds one flag per byte of `msg`, a length mismatch is reported as `InvalidErasure`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // Corrupt message, demodulator was unsure about two of the bytes
+    /// message[0] = 0;
+    /// message[2] = 0;
+    /// message[5] = 0;
+    /// let erased = [true, false, true, false, false, false, false, false];
+    ///
+    /// let corrected = decoder.correct_with_erasure_flags(&message, &erased).unwrap();
+    /// assert_eq!(&[1, 2, 3, 4], corrected.data())
+    /// ```
+    pub fn correct_with_erasure_flags(&self, msg: &[u8], erased: &[bool]) -> Result<Buffer> {
+        if msg.len() > F::ORDER {
+            return Err(DecoderError::MessageTooLong);
+        }
+        if msg.len() != erased.len() {
+            return Err(DecoderError::InvalidErasure);
+        }
+
+        let erase_pos: Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }> = erased.iter()
+            .enumerate()
+            .filter(|(_, e)| **e)
+            .map(|(i, _)| i as u8)
+            .collect();
+
+        self.correct(msg, Some(&erase_pos))
+    }
+
+    /// Classifies block-encoded message without modifying it, for systems which use
+    /// Reed-Solomon code as a strong checksum and must never alter payload bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::{BlockStatus, Decoder};
+    ///
+    /// // Create encoder and decoder
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// // Encode message
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// assert_eq!(BlockStatus::Clean, decoder.check(&message, None));
+    ///
+    /// message[2] = 1;
+    /// assert_eq!(BlockStatus::Correctable(1), decoder.check(&message, None));
+    ///
+    /// message[0] = 0;
+    /// message[1] = 0;
+    /// assert_eq!(BlockStatus::Uncorrectable, decoder.check(&message, None));
+    /// ```
+    pub fn check(&self, msg: &[u8], erase_pos: Option<&[u8]>) -> BlockStatus {
+        match self.find_corrections(msg, erase_pos.unwrap_or(&[])) {
+            Ok(None) => BlockStatus::Clean,
+            Ok(Some((_, magnitudes))) => BlockStatus::Correctable(magnitudes.len()),
+            Err(_) => BlockStatus::Uncorrectable,
+        }
+    }
+
     /// Performs fast corruption check.
     ///
     /// # Example
     /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
     /// use reed_solomon::Encoder;
     /// use reed_solomon::Decoder;
     ///
     /// // Create encoder and decoder
-    /// let mut encoder = Encoder::<5>::new(4);
+    /// let mut encoder = Encoder::<4>::new();
     /// let decoder = Decoder::new(4);
     ///
     /// // Encode message
-    /// let encoded = encoder.encode(&[1, 2, 3, 4]);
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
     /// let mut message = vec![1, 2, 3, 4];
     /// message.extend_from_slice(&encoded[..]);
     ///
@@ -169,201 +878,302 @@ impl Decoder {
     /// assert_eq!(decoder.is_corrupted(&message), true);
     /// ```
     pub fn is_corrupted(&self, msg: &[u8]) -> bool {
-        (0..self.ecc_len).any(|x| msg.eval(gf::pow(2, x as i32)) != 0)
+        !self.verify(msg, &[])
+    }
+
+    /// Computes `ecc_len` syndromes of a block split into `data` and `ecc` parts.
+    ///
+    /// All syndromes are zero if the block is not damaged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    /// let ecc = encoder.encode(&data).unwrap();
+    /// assert!(decoder.syndromes(&data, &ecc).iter().all(|s| *s == 0));
+    ///
+    /// data[1] = 0;
+    /// assert!(decoder.syndromes(&data, &ecc).iter().any(|s| *s != 0));
+    /// ```
+    pub fn syndromes(&self, data: &[u8], ecc: &[u8]) -> Polynom<F> {
+        split_syndrome_poly(data, ecc, self.ecc_len, self.fcr, self.prim)
+    }
+
+    /// Checks that a block split into `data` and `ecc` parts is not damaged.
+    ///
+    /// Syndromes are evaluated one by one and the check bails out on the first non-zero one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::Decoder;
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let decoder = Decoder::new(4);
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    /// let ecc = encoder.encode(&data).unwrap();
+    /// assert!(decoder.verify(&data, &ecc));
+    ///
+    /// data[1] = 0;
+    /// assert!(!decoder.verify(&data, &ecc));
+    /// ```
+    pub fn verify(&self, data: &[u8], ecc: &[u8]) -> bool {
+        (0..self.ecc_len).all(|i| split_syndrome::<F>(data, ecc, self.root_power(i)) == 0)
+    }
+
+    /// Power of 2 of the `i`-th root of the generator polynomial
+    fn root_power(&self, i: usize) -> usize {
+        root_power::<F>(self.fcr, self.prim, i)
+    }
+
+    /// Power of 2 of the locator of coefficient `degree`
+    fn locator_power(&self, degree: usize) -> u8 {
+        (self.prim as usize * degree % F::ORDER) as u8
     }
 
-    fn calc_syndromes(&self, msg: &[u8]) -> Polynom {
+    fn calc_syndromes(&self, msg: &[u8]) -> Polynom<F> {
         // index 0 is a pad for mathematical precision
         let mut synd = Polynom::with_length(self.ecc_len + 1);
-        for i in 0..self.ecc_len {
-            uncheck_mut!(synd[i + 1]) = msg.eval(gf::pow(2, i as i32))
-        }
-
+        synd[1..].copy_from_slice(&self.syndromes(msg, &[]));
         synd
     }
 
-    fn find_errata_locator(&self, e_pos: &[u8]) -> Polynom {
-        let mut e_loc = polynom![1];
-
-        let add_lhs = [1];
-        let mut add_rhs = [0, 0];
-        for i in e_pos.iter() {
-            add_rhs[0] = gf::pow(2, *i as i32);
-            e_loc = e_loc.mul(&add_lhs.add(&add_rhs));
-        }
-
-        e_loc
-    }
-
-    fn find_error_evaluator(&self, synd: &[u8], err_loc: &[u8], syms: usize) -> Polynom {
-        let mut divisor = Polynom::with_length(syms + 2);
-        divisor[0] = 1;
-
-        let (_, remainder) = (synd.mul(err_loc)).div(&divisor);
-        remainder
+    fn find_errata_locator(&self, e_pos: &[u8]) -> Polynom<F> {
+        errata_locator(e_pos)
     }
 
     /// Forney algorithm, computes the values (error magnitude) to correct the input message.
-    #[allow(non_snake_case)]
-    fn correct_errata(&self, msg: &[u8], synd: &[u8], err_pos: &[u8]) -> (Polynom, usize) {
+    fn errata_magnitudes(&self,
+                         msg_len: usize,
+                         synd: &[u8],
+                         err_pos: &[u8])
+                         -> Result<Vec<u8, { crate::POLYNOMIAL_MAX_LENGTH }>> {
         // convert the positions to coefficients degrees
-        let mut coef_pos = Polynom::with_length(err_pos.len());
+        let mut coef_pos: Polynom = Polynom::with_length(err_pos.len());
         for (i, x) in err_pos.iter().enumerate() {
-            coef_pos[i] = msg.len() as u8 - 1 - x;
+            coef_pos[i] = self.locator_power(msg_len - 1 - *x as usize);
         }
 
         let err_loc = self.find_errata_locator(&coef_pos);
-        let synd = Polynom::from(synd);
-        let err_eval = self.find_error_evaluator(&synd.reverse(), &err_loc, err_loc.len() - 1)
-            .reverse();
-
-        let mut X = Polynom::new();
-
-        for px in coef_pos.iter() {
-            let l = (255 - px) as i32;
-            X.push(gf::pow(2, -l))
-        }
-
-        let mut E = Polynom::with_length(msg.len());
-        let mut fixed = 0;
-
-        let err_eval_rev = err_eval.reverse();
-        for (i, Xi) in X.iter().enumerate() {
-            let Xi_inv = gf::inverse(*Xi);
-
-            let mut err_loc_prime_tmp = Polynom::new();
-            for (j, Xj) in X.iter().enumerate() {
-                if j != i {
-                    err_loc_prime_tmp.push(gf::sub(1, gf::mul(Xi_inv, *Xj)));
-                }
-            }
-
-            let mut err_loc_prime = 1;
-            for coef in err_loc_prime_tmp.iter() {
-                err_loc_prime = gf::mul(err_loc_prime, *coef);
+        let err_eval: Polynom<F> = error_evaluator(&synd[1..], &err_loc, err_loc.len() - 1);
+        let mut magnitudes = forney::<F, { crate::POLYNOMIAL_MAX_LENGTH }>(&err_eval, &err_loc, &coef_pos)
+            .ok_or(DecoderError::TooManyErrors)?;
+
+        // Forney's formula has an extra X^-fcr factor when roots don't start at 2^0
+        if self.fcr != 0 {
+            for (magnitude, px) in magnitudes.iter_mut().zip(coef_pos.iter()) {
+                *magnitude = F::mul(*magnitude, F::pow(2, -(self.fcr as i32 * *px as i32)));
             }
+        }
+        Ok(magnitudes)
+    }
 
-            let y = err_eval_rev.eval(Xi_inv);
-            let y = gf::mul(gf::pow(*Xi, 1), y);
-
-            let magnitude = gf::div(y, err_loc_prime);
+    fn correct_errata(&self, msg: &[u8], synd: &[u8], err_pos: &[u8]) -> Result<(Polynom, usize)> {
+        let magnitudes = self.errata_magnitudes(msg.len(), synd, err_pos)?;
 
-            let E_index = uncheck!(err_pos[i]) as usize;
-            uncheck_mut!(E[E_index]) = magnitude;
-            fixed += 1;
+        let mut msg = Polynom::from(msg);
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            uncheck_mut!(msg[*pos as usize]) ^= *magnitude;
         }
 
-        (msg.add(&E), fixed)
+        Ok((msg, magnitudes.len()))
     }
 
-    #[allow(non_snake_case)]
     fn find_error_locator(&self,
                           synd: &[u8],
                           erase_loc: Option<&[u8]>,
                           erase_count: usize)
-                          -> Result<Polynom> {
-        let (mut err_loc, mut old_loc) = if let Some(erase_loc) = erase_loc {
-            (Polynom::from(erase_loc), Polynom::from(erase_loc))
-        } else {
-            (polynom![1], polynom![1])
-        };
+                          -> Result<Polynom<F>> {
+        match (self.solver, erase_loc) {
+            (KeyEquationSolver::Euclidean, None) => euclidean(synd, self.ecc_len, erase_count),
+            _ => berlekamp_massey(synd, self.ecc_len, erase_loc, erase_count),
+        }.ok_or(DecoderError::TooManyErrors)
+    }
 
-        let synd_shift = if synd.len() > self.ecc_len {
-            synd.len() - self.ecc_len
-        } else {
-            0
+    fn find_errors(&self, err_loc: &[u8], msg_len: usize) -> Result<Polynom> {
+        let Some(iprim) = crate::encoder::power_inverse(self.prim, F::ORDER).filter(|_| self.prim != 1) else {
+            return chien_search::<F, { crate::POLYNOMIAL_MAX_LENGTH }>(err_loc, msg_len)
+                .map(|pos| Polynom::from(&pos[..]))
+                .ok_or(DecoderError::TooManyErrors);
         };
 
-        for i in 0..(self.ecc_len - erase_count) {
-            let K = if erase_loc.is_some() {
-                erase_count + i + synd_shift
-            } else {
-                i + synd_shift
-            };
-
-            let mut delta = uncheck!(synd[K]);
-            for j in 1..err_loc.len() {
-                let d_index = err_loc.len() - j - 1;
-                delta ^= gf::mul(err_loc[d_index], uncheck!(synd[K - j]));
+        // Locators of the message coefficients are scattered over all powers of 2, search them
+        // all and map the powers back to degrees
+        let roots = chien_search::<F, { crate::POLYNOMIAL_MAX_LENGTH }>(err_loc, F::ORDER)
+            .ok_or(DecoderError::TooManyErrors)?;
+        let mut err_pos = Polynom::with_length(0);
+        for root in roots.iter() {
+            let degree = (F::ORDER - 1 - *root as usize) * iprim % F::ORDER;
+            if degree >= msg_len {
+                return Err(DecoderError::TooManyErrors);
             }
+            err_pos.push((msg_len - 1 - degree) as u8);
+        }
+        Ok(err_pos)
+    }
 
-            old_loc.push(0);
+    fn forney_syndromes(&self, synd: &[u8], pos: &[u8], msg_len: usize) -> Polynom<F> {
+        let mut erase_pos_rev: Polynom = Polynom::with_length(pos.len());
+        for (i, x) in pos.iter().enumerate() {
+            erase_pos_rev[i] = self.locator_power(msg_len - 1 - *x as usize);
+        }
 
-            if delta != 0 {
-                if old_loc.len() > err_loc.len() {
-                    let new_loc = old_loc.scale(delta);
-                    old_loc = err_loc.scale(gf::inverse(delta));
-                    err_loc = new_loc;
-                }
+        let mut fsynd = Polynom::<F>::from(&synd[1..]);
 
-                err_loc = err_loc.add(&old_loc.scale(delta));
+        for pos in erase_pos_rev.iter() {
+            let x = F::pow(2, *pos as i32);
+            for j in 0..(fsynd.len() - 1) {
+                fsynd[j] = F::mul(fsynd[j], x) ^ fsynd[j + 1];
             }
         }
 
-        let shift = err_loc.iter().take_while(|&&v| v == 0).count();
-        let err_loc = Polynom::from(&err_loc[shift..]);
+        fsynd
+    }
+}
 
-        let errs = err_loc.len() - 1;
-        let errs = if erase_count > errs {
-            erase_count
-        } else {
-            (errs - erase_count) * 2 + erase_count
-        };
+/// Same fields as the serialized [`Decoder`], checked on the way in
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DecoderConfig {
+    ecc_len: usize,
+    solver: KeyEquationSolver,
+    max_corrections: usize,
+    fcr: u8,
+    prim: u8,
+}
 
-        if errs > self.ecc_len {
-            Err(DecoderError::TooManyErrors)
-        } else {
-            Ok(err_loc)
+/// Rejects a `prim` that [`Decoder::prim`] would panic on
+#[cfg(feature = "serde")]
+impl<'de, F: GaloisField<Elem = u8>> serde::Deserialize<'de> for Decoder<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let config = DecoderConfig::deserialize(deserializer)?;
+        if crate::encoder::power_inverse(config.prim, F::ORDER).is_none() {
+            return Err(serde::de::Error::custom("2^prim must be a primitive element"));
         }
+        Ok(Decoder {
+            ecc_len: config.ecc_len,
+            solver: config.solver,
+            max_corrections: config.max_corrections,
+            fcr: config.fcr,
+            prim: config.prim,
+            field: PhantomData,
+        })
     }
+}
 
-    fn find_errors(&self, err_loc: &[u8], msg_len: usize) -> Result<Polynom> {
-        let errs = err_loc.len() - 1;
-        let mut err_pos = polynom![];
+/// Streaming Reed-Solomon BCH decoder.
+///
+/// Accumulates syndromes as message bytes arrive, so no message buffer is needed,
+/// and finds the corrections on `finalize`.
+#[derive(Debug, Copy, Clone)]
+pub struct StreamDecoder<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    synd: [u8; ECC_LEN],
+    bytes_processed: usize,
+    field: PhantomData<F>,
+}
 
-        for i in 0..msg_len {
-            if err_loc.eval(gf::pow(2, i as i32)) == 0 {
-                let x = msg_len as u8 - 1 - i as u8;
-                err_pos.push(x);
-            }
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> StreamDecoder<ECC_LEN, F> {
+    /// Constructs a new `StreamDecoder` for `ECC_LEN` ecc bytes.
+    pub const fn new() -> Self {
+        Self {
+            synd: [0; ECC_LEN],
+            bytes_processed: 0,
+            field: PhantomData,
         }
+    }
 
-        if err_pos.len() != errs {
-            Err(DecoderError::TooManyErrors)
-        } else {
-            Ok(err_pos)
+    /// Feeds next byte of the block-encoded message (data followed by ecc).
+    pub fn decode_single(&mut self, byte: u8) {
+        for (i, s) in self.synd.iter_mut().enumerate() {
+            *s = F::mul(*s, F::pow(2, i as i32)) ^ byte;
         }
+        self.bytes_processed += 1;
     }
 
-    fn forney_syndromes(&self, synd: &[u8], pos: &[u8], msg_len: usize) -> Polynom {
-        let mut erase_pos_rev = Polynom::with_length(pos.len());
-        for (i, x) in pos.iter().enumerate() {
-            erase_pos_rev[i] = msg_len as u8 - 1 - x;
+    /// Finishes the block and returns `(position, magnitude)` pairs, each magnitude has to be
+    /// XOR-ed into the message byte at its position to correct it.
+    ///
+    /// The decoder is reset for the next block.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, StreamDecoder};
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let mut decoder = StreamDecoder::<4>::new();
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3, 4]).unwrap();
+    /// let mut message = vec![1, 2, 3, 4];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// message[2] = 0;
+    ///
+    /// for x in message.iter() {
+    ///     decoder.decode_single(*x);
+    /// }
+    ///
+    /// for (pos, magnitude) in decoder.finalize().unwrap() {
+    ///     message[pos as usize] ^= magnitude;
+    /// }
+    /// assert_eq!(&[1, 2, 3, 4], &message[..4]);
+    /// ```
+    pub fn finalize(&mut self) -> Result<Vec<(u8, u8), ECC_LEN>> {
+        let msg_len = self.bytes_processed;
+        let mut synd = Polynom::<F>::with_length(ECC_LEN + 1);
+        synd[1..].copy_from_slice(&self.synd);
+        self.reset();
+
+        if msg_len > F::ORDER {
+            return Err(DecoderError::MessageTooLong);
         }
 
-        let mut fsynd = Polynom::from(&synd[1..]);
+        let mut corrections = Vec::new();
+        let decoder = Decoder::<F>::with_field(ECC_LEN);
+        let Some((err_pos, magnitudes)) = decoder.find_corrections_from_syndromes(&synd, &[], msg_len)? else {
+            return Ok(corrections);
+        };
 
-        for pos in erase_pos_rev.iter() {
-            let x = gf::pow(2, *pos as i32);
-            for j in 0..(fsynd.len() - 1) {
-                fsynd[j] = gf::mul(fsynd[j], x) ^ fsynd[j + 1];
-            }
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            corrections.push((*pos, *magnitude)).map_err(|_| DecoderError::TooManyErrors)?;
         }
 
-        fsynd
+        Ok(corrections)
+    }
+
+    /// Discards partially received block.
+    pub fn reset(&mut self) {
+        self.synd = [0; ECC_LEN];
+        self.bytes_processed = 0;
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Default for StreamDecoder<ECC_LEN, F> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[cfg(test)]
+#[allow(unused_mut, clippy::bool_assert_comparison, clippy::unnecessary_mut_passed)]
 mod tests {
     use std::vec::Vec;
     use super::*;
-    use crate::Encoder;
+    use crate::{Encoder, EncoderError};
 
     #[test]
     fn calc_syndromes() {
+        crate::gf::init_test_tables();
         let px = [1, 2, 3, 4, 5, 6, 7, 8, 9];
-        let mut encoded = Encoder::<9>::new(8).encode(&px[..]);
+        let mut encoded = Encoder::<8>::new().encode(&px[..]).unwrap();
         let mut message = Vec::from(&px[..]);
         message.extend_from_slice(&encoded[..]);
 
@@ -375,10 +1185,26 @@ mod tests {
                    *Decoder::new(8).calc_syndromes(&message));
     }
 
+    #[test]
+    fn syndromes() {
+        crate::gf::init_test_tables();
+        let px = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&px[..]).unwrap();
+        let mut data = px;
+
+        assert_eq!([0; 8], *Decoder::new(8).syndromes(&data, &encoded));
+
+        data[5] = 1;
+
+        assert_eq!([7, 162, 172, 245, 176, 71, 58, 180],
+                   *Decoder::new(8).syndromes(&data, &encoded));
+    }
+
     #[test]
     fn is_corrupted() {
+        crate::gf::init_test_tables();
         let px = [1, 2, 3, 4, 5, 6, 7, 8, 9];
-        let mut encoded = Encoder::<9>::new(8).encode(&px[..]);
+        let mut encoded = Encoder::<8>::new().encode(&px[..]).unwrap();
         let mut message = Vec::new();
         message.extend_from_slice(&px[..]);
         message.extend_from_slice(&encoded[..]);
@@ -390,24 +1216,30 @@ mod tests {
         assert_eq!(true, Decoder::new(8).is_corrupted(&message));
     }
 
+    #[test]
+    fn verify() {
+        crate::gf::init_test_tables();
+        let px = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut encoded = Encoder::<8>::new().encode(&px[..]).unwrap();
+
+        assert!(Decoder::new(8).verify(&px, &encoded));
+
+        encoded[7] ^= 1;
+
+        assert!(!Decoder::new(8).verify(&px, &encoded));
+    }
+
     #[test]
     fn find_errata_locator() {
+        crate::gf::init_test_tables();
         let e_pos = [19, 18, 17, 14, 15, 16];
         assert_eq!([134, 207, 111, 227, 24, 150, 1],
                    *Decoder::new(6).find_errata_locator(&e_pos[..]));
     }
 
-    #[test]
-    fn find_error_evaluator() {
-        let synd = [232, 103, 78, 56, 109, 59, 242, 42, 64, 0];
-        let err_loc = [134, 207, 111, 227, 24, 150, 1];
-
-        assert_eq!([148, 151, 175, 126, 68, 64, 0],
-                   *Decoder::new(6).find_error_evaluator(&synd, &err_loc, 6));
-    }
-
     #[test]
     fn correct_errata() {
+        crate::gf::init_test_tables();
         let msg = [0, 0, 0, 2, 2, 2, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94, 31, 179, 149, 163];
         let synd = [0, 64, 42, 242, 59, 109, 56, 78, 103, 232];
         let err_pos = [0, 1, 2, 5, 4, 3];
@@ -415,15 +1247,16 @@ mod tests {
                       31, 179, 149, 163];
 
         assert_eq!(result,
-                   *Decoder::new(err_pos.len()).correct_errata(&msg, &synd, &err_pos).0);
+                   *Decoder::new(err_pos.len()).correct_errata(&msg, &synd, &err_pos).unwrap().0);
     }
 
     #[test]
     fn error_count() {
+        crate::gf::init_test_tables();
         let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
-        let mut encoder = Encoder::<11>::new(10);
+        let mut encoder = Encoder::<10>::new();
 
-        let encoded = encoder.encode(&msg[..]);
+        let encoded = encoder.encode(&msg[..]).unwrap();
         let mut full_message = Vec::new();
         full_message.extend_from_slice(&msg[..]);
         full_message.extend_from_slice(&encoded[..]);
@@ -436,8 +1269,420 @@ mod tests {
         assert_eq!(err, 2);
     }
 
+    #[test]
+    fn correct_in_place() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+        let orig = full_message.clone();
+
+        full_message[1] = 0;
+        full_message[12] = 0;
+        full_message[13] = 0;
+
+        let decoder = Decoder::new(8);
+        assert_eq!(Ok(4), decoder.correct_in_place(&mut full_message, Some(&[0])));
+        assert_eq!(orig, full_message);
+
+        assert_eq!(Ok(0), decoder.correct_in_place(&mut full_message, None));
+
+        // Erasures outside of the message are rejected, damaged or not
+        assert_eq!(Err(DecoderError::InvalidErasure), decoder.correct_in_place(&mut full_message, Some(&[200])));
+        full_message[3] = 0;
+        assert_eq!(Err(DecoderError::InvalidErasure), decoder.correct_in_place(&mut full_message, Some(&[18])));
+        assert_eq!(Err(DecoderError::InvalidErasure), decoder.correct(&full_message, Some(&[200])).map(|_| ()));
+        full_message[3] = 3;
+
+        // Uncorrectable message is left untouched
+        for x in full_message.iter_mut().take(5) {
+            *x = 0xff;
+        }
+        let corrupted = full_message.clone();
+        assert!(decoder.correct_in_place(&mut full_message, None).is_err());
+        assert_eq!(corrupted, full_message);
+    }
+
+    #[test]
+    fn correct_with_report() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        full_message[1] ^= 3;
+        full_message[4] ^= 7;
+        full_message[11] ^= 5;
+
+        let decoder = Decoder::new(8);
+        let report = decoder.correct_with_report(&mut full_message, Some(&[4, 11])).unwrap();
+        assert_eq!(&[1, 4, 11], report.positions());
+        assert_eq!(&[3, 7, 5], report.magnitudes());
+        assert_eq!(1, report.error_count());
+        assert_eq!(2, report.erasure_count());
+        assert!(!report.budget_exhausted());
+        assert_eq!(msg, full_message[..10]);
+
+        let report = decoder.correct_with_report(&mut full_message, None).unwrap();
+        assert_eq!(0, report.positions().len());
+        assert_eq!(Err(DecoderError::InvalidErasure),
+                   decoder.correct_with_report(&mut full_message, Some(&[18])).map(|_| ()));
+
+        for x in full_message.iter_mut().take(4) {
+            *x ^= 1;
+        }
+        let report = decoder.correct_with_report(&mut full_message, None).unwrap();
+        assert_eq!(4, report.error_count());
+        assert!(report.budget_exhausted());
+    }
+
+    #[test]
+    fn decode_to_slice() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        full_message[1] = 0;
+        full_message[2] = 0;
+        full_message[16] = 0;
+
+        let decoder = Decoder::new(8);
+        let mut out = [0; 12];
+        decoder.decode_to_slice(&full_message, Some(&[2]), &mut out).unwrap();
+        assert_eq!(msg, out[..10]);
+
+        let mut out = [0; 9];
+        assert_eq!(Err(DecoderError::BufferTooSmall), decoder.decode_to_slice(&full_message, None, &mut out));
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.decode_to_slice(&full_message[..3], None, &mut out));
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.decode_to_slice(&full_message[..8], None, &mut out));
+    }
+
+    #[test]
+    fn fcr() {
+        crate::gf::init_test_tables();
+        let msg: [u8; 30] = core::array::from_fn(|i| (i * 5) as u8);
+        for fcr in [1, 112, 120] {
+            let mut encoder = Encoder::<10>::with_fcr(fcr);
+            let mut full = Vec::from(msg);
+            full.extend_from_slice(&encoder.encode(&msg).unwrap());
+
+            let decoder = Decoder::new(10).fcr(fcr);
+            assert!(!decoder.is_corrupted(&full));
+            assert!(Decoder::new(10).is_corrupted(&full));
+
+            let mut corrupted = full.clone();
+            for i in [0, 7, 29, 35] {
+                corrupted[i] ^= 0x5a;
+            }
+            corrupted[12] = 0;
+            assert_eq!(full, &decoder.correct(&corrupted, Some(&[12])).unwrap()[..]);
+            let euclidean = Decoder::with_solver(10, KeyEquationSolver::Euclidean).fcr(fcr);
+            assert_eq!(full, &euclidean.correct(&corrupted, None).unwrap()[..]);
+
+            let erasures: Vec<u8> = (0..10).map(|i| i * 3).collect();
+            let mut erased = full.clone();
+            for i in erasures.iter() {
+                erased[*i as usize] = 0;
+            }
+            assert_eq!(full, &decoder.correct_erasures(&erased, &erasures).unwrap()[..]);
+        }
+    }
+
+    #[test]
+    fn prim() {
+        crate::gf::init_test_tables();
+        let msg: [u8; 200] = core::array::from_fn(|i| (i * 5) as u8);
+        let mut encoder = Encoder::<16>::with_roots(112, 11);
+        let mut full = Vec::from(msg);
+        full.extend_from_slice(&encoder.encode(&msg).unwrap());
+
+        let decoder = Decoder::new(16).fcr(112).prim(11);
+        assert!(!decoder.is_corrupted(&full));
+        assert!(Decoder::new(16).fcr(112).is_corrupted(&full));
+
+        let mut corrupted = full.clone();
+        for i in [0, 50, 199, 201, 215] {
+            corrupted[i] ^= 0xa5;
+        }
+        for i in [3, 100, 150] {
+            corrupted[i] = 0;
+        }
+        assert_eq!(full, &decoder.correct(&corrupted, Some(&[3, 100, 150])).unwrap()[..]);
+
+        let euclidean = Decoder::with_solver(16, KeyEquationSolver::Euclidean).fcr(112).prim(11);
+        corrupted[3] = full[3];
+        assert_eq!(full, &euclidean.correct(&corrupted, None).unwrap()[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn prim_not_primitive() {
+        let _ = Decoder::new(16).prim(5);
+    }
+
+    #[test]
+    fn decode_with_layout() {
+        crate::gf::init_test_tables();
+        let msg: [u8; 50] = core::array::from_fn(|i| (i * 9) as u8);
+        let encoder = Encoder::<8>::with_fcr(1);
+        let decoder = Decoder::new(8).fcr(1);
+
+        for layout in [Layout::DataFirst, Layout::ParityFirst, Layout::NonSystematic] {
+            let mut codeword = [0; 58];
+            encoder.encode_with_layout(&msg, layout, &mut codeword).unwrap();
+            codeword[2] ^= 1;
+            codeword[20] = 0;
+            codeword[55] ^= 0x80;
+
+            let mut out = [0; 50];
+            assert_eq!(Ok(50), decoder.decode_with_layout(&codeword, layout, Some(&[20]), &mut out));
+            assert_eq!(msg, out);
+
+            assert_eq!(Err(DecoderError::BufferTooSmall), decoder.decode_with_layout(&codeword, layout, None, &mut out[..49]));
+            assert_eq!(Err(DecoderError::TooManyErrors), decoder.decode_with_layout(&codeword[..3], layout, None, &mut out));
+            assert_eq!(Err(DecoderError::InvalidErasure), decoder.decode_with_layout(&codeword, layout, Some(&[60]), &mut out));
+        }
+    }
+
+    #[test]
+    fn correct_punctured() {
+        crate::gf::init_test_tables();
+        let msg: [u8; 20] = core::array::from_fn(|i| i as u8);
+        let mut encoder = Encoder::<10>::new();
+        let decoder = Decoder::new(10);
+
+        let mut full = Vec::from(msg);
+        full.extend_from_slice(&encoder.encode(&msg).unwrap());
+        let mut punctured = Vec::from(msg);
+        punctured.extend_from_slice(&encoder.encode_punctured(&msg, 4).unwrap());
+        assert_eq!(Err(EncoderError::TooManyPunctured), encoder.encode_punctured(&msg, 11));
+        assert_eq!(full[..26], punctured[..]);
+
+        // 4 erasures from puncturing leave room for 3 errors
+        punctured[1] = 0;
+        punctured[7] = 0;
+        punctured[22] = 0;
+        assert_eq!(full, &decoder.correct_punctured(&punctured, 4, None).unwrap()[..]);
+
+        // 1 more erasure and 2 errors
+        punctured[1] = 1;
+        punctured[9] = 0;
+        assert_eq!(full, &decoder.correct_punctured(&punctured, 4, Some(&[9])).unwrap()[..]);
+
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.correct_punctured(&punctured[..15], 11, None).map(|_| ()));
+        assert_eq!(Err(DecoderError::MessageTooLong), decoder.correct_punctured(&[0; 250], 6, None).map(|_| ()));
+    }
+
+    #[test]
+    fn decode_blocks() {
+        crate::gf::init_test_tables();
+        let msg: [u8; 600] = core::array::from_fn(|i| (i * 7) as u8);
+        let mut encoded = Vec::new();
+        for codeword in crate::BlockEncoder::<8>::new().encode(&msg) {
+            encoded.extend_from_slice(&codeword);
+        }
+        assert_eq!(624, encoded.len());
+
+        for i in [3, 250, 254, 255, 500, 620] {
+            encoded[i] ^= 0xff;
+        }
+
+        let decoder = Decoder::new(8);
+        let mut out = [0; 600];
+        assert_eq!(Ok(600), decoder.decode_blocks(&encoded, &mut out));
+        assert_eq!(msg, out);
+
+        assert_eq!(Err(DecoderError::BufferTooSmall), decoder.decode_blocks(&encoded, &mut out[..599]));
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.decode_blocks(&encoded[..518], &mut out));
+    }
+
+    #[test]
+    fn correct_erasures() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        let erasures = [0, 2, 4, 6, 8, 10, 12, 14];
+        for e in erasures.iter() {
+            full_message[*e as usize] = 0xff;
+        }
+
+        let decoder = Decoder::new(8);
+        assert!(decoder.correct(&full_message, None).is_err());
+
+        let corrected = decoder.correct_erasures(&full_message, &erasures).unwrap();
+        assert_eq!(msg, corrected.data());
+
+        // Error outside of known erasures is detected while redundancy is left
+        full_message[1] = 0xff;
+        assert!(decoder.correct_erasures(&full_message, &erasures[..6]).is_err());
+
+        let erasures = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        assert!(decoder.correct_erasures(&full_message, &erasures).is_err());
+        assert_eq!(Err(DecoderError::InvalidErasure), decoder.correct_erasures(&full_message, &[18]).map(|_| ()));
+    }
+
+    #[test]
+    fn correct_with_erasure_flags() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        let mut erased = [false; 18];
+        for e in [1, 3, 5, 7, 11, 13] {
+            full_message[e] = 0xff;
+            erased[e] = true;
+        }
+        full_message[16] ^= 0x20;
+
+        let decoder = Decoder::new(8);
+        assert!(decoder.correct(&full_message, None).is_err());
+
+        let corrected = decoder.correct_with_erasure_flags(&full_message, &erased).unwrap();
+        assert_eq!(msg, corrected.data());
+
+        erased[0] = true;
+        erased[2] = true;
+        erased[4] = true;
+        assert!(decoder.correct_with_erasure_flags(&full_message, &erased).is_err());
+        assert_eq!(Err(DecoderError::InvalidErasure),
+                   decoder.correct_with_erasure_flags(&full_message, &erased[..17]).map(|_| ()));
+    }
+
+    #[test]
+    fn message_length() {
+        crate::gf::init_test_tables();
+        let decoder = Decoder::new(8);
+        let mut long = [0; 300];
+        assert_eq!(Err(DecoderError::MessageTooLong), decoder.correct(&long, None).map(|_| ()));
+        assert_eq!(Err(DecoderError::MessageTooLong), decoder.correct_in_place(&mut long, None));
+        assert_eq!(Err(DecoderError::MessageTooLong), decoder.correct_erasures(&long, &[]).map(|_| ()));
+        assert_eq!(Err(DecoderError::MessageTooLong),
+                   decoder.correct_with_erasure_flags(&long, &[false; 300]).map(|_| ()));
+        let (data, ecc) = long.split_at_mut(250);
+        assert_eq!(Err(DecoderError::MessageTooLong), decoder.correct_split_in_place(data, ecc, None));
+
+        // Shorter than ecc
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.correct(&[1, 2, 3], None).map(|_| ()));
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.correct_erasures(&[1, 2, 3], &[]).map(|_| ()));
+        assert_eq!(Err(DecoderError::TooManyErrors), decoder.correct_punctured(&[1, 2, 3], 1, None).map(|_| ()));
+    }
+
+    #[test]
+    fn check() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        let decoder = Decoder::new(8);
+        assert_eq!(BlockStatus::Clean, decoder.check(&full_message, None));
+
+        for i in 0..3 {
+            full_message[i * 3] ^= 0x42;
+        }
+        assert_eq!(BlockStatus::Correctable(4), decoder.check(&full_message, Some(&[1])));
+
+        full_message[9] ^= 0x42;
+        let copy = full_message.clone();
+        assert_eq!(BlockStatus::Correctable(4), decoder.check(&full_message, None));
+        assert_eq!(copy, full_message);
+
+        full_message[13] ^= 0x17;
+        full_message[15] ^= 0xa5;
+        assert_eq!(BlockStatus::Uncorrectable, decoder.check(&full_message, None));
+    }
+
+    #[test]
+    fn find_corrections() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        let decoder = Decoder::new(8);
+        assert!(decoder.find_corrections(&full_message, &[]).unwrap().is_none());
+        assert_eq!(0, decoder.correct_in_place(&mut full_message, None).unwrap());
+
+        full_message[4] ^= 0x01;
+        let (err_pos, magnitudes) = decoder.find_corrections(&full_message, &[]).unwrap().unwrap();
+        assert_eq!([4], err_pos[..]);
+        assert_eq!([0x01], magnitudes[..]);
+    }
+
+    #[test]
+    fn limit_corrections() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+
+        let decoder = Decoder::new(8).limit_corrections(3);
+        for i in 0..3 {
+            full_message[i * 2] ^= 0x33;
+        }
+        assert_eq!(msg, decoder.correct(&full_message, None).unwrap().data());
+        assert_eq!(Some(DecoderError::TooManyCorrections), decoder.correct(&full_message, Some(&[9])).err());
+
+        full_message[7] ^= 0x33;
+        assert_eq!(Some(DecoderError::TooManyCorrections), decoder.correct(&full_message, None).err());
+        assert_eq!(msg, Decoder::new(8).correct(&full_message, None).unwrap().data());
+
+        let erasures = [0, 2, 4, 7];
+        assert_eq!(Some(DecoderError::TooManyCorrections), decoder.correct_erasures(&full_message, &erasures).err());
+        assert_eq!(msg, Decoder::new(8).correct_erasures(&full_message, &erasures).unwrap().data());
+    }
+
+    #[test]
+    fn correct_split_in_place() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut data = msg;
+        let mut ecc = [0; 8];
+        ecc.copy_from_slice(&encoded);
+
+        let decoder = Decoder::new(8);
+        assert_eq!(0, decoder.correct_split_in_place(&mut data, &mut ecc, None).unwrap());
+
+        data[0] = 0xff;
+        data[9] = 0xff;
+        ecc[0] = 0xff;
+        ecc[7] = 0xff;
+        assert_eq!(4, decoder.correct_split_in_place(&mut data, &mut ecc, Some(&[17])).unwrap());
+        assert_eq!(msg, data);
+        assert_eq!(encoded[..], ecc);
+
+        for x in data.iter_mut().take(5) {
+            *x = 0xff;
+        }
+        let damaged = data;
+        assert!(decoder.correct_split_in_place(&mut data, &mut ecc, None).is_err());
+        assert_eq!(damaged, data);
+    }
+
     #[test]
     fn find_error_locator() {
+        crate::gf::init_test_tables();
         let synd = [79, 25, 0, 160, 198, 122, 192, 169, 232];
         let nsym = 9;
         let erase_loc = None;
@@ -453,7 +1698,8 @@ mod tests {
 
     #[test]
     fn find_errors() {
-        let err_loc = [1, 121, 144, 193];
+        crate::gf::init_test_tables();
+        let err_loc = [193, 144, 121, 1];
         let msg_len = 20;
         let result = [5, 4, 3];
 
@@ -462,7 +1708,7 @@ mod tests {
         assert!(err_pos.is_ok());
         assert_eq!(result, *err_pos.unwrap());
 
-        let err_loc = [1, 134, 181];
+        let err_loc = [181, 134, 1];
         let msg_len = 12;
 
         let err_pos = Decoder::new(6).find_errors(&err_loc, msg_len);
@@ -472,6 +1718,7 @@ mod tests {
 
     #[test]
     fn forney_syndromes() {
+        crate::gf::init_test_tables();
         let synd = [0, 64, 42, 242, 59, 109, 56, 78, 103, 232];
         let pos = [0, 1, 2];
         let nmess = 20;
@@ -483,6 +1730,7 @@ mod tests {
 
     #[test]
     fn decode() {
+        crate::gf::init_test_tables();
         let mut msg = [0, 2, 2, 2, 2, 2, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94, 31, 179, 149, 163];
         let ecc = 9;
         let erase_pos = [0, 1, 2];
@@ -495,4 +1743,131 @@ mod tests {
 
         assert_eq!(result, **decoded);
     }
+
+    #[test]
+    fn stream_decoder() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8>::new().encode(&msg[..]).unwrap();
+        let mut full_message = Vec::new();
+        full_message.extend_from_slice(&msg[..]);
+        full_message.extend_from_slice(&encoded[..]);
+        let orig = full_message.clone();
+
+        let mut decoder = StreamDecoder::<8>::new();
+
+        for x in full_message.iter() {
+            decoder.decode_single(*x);
+        }
+        assert_eq!(0, decoder.finalize().unwrap().len());
+
+        full_message[0] = 0xff;
+        full_message[9] = 0;
+        full_message[17] = 0;
+
+        for x in full_message.iter() {
+            decoder.decode_single(*x);
+        }
+        let corrections = decoder.finalize().unwrap();
+        assert_eq!(3, corrections.len());
+        for (pos, magnitude) in corrections {
+            full_message[pos as usize] ^= magnitude;
+        }
+        assert_eq!(orig, full_message);
+
+        for x in full_message.iter_mut().take(5) {
+            *x = 0xff;
+        }
+        for x in full_message.iter() {
+            decoder.decode_single(*x);
+        }
+        assert!(decoder.finalize().is_err());
+    }
+
+    #[test]
+    fn euclidean_solver() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<10>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        let bm = Decoder::new(10);
+        let euclid = Decoder::with_solver(10, KeyEquationSolver::Euclidean);
+
+        for errors in 0..7 {
+            for erasures in 0..5 {
+                let mut corrupted = orig.clone();
+                let erase_pos: Vec<u8> = (0..erasures as u8).map(|x| x * 3 + 1).collect();
+                for x in erase_pos.iter() {
+                    corrupted[*x as usize] = 0;
+                }
+                for i in 0..errors {
+                    corrupted[19 - i * 2] ^= 0x5a;
+                }
+
+                let result = euclid.correct(&corrupted, Some(&erase_pos));
+                if 2 * errors + erasures <= 10 {
+                    assert_eq!(orig, result.unwrap()[..]);
+                    assert_eq!(orig, bm.correct(&corrupted, Some(&erase_pos)).unwrap()[..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn primitive_poly() {
+        crate::gf::init_test_tables();
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let encoded = Encoder::<8, Gf2p8<0x187>>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        // Codeword of one field is not a codeword of another
+        assert!(Decoder::new(8).is_corrupted(&orig));
+
+        let decoder = Decoder::<Gf2p8<0x187>>::with_field(8);
+        assert!(!decoder.is_corrupted(&orig));
+
+        for errors in 0..5 {
+            for erasures in 0..(9 - 2 * errors) {
+                let mut corrupted = orig.clone();
+                let erase_pos: Vec<u8> = (0..erasures as u8).map(|x| x * 2 + 1).collect();
+                for x in erase_pos.iter() {
+                    corrupted[*x as usize] = 0;
+                }
+                for i in 0..errors {
+                    corrupted[16 - i * 2] ^= 0xa5;
+                }
+
+                let result = decoder.correct(&corrupted, Some(&erase_pos));
+                assert_eq!(orig, result.unwrap()[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn galois_field() {
+        use crate::gf::gf4::Gf2p4;
+
+        let msg = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+        let encoded = Encoder::<4, Gf2p4>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        // Same codeword as the dedicated GF(2^4) encoder produces
+        assert_eq!(crate::Encoder4::new(4).encode(&msg), encoded);
+
+        let decoder = Decoder::<Gf2p4>::with_field(4);
+        let mut corrupted = orig.clone();
+        corrupted[0] = 0;
+        corrupted[13] ^= 0xf;
+        assert_eq!(orig, decoder.correct(&corrupted, None).unwrap()[..]);
+
+        corrupted[7] = 0;
+        assert_eq!(orig, decoder.correct(&corrupted, Some(&[0, 7])).unwrap()[..]);
+    }
 }
@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use crate::encoder::{field_generator_poly, lfsr_run, power_inverse, EncoderError};
+use crate::gf::{GaloisField, Gf2p8};
+
+/// Reed-Solomon BCH encoder whose ecc length is chosen at runtime, e.g. negotiated per
+/// connection, instead of being a type parameter like in [`Encoder`](crate::Encoder)
+#[derive(Debug, Clone)]
+pub struct DynEncoder<F: GaloisField<Elem = u8> = Gf2p8> {
+    /// Generator polynomial without its leading 1
+    generator: Vec<u8>,
+    lgenerator: Vec<u8>,
+    field: PhantomData<F>,
+}
+
+impl DynEncoder {
+    /// Constructs a new `DynEncoder` producing `ecc_len` ecc bytes.
+    ///
+    /// Errors if `ecc_len` leaves no room for data in a codeword.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{DynEncoder, Encoder};
+    ///
+    /// let ecc_len = 8;
+    /// let encoder = DynEncoder::new(ecc_len).unwrap();
+    ///
+    /// assert_eq!(Encoder::<8>::new().encode(b"Hello").unwrap()[..], encoder.encode(b"Hello").unwrap());
+    /// ```
+    pub fn new(ecc_len: usize) -> Result<Self, EncoderError> {
+        Self::with_field(ecc_len)
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> DynEncoder<F> {
+    /// Same as [`DynEncoder::new`] over the field `F`, e.g. `DynEncoder::<Gf2p8<0x187>>::with_field(16)`.
+    pub fn with_field(ecc_len: usize) -> Result<Self, EncoderError> {
+        Self::with_roots(ecc_len, 0, 1)
+    }
+
+    /// Same as [`with_field`](Self::with_field) with generator roots of [`Encoder::with_roots`](crate::Encoder::with_roots).
+    ///
+    /// # Panics
+    /// If `2^prim` doesn't generate the whole field.
+    pub fn with_roots(ecc_len: usize, fcr: u8, prim: u8) -> Result<Self, EncoderError> {
+        assert!(power_inverse(prim, F::ORDER).is_some(), "2^prim must be a primitive element");
+        if ecc_len >= F::ORDER {
+            return Err(EncoderError::EccLenMismatch);
+        }
+
+        let generator = Vec::from(&field_generator_poly::<F>(ecc_len, fcr, prim)[1..]);
+        let lgenerator = generator.iter()
+            .map(|g| if *g == 0 { 0 } else { F::log(*g) as u8 })
+            .collect();
+        Ok(Self {
+            generator,
+            lgenerator,
+            field: PhantomData,
+        })
+    }
+
+    /// Number of ecc bytes per codeword
+    pub fn ecc_len(&self) -> usize {
+        self.generator.len()
+    }
+
+    /// Encodes passed `&[u8]` slice and returns its [`ecc_len`](Self::ecc_len) ecc bytes.
+    ///
+    /// Errors if the data doesn't fit a single block together with ecc.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>, EncoderError> {
+        let mut ecc = alloc::vec![0; self.ecc_len()];
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(ecc)
+    }
+
+    /// Encodes passed `&[u8]` slice and writes its ecc bytes into `ecc_out`, which must be
+    /// exactly [`ecc_len`](Self::ecc_len) bytes long.
+    pub fn encode_to_slice(&self, data: &[u8], ecc_out: &mut [u8]) -> Result<(), EncoderError> {
+        if ecc_out.len() != self.ecc_len() {
+            return Err(EncoderError::EccLenMismatch);
+        }
+        if data.len() + self.ecc_len() > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        ecc_out.fill(0);
+        lfsr_run::<F>(&self.generator, &self.lgenerator, ecc_out, data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynEncoder;
+    use crate::{Encoder, EncoderError};
+
+    #[test]
+    fn matches_encoder() {
+        crate::gf::init_test_tables();
+        let data: [u8; 200] = core::array::from_fn(|i| (i * 13) as u8);
+        for (ecc_len, expected) in [
+            (0, Encoder::<0>::new().encode(&data).unwrap().to_vec()),
+            (4, Encoder::<4>::new().encode(&data).unwrap().to_vec()),
+            (32, Encoder::<32>::new().encode(&data).unwrap().to_vec()),
+        ] {
+            let encoder = DynEncoder::new(ecc_len).unwrap();
+            assert_eq!(ecc_len, encoder.ecc_len());
+            assert_eq!(expected, encoder.encode(&data).unwrap());
+        }
+
+        let fcr = DynEncoder::<crate::gf::Gf2p8<0x187>>::with_roots(16, 112, 11).unwrap();
+        let expected = Encoder::<16, crate::gf::Gf2p8<0x187>>::with_roots(112, 11).encode(&data).unwrap();
+        assert_eq!(expected[..], fcr.encode(&data).unwrap());
+
+        assert_eq!(Err(EncoderError::EccLenMismatch), DynEncoder::new(255).map(|_| ()));
+        assert_eq!(Err(EncoderError::MessageTooLong), DynEncoder::new(56).unwrap().encode(&data));
+        assert_eq!(Err(EncoderError::EccLenMismatch), DynEncoder::new(4).unwrap().encode_to_slice(&data, &mut [0; 3]));
+    }
+}
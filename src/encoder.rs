@@ -1,166 +1,1036 @@
-use core::convert::TryInto;
-use super::gf::poly_math::*;
-use super::gf;
+use core::marker::PhantomData;
+use super::gf::poly::Polynom;
+use super::buffer::Buffer;
+use super::gf::{GaloisField, Gf2p8};
 use heapless::Vec;
 
-/// Reed-Solomon BCH encoder
+/// Encoder error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EncoderError {
+    /// Requested or output buffer ecc length differs from the one of the encoder
+    EccLenMismatch,
+    /// Data is longer than a single block
+    MessageTooLong,
+    /// Streaming block length is zero or doesn't fit a codeword together with ecc, or data
+    /// can't be split evenly between interleaved codewords
+    InvalidBlockLength,
+    /// No bytes were fed since the last block boundary, returned by [`legacy::Encoder`](crate::legacy::Encoder)
+    NothingToFinalize,
+    /// More ecc bytes are punctured than the encoder produces
+    TooManyPunctured,
+    /// Output buffer or serialized state doesn't have the expected length, or a batch output has
+    /// a different number of entries than the input
+    BufferLenMismatch,
+}
+
+/// Arrangement of data and ecc in a codeword
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// Data followed by ecc
+    #[default]
+    DataFirst,
+    /// Ecc followed by data, as expected by some hardware framers
+    ParityFirst,
+    /// Data polynomial multiplied by the generator, data isn't readable without decoding
+    NonSystematic,
+}
+
+/// Reed-Solomon BCH encoder producing `ECC_LEN` ecc bytes
+///
+/// Works over the default 0x11d field unless another byte field is given as `F`,
+/// e.g. `Encoder::<16, Gf2p8<0x187>>` for CCSDS.
 #[derive(Debug)]
-pub struct Encoder<const ECC_BYTE_COUNT_STORE: usize> {
-    generator: [u8; ECC_BYTE_COUNT_STORE],
-    lgenerator: [u8; ECC_BYTE_COUNT_STORE],
-    scratch_space: Vec<u8, ECC_BYTE_COUNT_STORE>,
-    bytes_processed: u8,
+pub struct Encoder<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    /// Generator polynomial without its leading 1
+    generator: [u8; ECC_LEN],
+    lgenerator: [u8; ECC_LEN],
+    field: PhantomData<F>,
 }
 
-impl<const ECC_BYTE_COUNT_STORE: usize> Encoder<ECC_BYTE_COUNT_STORE> {
-    const fn make_lgenerator(generator: &[u8; ECC_BYTE_COUNT_STORE]) -> [u8; ECC_BYTE_COUNT_STORE] {
-        let mut lgen = [0u8; ECC_BYTE_COUNT_STORE];
-        let mut i = 0;
-        while i < generator.len() {
-            lgen[i] = gf::LOG[generator[i] as usize];
-            i += 1;
-        }
-        lgen
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Encoder<ECC_LEN, F> {
+    /// Constructs a new `Encoder` and calculates its generator polynomial.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let encoder = Encoder::<8>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_fcr(0)
     }
 
-    /// Constructs a new `Encoder` and calculates generator polynomial of given `ecc_len`.
+    /// Constructs a new `Encoder` whose generator polynomial has roots `2^fcr, .., 2^(fcr + ECC_LEN - 1)`
+    /// instead of starting at `2^0`, e.g. `fcr = 112` for CCSDS. The decoder has to be built
+    /// with the same [`Decoder::fcr`](crate::Decoder::fcr).
     ///
     /// # Example
     /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
     /// use reed_solomon::Encoder;
     ///
-    /// let encoder = Encoder::<9>::new(8);
+    /// let encoder = Encoder::<8>::with_fcr(1);
     /// ```
-    pub fn new(ecc_len: usize) -> Self {
-        debug_assert!(ecc_len == ECC_BYTE_COUNT_STORE - 1, "ECC length must be ECC_BYTE_COUNT_STORE - 1");
-        let generator: [u8; ECC_BYTE_COUNT_STORE] = generator_poly(ecc_len).try_into().unwrap();
+    pub fn with_fcr(fcr: u8) -> Self {
+        Self::with_roots(fcr, 1)
+    }
 
+    /// Constructs a new `Encoder` whose generator polynomial has roots `b^fcr, .., b^(fcr + ECC_LEN - 1)`
+    /// for `b = 2^prim`, e.g. `fcr = 112, prim = 11` for CCSDS. The decoder has to be built
+    /// with the same [`Decoder::fcr`](crate::Decoder::fcr) and [`Decoder::prim`](crate::Decoder::prim).
+    ///
+    /// # Panics
+    /// If `2^prim` doesn't generate the whole field, i.e. `prim` and the field order have a
+    /// common factor.
+    pub fn with_roots(fcr: u8, prim: u8) -> Self {
+        assert!(power_inverse(prim, F::ORDER).is_some(), "2^prim must be a primitive element");
+        let generator = field_generator_poly::<F>(ECC_LEN, fcr, prim);
         Self::new_with_precomputed_generator(&generator)
     }
 
+    /// Constructs a new `Encoder` from a precomputed generator polynomial.
+    ///
+    /// The generator must be `ECC_LEN + 1` bytes long, e.g. one of the `ENCODE_GEN_*` constants.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, ENCODE_GEN_8_ECC_BYTES};
+    ///
+    /// let encoder = Encoder::<8>::new_with_precomputed_generator(&ENCODE_GEN_8_ECC_BYTES);
+    /// ```
+    ///
+    /// # Panics
+    /// If the generator length isn't `ECC_LEN + 1` or its leading coefficient isn't 1.
+    #[cfg(not(feature = "external_tables"))]
+    pub const fn new_with_precomputed_generator(generator: &[u8]) -> Self {
+        let generator = monic_tail(generator);
+        Self {
+            lgenerator: log_generator::<F, ECC_LEN>(&generator),
+            generator,
+            field: PhantomData,
+        }
+    }
 
-    // generator_poly should be called to produce an array to be passed into this function
-    // The array should be ecc_len + 1 bytes long
-    pub const fn new_with_precomputed_generator(generator: &[u8; ECC_BYTE_COUNT_STORE]) -> Self {
+    /// Constructs a new `Encoder` from a precomputed generator polynomial.
+    ///
+    /// Not `const` with `external_tables`, since the LOG table is only known at runtime.
+    #[cfg(feature = "external_tables")]
+    pub fn new_with_precomputed_generator(generator: &[u8]) -> Self {
+        let generator = monic_tail(generator);
         Self {
-            lgenerator: Self::make_lgenerator(generator),
-            generator: *generator,
-            scratch_space: Vec::new(),
-            bytes_processed: 0,
+            lgenerator: log_generator::<F, ECC_LEN>(&generator),
+            generator,
+            field: PhantomData,
         }
     }
 
-    /// Encodes passed `&[u8]` slice and returns `Buffer` with result and `ecc` offset.
+    /// Coefficients of the generator polynomial after its leading 1, highest degree first.
+    ///
+    /// These are the taps of the encoding shift register, e.g. to load into a hardware
+    /// implementation.
     ///
     /// # Example
     /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, ENCODE_GEN_4_ECC_BYTES};
+    ///
+    /// assert_eq!(ENCODE_GEN_4_ECC_BYTES[1..], *Encoder::<4>::new().generator());
+    /// ```
+    pub fn generator(&self) -> &[u8] {
+        &self.generator
+    }
+
+    /// Same as [`generator`](Self::generator), but lowest degree first.
+    pub fn generator_reversed(&self) -> [u8; ECC_LEN] {
+        let mut reversed = self.generator;
+        reversed.reverse();
+        reversed
+    }
+
+    /// Logarithms of [`generator`](Self::generator) coefficients, i.e. `2^log = coefficient`.
+    ///
+    /// Zero coefficients have no logarithm and their entries are meaningless.
+    pub fn generator_log(&self) -> &[u8] {
+        &self.lgenerator
+    }
+
+    /// Encodes passed `&[u8]` slice and returns its `ECC_LEN` ecc bytes.
+    ///
+    /// Errors if the data doesn't fit a single block together with ecc.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
     /// use reed_solomon::Encoder;
     ///
     /// let data = "Hello World".as_bytes();
-    /// let mut encoder = Encoder::<9>::new(8);
+    /// let mut encoder = Encoder::<8>::new();
     ///
-    /// let encoded = encoder.encode(&data);
+    /// let encoded = encoder.encode(&data).unwrap();
     ///
     /// println!("ecc:   {:?}", encoded);
     /// ```
-    pub fn encode(&mut self, data: &[u8]) -> Vec<u8, ECC_BYTE_COUNT_STORE> {
-        debug_assert!(data.len() < 256 - self.generator.len(), "Data isnt a single chunk long or less");
-        let mut ecc = Vec::<u8, ECC_BYTE_COUNT_STORE>::new();
-        for byte in data.iter() {
-            ecc = self.encode_single(*byte);
+    pub fn encode(&mut self, data: &[u8]) -> Result<Vec<u8, ECC_LEN>, EncoderError> {
+        let mut ecc = [0; ECC_LEN];
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(unwrap_unchecked!(Vec::from_slice(&ecc)))
+    }
+
+    /// Encodes passed `&[u8]` slice and writes its ecc bytes into `ecc_out`, which must be
+    /// exactly `ECC_LEN` bytes long.
+    ///
+    /// Doesn't touch the state of the streaming encoder, so a partially fed block is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let encoder = Encoder::<8>::new();
+    /// let mut page = [0; 19];
+    /// page[..11].copy_from_slice(b"Hello World");
+    ///
+    /// let (data, ecc) = page.split_at_mut(11);
+    /// encoder.encode_to_slice(data, ecc).unwrap();
+    /// ```
+    pub fn encode_to_slice(&self, data: &[u8], ecc_out: &mut [u8]) -> Result<(), EncoderError> {
+        if ecc_out.len() != ECC_LEN {
+            return Err(EncoderError::EccLenMismatch);
+        }
+        if data.len() + ECC_LEN > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        ecc_out.fill(0);
+        lfsr_run::<F>(&self.generator, &self.lgenerator, ecc_out, data);
+        Ok(())
+    }
+
+    /// Encodes passed `&[u8]` slice and returns its ecc bytes without the last `punctured`
+    /// ones, to be restored as erasures by [`Decoder::correct_punctured`](crate::Decoder::correct_punctured).
+    ///
+    /// Errors with `TooManyPunctured` if more than `ECC_LEN` bytes are punctured.
+    pub fn encode_punctured(&self, data: &[u8], punctured: usize) -> Result<Vec<u8, ECC_LEN>, EncoderError> {
+        if punctured > ECC_LEN {
+            return Err(EncoderError::TooManyPunctured);
+        }
+
+        let mut ecc = [0; ECC_LEN];
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(unwrap_unchecked!(Vec::from_slice(&ecc[..ECC_LEN - punctured])))
+    }
+
+    /// Encodes the concatenation of `parts` as a single message and returns its ecc bytes,
+    /// e.g. a header and a payload without copying them into one buffer first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let mut encoder = Encoder::<8>::new();
+    /// let ecc = encoder.encode_vectored(&[b"Hello", b" ", b"World"]).unwrap();
+    ///
+    /// assert_eq!(encoder.encode(b"Hello World").unwrap(), ecc);
+    /// ```
+    pub fn encode_vectored(&self, parts: &[&[u8]]) -> Result<Vec<u8, ECC_LEN>, EncoderError> {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        if len + ECC_LEN > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        let mut ecc = [0; ECC_LEN];
+        for part in parts.iter() {
+            lfsr_run::<F>(&self.generator, &self.lgenerator, &mut ecc, part);
+        }
+        Ok(unwrap_unchecked!(Vec::from_slice(&ecc)))
+    }
+
+    /// Encodes every message of `msgs` and writes its ecc bytes into the matching entry of `out`,
+    /// e.g. to protect many small records in one call.
+    ///
+    /// Errors before writing anything if `out` has a different number of entries than `msgs`
+    /// or a message doesn't fit a single block together with ecc.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let mut encoder = Encoder::<4>::new();
+    /// let mut ecc = [[0; 4]; 2];
+    /// encoder.encode_batch(&[b"first", b"second"], &mut ecc).unwrap();
+    ///
+    /// assert_eq!(encoder.encode(b"second").unwrap(), ecc[1]);
+    /// ```
+    pub fn encode_batch(&self, msgs: &[&[u8]], out: &mut [[u8; ECC_LEN]]) -> Result<(), EncoderError> {
+        if msgs.len() != out.len() {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+        if msgs.iter().any(|msg| msg.len() + ECC_LEN > F::ORDER) {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        for (msg, ecc) in msgs.iter().zip(out.iter_mut()) {
+            *ecc = [0; ECC_LEN];
+            lfsr_run::<F>(&self.generator, &self.lgenerator, ecc, msg);
+        }
+        Ok(())
+    }
+
+    /// Encodes passed `&[u8]` slice into a whole codeword arranged as `layout`, `out` must be
+    /// exactly `data.len() + ECC_LEN` bytes long.
+    ///
+    /// [`Decoder::decode_with_layout`](crate::Decoder::decode_with_layout) decodes it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, Layout};
+    ///
+    /// let mut encoder = Encoder::<8>::new();
+    /// let mut codeword = [0; 19];
+    /// encoder.encode_with_layout(b"Hello World", Layout::ParityFirst, &mut codeword).unwrap();
+    ///
+    /// assert_eq!(encoder.encode(b"Hello World").unwrap(), codeword[..8]);
+    /// assert_eq!(b"Hello World", &codeword[8..]);
+    /// ```
+    pub fn encode_with_layout(&self, data: &[u8], layout: Layout, out: &mut [u8]) -> Result<(), EncoderError> {
+        if out.len() != data.len() + ECC_LEN {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+
+        match layout {
+            Layout::DataFirst => {
+                let (data_out, ecc) = out.split_at_mut(data.len());
+                self.encode_to_slice(data, ecc)?;
+                data_out.copy_from_slice(data);
+            }
+            Layout::ParityFirst => {
+                let (ecc, data_out) = out.split_at_mut(ECC_LEN);
+                self.encode_to_slice(data, ecc)?;
+                data_out.copy_from_slice(data);
+            }
+            Layout::NonSystematic => {
+                if out.len() > F::ORDER {
+                    return Err(EncoderError::MessageTooLong);
+                }
+
+                let mut generator: Polynom<F> = polynom![1];
+                for x in self.generator.iter() {
+                    generator.push(*x);
+                }
+                out.copy_from_slice(&Polynom::<F>::from(data).mul(&generator));
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes passed `&[u8]` slice and returns the whole codeword: data followed by ecc.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let encoder = Encoder::<8>::new();
+    /// let buffer = encoder.encode_buffer(b"Hello World").unwrap();
+    ///
+    /// assert_eq!(b"Hello World", buffer.data());
+    /// assert_eq!(8, buffer.ecc().len());
+    /// assert_eq!(19, buffer.len());
+    /// ```
+    pub fn encode_buffer(&self, data: &[u8]) -> Result<Buffer, EncoderError> {
+        if data.len() + ECC_LEN > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        let mut buffer = Buffer::from_slice(data, data.len());
+        buffer.set_length(data.len() + ECC_LEN);
+        let (data, ecc) = buffer.split_at_mut(data.len());
+        self.encode_to_slice(data, ecc)?;
+        Ok(buffer)
+    }
+
+    /// Starts streaming a block, the encoder is given back along with the parity by
+    /// [`Filling::finalize`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let mut stream = Encoder::<8>::new().start();
+    /// for x in b"Hello World" {
+    ///     stream.encode_single(*x);
+    /// }
+    /// let (mut encoder, ecc) = stream.finalize();
+    ///
+    /// assert_eq!(encoder.encode(b"Hello World").unwrap()[..], ecc.unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// If `ECC_LEN` leaves no room for data in a codeword.
+    pub fn start(self) -> Filling<ECC_LEN, F> {
+        assert!(ECC_LEN < F::ORDER, "no room for data in a codeword");
+        Filling {
+            encoder: self,
+            remainder: [0; ECC_LEN],
+            bytes_processed: 0,
+            block_len: F::ORDER - ECC_LEN,
+        }
+    }
+
+    /// Resumes streaming encoding from a snapshot taken by [`Filling::export_state`].
+    ///
+    /// The encoder must have the same generator polynomial as the one which took the snapshot.
+    /// Errors if the block length of the snapshot is invalid for this encoder or already reached.
+    pub fn import_state(self, state: &StreamState<ECC_LEN>) -> Result<Filling<ECC_LEN, F>, EncoderError> {
+        let mut stream = self.start_with_block_len(state.block_len as usize)?;
+        if state.bytes_processed >= state.block_len {
+            return Err(EncoderError::InvalidBlockLength);
         }
-        if let Ok(ecc) = self.finalize() {
-            ecc
-        } else {
-            ecc
+        stream.remainder = state.remainder;
+        stream.bytes_processed = state.bytes_processed as usize;
+        Ok(stream)
+    }
+
+    /// Starts streaming encoding with parity after every `block_len` data bytes, same as
+    /// [`start`](Self::start) followed by [`Filling::with_block_len`].
+    ///
+    /// Errors if `block_len` is zero or longer than `255 - ECC_LEN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let stream = Encoder::<8>::new().start_with_block_len(247).unwrap();
+    /// assert_eq!(247, stream.block_len());
+    /// assert!(Encoder::<8>::new().start_with_block_len(248).is_err());
+    /// ```
+    pub fn start_with_block_len(self, block_len: usize) -> Result<Filling<ECC_LEN, F>, EncoderError> {
+        if block_len == 0 || block_len + ECC_LEN > F::ORDER {
+            return Err(EncoderError::InvalidBlockLength);
         }
+        Ok(Filling {
+            encoder: self,
+            remainder: [0; ECC_LEN],
+            bytes_processed: 0,
+            block_len,
+        })
     }
+}
 
-    pub fn encode_single(&mut self, data: u8) -> Vec<u8, ECC_BYTE_COUNT_STORE> {
-        //First fill up scratch space
-        if self.scratch_space.len() < self.generator.len() {
-            unsafe { self.scratch_space.push(data).unwrap_unchecked() };
-            self.bytes_processed += 1;
-            return unsafe { Vec::from_slice(&[data]).unwrap_unchecked() };
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Default for Encoder<ECC_LEN, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialized as its `ECC_LEN + 1` bytes generator polynomial, which also carries the roots
+/// chosen with [`Encoder::with_roots`]
+#[cfg(feature = "serde")]
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> serde::Serialize for Encoder<ECC_LEN, F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(core::iter::once(&1).chain(self.generator.iter()))
+    }
+}
+
+/// Accepts the generator polynomial as [`Encoder::new_with_precomputed_generator`] does, but
+/// errors instead of panicking on a bad one
+#[cfg(feature = "serde")]
+impl<'de, const ECC_LEN: usize, F: GaloisField<Elem = u8>> serde::Deserialize<'de> for Encoder<ECC_LEN, F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let generator = Polynom::<F>::deserialize(deserializer)?;
+        if generator.len() != ECC_LEN + 1 {
+            return Err(D::Error::invalid_length(generator.len(), &"ECC_LEN + 1 generator coefficients"));
+        }
+        if generator[0] != 1 {
+            return Err(D::Error::custom("generator must be monic"));
         }
+        Ok(Self::new_with_precomputed_generator(&generator))
+    }
+}
+
+/// Output of [`Filling::encode_single`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StreamOut<const ECC_LEN: usize> {
+    /// The fed byte, the block isn't full yet
+    Byte(u8),
+    /// The fed byte completed a block, its parity follows it
+    BlockComplete {
+        /// The fed byte, last data byte of the block
+        last: u8,
+        /// Error correction code of the block
+        parity: [u8; ECC_LEN],
+    },
+}
 
-        self.run_encoding_round();
+/// Streaming encoder in the middle of a block, created by [`Encoder::start`].
+///
+/// Parity is only available by consuming it with [`finalize`](Filling::finalize), so no bytes
+/// can be fed after it and a stream can't be dropped unnoticed with a pending block.
+#[derive(Debug)]
+#[must_use = "pending block is lost unless finalized"]
+pub struct Filling<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    encoder: Encoder<ECC_LEN, F>,
+    /// Remainder of the streamed block so far
+    remainder: [u8; ECC_LEN],
+    /// Data bytes fed into the current block
+    bytes_processed: usize,
+    /// Data bytes per block
+    block_len: usize,
+}
 
-        self.scratch_space.rotate_left(1);
-        self.scratch_space[self.generator.len() - 1] = data;
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Filling<ECC_LEN, F> {
+    /// Feeds a single byte into the streaming encoder.
+    ///
+    /// Returns the byte itself, together with the error correction code once a full block of
+    /// `255 - ECC_LEN` bytes has been processed. The next byte starts a new block.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, StreamOut};
+    ///
+    /// let mut stream = Encoder::<8>::new().start();
+    /// for x in 0..246 {
+    ///     assert_eq!(StreamOut::Byte(x), stream.encode_single(x));
+    /// }
+    /// assert!(matches!(stream.encode_single(246), StreamOut::BlockComplete { last: 246, .. }));
+    /// ```
+    pub fn encode_single(&mut self, data: u8) -> StreamOut<ECC_LEN> {
+        lfsr_step::<F>(&self.encoder.generator, &self.encoder.lgenerator, &mut self.remainder, data);
 
         self.bytes_processed += 1;
-        if self.bytes_processed == (256 - self.generator.len()) as u8 {
-            let mut ecc = unsafe { self.finalize().unwrap_unchecked() };
-            unsafe { ecc.insert(0, data).unwrap_unchecked() };
-            return ecc;
+        if self.bytes_processed == self.block_len {
+            let parity = self.remainder;
+            self.remainder = [0; ECC_LEN];
+            self.bytes_processed = 0;
+            return StreamOut::BlockComplete { last: data, parity };
+        }
+        StreamOut::Byte(data)
+    }
+
+    /// Emits parity after every `block_len` data bytes instead of `255 - ECC_LEN`, for
+    /// protocols with short fixed-size records.
+    ///
+    /// Errors if `block_len` is longer than `255 - ECC_LEN` or already reached by the
+    /// current block, including zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, StreamOut};
+    ///
+    /// let mut stream = Encoder::<8>::new().start().with_block_len(56).unwrap();
+    /// for x in 0..55 {
+    ///     assert_eq!(StreamOut::Byte(x), stream.encode_single(x));
+    /// }
+    /// assert!(matches!(stream.encode_single(55), StreamOut::BlockComplete { .. }));
+    /// ```
+    pub fn with_block_len(mut self, block_len: usize) -> Result<Self, EncoderError> {
+        if block_len <= self.bytes_processed || block_len + ECC_LEN > F::ORDER {
+            return Err(EncoderError::InvalidBlockLength);
+        }
+        self.block_len = block_len;
+        Ok(self)
+    }
+
+    /// Data bytes per block
+    pub fn block_len(&self) -> usize {
+        self.block_len
+    }
+
+    /// Data bytes fed into the current block so far
+    pub fn bytes_processed(&self) -> usize {
+        self.bytes_processed
+    }
+
+    /// Snapshot of the partially encoded block, to be stored e.g. in battery-backed memory and
+    /// resumed with [`Encoder::import_state`] after a power loss.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, StreamState};
+    ///
+    /// let mut stream = Encoder::<8>::new().start();
+    /// stream.encode_single(b'H');
+    ///
+    /// let mut backup = [0; StreamState::<8>::LEN];
+    /// stream.export_state().write(&mut backup).unwrap();
+    /// drop(stream);
+    ///
+    /// let state = StreamState::<8>::read(&backup).unwrap();
+    /// let mut stream = Encoder::<8>::new().import_state(&state).unwrap();
+    /// stream.encode_single(b'i');
+    ///
+    /// assert_eq!(Encoder::<8>::new().encode(b"Hi").unwrap()[..], stream.finalize().1.unwrap());
+    /// ```
+    pub fn export_state(&self) -> StreamState<ECC_LEN> {
+        StreamState {
+            remainder: self.remainder,
+            bytes_processed: self.bytes_processed as u8,
+            block_len: self.block_len as u8,
+        }
+    }
+
+    /// Finishes the current block, returns the encoder and the block's error correction code.
+    ///
+    /// The code is `None` if no bytes were fed since the last block boundary.
+    pub fn finalize(self) -> (Encoder<ECC_LEN, F>, Option<[u8; ECC_LEN]>) {
+        let ecc = if self.bytes_processed == 0 { None } else { Some(self.remainder) };
+        (self.encoder, ecc)
+    }
+
+    /// Discards the current block and returns the encoder.
+    pub fn discard(self) -> Encoder<ECC_LEN, F> {
+        self.encoder
+    }
+
+    /// Passes the parity of every completed block to `sink` instead of returning it, so that
+    /// bytes can be pushed or fed with [`Extend`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let mut parities = Vec::new();
+    /// let mut sink = Encoder::<8>::new().start().with_sink(|ecc: &[u8; 8]| parities.push(*ecc));
+    /// sink.extend(b"Hello World".iter().cycle().take(500));
+    /// sink.finalize();
+    ///
+    /// // Two full blocks of 247 bytes and the remaining 6
+    /// assert_eq!(3, parities.len());
+    /// ```
+    pub fn with_sink<S: FnMut(&[u8; ECC_LEN])>(self, sink: S) -> Sink<ECC_LEN, F, S> {
+        Sink {
+            stream: self,
+            sink,
+        }
+    }
+
+    /// Passes every data and parity byte to `sink` as it's produced, e.g. straight into a
+    /// transmit FIFO from an interrupt handler.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    ///
+    /// let mut out = Vec::new();
+    /// let mut stream = Encoder::<8>::new().start().with_byte_sink(|x| out.push(x));
+    /// stream.extend(b"Hello World");
+    /// stream.finalize();
+    ///
+    /// assert_eq!(b"Hello World", &out[..11]);
+    /// assert_eq!(Encoder::<8>::new().encode(b"Hello World").unwrap(), out[11..]);
+    /// ```
+    pub fn with_byte_sink<S: ByteSink>(self, sink: S) -> ByteStream<ECC_LEN, F, S> {
+        ByteStream {
+            stream: self,
+            sink,
         }
+    }
+}
+
+/// Receiver of the bytes of a [`ByteStream`], implemented for `FnMut(u8)` closures
+pub trait ByteSink {
+    /// Takes the next data or parity byte
+    fn put(&mut self, byte: u8);
+}
 
-        unsafe { Vec::from_slice(&[data]).unwrap_unchecked() }
+impl<T: FnMut(u8)> ByteSink for T {
+    fn put(&mut self, byte: u8) {
+        self(byte)
     }
+}
 
-    // Errors if nothing in scratch space
-    pub fn finalize(&mut self) -> Result<Vec<u8, ECC_BYTE_COUNT_STORE>, ()> {
-        if self.scratch_space.len() == 0 {
-            return Err(());
+/// Plain data snapshot of a [`Filling`] streaming encoder
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct StreamState<const ECC_LEN: usize> {
+    /// Remainder of the streamed block so far
+    pub remainder: [u8; ECC_LEN],
+    /// Data bytes fed into the current block
+    pub bytes_processed: u8,
+    /// Data bytes per block
+    pub block_len: u8,
+}
+
+impl<const ECC_LEN: usize> StreamState<ECC_LEN> {
+    /// Length of the serialized snapshot: the remainder, `bytes_processed` and `block_len`
+    pub const LEN: usize = ECC_LEN + 2;
+
+    /// Serializes the snapshot into `out`, which must be exactly [`LEN`](Self::LEN) bytes long
+    pub fn write(&self, out: &mut [u8]) -> Result<(), EncoderError> {
+        if out.len() != Self::LEN {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+        out[..ECC_LEN].copy_from_slice(&self.remainder);
+        out[ECC_LEN] = self.bytes_processed;
+        out[ECC_LEN + 1] = self.block_len;
+        Ok(())
+    }
+
+    /// Deserializes a snapshot written by [`write`](Self::write)
+    pub fn read(bytes: &[u8]) -> Result<Self, EncoderError> {
+        if bytes.len() != Self::LEN {
+            return Err(EncoderError::BufferLenMismatch);
+        }
+        let mut remainder = [0; ECC_LEN];
+        remainder.copy_from_slice(&bytes[..ECC_LEN]);
+        Ok(Self {
+            remainder,
+            bytes_processed: bytes[ECC_LEN],
+            block_len: bytes[ECC_LEN + 1],
+        })
+    }
+}
+
+/// Streaming encoder handing parity to a callback, created by [`Filling::with_sink`]
+#[must_use = "pending block is lost unless finalized"]
+pub struct Sink<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: FnMut(&[u8; ECC_LEN])> {
+    stream: Filling<ECC_LEN, F>,
+    sink: S,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: FnMut(&[u8; ECC_LEN])> Sink<ECC_LEN, F, S> {
+    /// Feeds a single byte, the sink is called if it completes a block
+    pub fn push(&mut self, data: u8) {
+        if let StreamOut::BlockComplete { parity, .. } = self.stream.encode_single(data) {
+            (self.sink)(&parity);
         }
+    }
 
-        let mut rounds = self.generator.len();
-        if self.scratch_space.len() < self.generator.len() {
-            rounds = self.scratch_space.len();
-            unsafe { self.scratch_space.resize(self.generator.len(), 0).unwrap_unchecked() };
+    /// Hands the parity of the pending block to the sink if any bytes were fed since the last
+    /// block boundary, and returns the encoder.
+    pub fn finalize(mut self) -> Encoder<ECC_LEN, F> {
+        let (encoder, ecc) = self.stream.finalize();
+        if let Some(ecc) = ecc {
+            (self.sink)(&ecc);
         }
+        encoder
+    }
+}
 
-        for _ in 0..rounds {
-            self.run_encoding_round();
-            self.scratch_space.rotate_left(1);
-            self.scratch_space[self.generator.len() - 1] = 0;
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: FnMut(&[u8; ECC_LEN])> core::fmt::Debug for Sink<ECC_LEN, F, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Sink").field("stream", &self.stream).finish_non_exhaustive()
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: FnMut(&[u8; ECC_LEN])> Extend<u8> for Sink<ECC_LEN, F, S> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
         }
-        let mut out = self.scratch_space.clone();
-        out.truncate(self.generator.len() - 1);
-        self.reset();
-        Ok(out)
     }
+}
 
-    pub fn reset(&mut self) {
-        self.scratch_space.clear();
-        self.bytes_processed = 0;
+impl<'a, const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: FnMut(&[u8; ECC_LEN])> Extend<&'a u8> for Sink<ECC_LEN, F, S> {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
     }
+}
 
-    fn run_encoding_round(&mut self) {
-        let coef = unsafe { self.scratch_space.get_unchecked(0) };
-        if *coef != 0 {
-            let lcoef = gf::LOG[*coef as usize] as usize;
-            for j in 1..self.generator.len() {
-                let scratch_var: &mut u8 = unsafe { &mut self.scratch_space.get_unchecked_mut(j) };
-                let lgen_var = *unsafe { self.lgenerator.get_unchecked(j) };
-                *scratch_var ^= gf::EXP[(lcoef + lgen_var as usize)];
+/// Streaming encoder handing every output byte to a [`ByteSink`], created by
+/// [`Filling::with_byte_sink`]
+#[must_use = "pending block is lost unless finalized"]
+pub struct ByteStream<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: ByteSink> {
+    stream: Filling<ECC_LEN, F>,
+    sink: S,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: ByteSink> ByteStream<ECC_LEN, F, S> {
+    /// Feeds a single byte and passes it to the sink, followed by the parity if it completes
+    /// a block
+    pub fn push(&mut self, data: u8) {
+        let out = self.stream.encode_single(data);
+        self.sink.put(data);
+        if let StreamOut::BlockComplete { parity, .. } = out {
+            for x in parity {
+                self.sink.put(x);
             }
         }
     }
+
+    /// Passes the parity of the pending block to the sink if any bytes were fed since the last
+    /// block boundary, and returns the encoder together with the sink.
+    pub fn finalize(mut self) -> (Encoder<ECC_LEN, F>, S) {
+        let (encoder, ecc) = self.stream.finalize();
+        for x in ecc.into_iter().flatten() {
+            self.sink.put(x);
+        }
+        (encoder, self.sink)
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: ByteSink> core::fmt::Debug for ByteStream<ECC_LEN, F, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("ByteStream").field("stream", &self.stream).finish_non_exhaustive()
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: ByteSink> Extend<u8> for ByteStream<ECC_LEN, F, S> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<'a, const ECC_LEN: usize, F: GaloisField<Elem = u8>, S: ByteSink> Extend<&'a u8> for ByteStream<ECC_LEN, F, S> {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Coefficients of a monic generator polynomial after its leading 1
+pub(crate) const fn monic_tail<const N: usize>(generator: &[u8]) -> [u8; N] {
+    assert!(generator.len() == N + 1 && generator[0] == 1, "generator must be monic and N + 1 bytes long");
+    let mut tail = [0; N];
+    let mut i = 0;
+    while i < N {
+        tail[i] = generator[i + 1];
+        i += 1;
+    }
+    tail
+}
+
+#[cfg(not(feature = "external_tables"))]
+pub(crate) const fn log_generator<F: GaloisField<Elem = u8>, const N: usize>(generator: &[u8; N]) -> [u8; N] {
+    let mut lgen = [0u8; N];
+    let mut i = 0;
+    while i < generator.len() {
+        lgen[i] = F::LOG_TABLE[generator[i] as usize];
+        i += 1;
+    }
+    lgen
+}
+
+#[cfg(feature = "external_tables")]
+pub(crate) fn log_generator<F: GaloisField<Elem = u8>, const N: usize>(generator: &[u8; N]) -> [u8; N] {
+    let mut lgen = [0u8; N];
+    for (l, g) in lgen.iter_mut().zip(generator.iter()) {
+        *l = if *g == 0 { 0 } else { F::log(*g) as u8 };
+    }
+    lgen
+}
+
+/// Feeds `byte` into the shift register holding the remainder of division by a monic generator,
+/// given its coefficients after the leading 1 and their logarithms
+pub(crate) fn lfsr_step<F: GaloisField<Elem = u8>>(generator: &[u8], lgenerator: &[u8], remainder: &mut [u8], byte: u8) {
+    let Some(&first) = remainder.first() else {
+        return;
+    };
+    let coef = byte ^ first;
+    remainder.rotate_left(1);
+    let last = remainder.len() - 1;
+    remainder[last] = 0;
+    add_scaled::<F>(remainder, generator, lgenerator, coef);
+}
+
+/// Same as [`lfsr_step`] for every byte of `data`, but the remainder is kept as a ring during
+/// the run instead of being shifted each round, and rotated into place once at the end
+pub(crate) fn lfsr_run<F: GaloisField<Elem = u8>>(generator: &[u8], lgenerator: &[u8], remainder: &mut [u8], data: &[u8]) {
+    let len = remainder.len();
+    if len == 0 {
+        return;
+    }
+
+    // Index of the highest degree remainder coefficient
+    let mut head = 0;
+    for x in data.iter() {
+        let coef = *x ^ uncheck!(remainder[head]);
+        uncheck_mut!(remainder[head]) = 0;
+        head += 1;
+        if head == len {
+            head = 0;
+        }
+
+        let (wrapped, front) = remainder.split_at_mut(head);
+        let (gen_front, gen_wrapped) = generator.split_at(len - head);
+        let (lgen_front, lgen_wrapped) = lgenerator.split_at(len - head);
+        add_scaled::<F>(front, gen_front, lgen_front, coef);
+        add_scaled::<F>(wrapped, gen_wrapped, lgen_wrapped, coef);
+    }
+    remainder.rotate_left(head);
+}
+
+/// Adds `coef` times the generator coefficients to `remainder`
+#[inline(always)]
+fn add_scaled<F: GaloisField<Elem = u8>>(remainder: &mut [u8], generator: &[u8], lgenerator: &[u8], coef: u8) {
+    if cfg!(feature = "constant_time") {
+        // Neither branch on the data nor use it as a table index
+        for (r, g) in remainder.iter_mut().zip(generator.iter()) {
+            *r ^= F::mul(coef, *g);
+        }
+    } else if coef != 0 {
+        let lcoef = F::log(coef);
+        for (r, lgen_var) in remainder.iter_mut().zip(lgenerator.iter()) {
+            *r ^= F::exp(lcoef + *lgen_var as usize);
+        }
+    }
+}
+
+/// Inverse of `prim` modulo `order`, if they have no common factor
+pub(crate) fn power_inverse(prim: u8, order: usize) -> Option<usize> {
+    (1..order).find(|i| prim as usize * i % order == 1)
 }
 
-fn generator_poly<const MAX_LEN: usize>(ecclen: usize) -> [u8; MAX_LEN] {
-    let mut gen = polynom![1];
+pub(crate) fn field_generator_poly<F: GaloisField<Elem = u8>>(ecclen: usize, fcr: u8, prim: u8) -> Polynom<F> {
+    let mut gen: Polynom<F> = polynom![1];
     let mut mm = [1, 0];
     let mut i = 0;
     while i < ecclen {
-        mm[1] = gf::pow(2, i as i32);
+        mm[1] = F::pow(2, prim as i32 * (fcr as i32 + i as i32));
         gen = gen.mul(&mm);
         i += 1;
     }
-    gen[..].try_into().unwrap()
+    gen
+}
+
+/// Computes the generator polynomial `(x - 2^0)..(x - 2^(ecc_len - 1))` of the default field,
+/// highest degree first, usable in `const` items to precompute generators for
+/// [`Encoder::new_with_precomputed_generator`].
+///
+/// It multiplies without lookup tables, so it's `const` even with `external_tables`.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::{generator_poly, Encoder, ENCODE_GEN_8_ECC_BYTES};
+///
+/// const GEN_24: [u8; 25] = generator_poly(24);
+/// let encoder = Encoder::<24>::new_with_precomputed_generator(&GEN_24);
+///
+/// assert_eq!(ENCODE_GEN_8_ECC_BYTES, generator_poly::<9>(8));
+/// ```
+///
+/// # Panics
+/// If `N` isn't `ecc_len + 1`.
+pub const fn generator_poly<const N: usize>(ecc_len: usize) -> [u8; N] {
+    use crate::gf::{ct, PRIMITIVE_POLY};
+    assert!(N == ecc_len + 1, "generator of ecc_len bytes has ecc_len + 1 coefficients");
+
+    let mut gen = [0u8; N];
+    gen[0] = 1;
+    let mut root = 1;
+    let mut d = 0;
+    while d < ecc_len {
+        // Multiply by (x + 2^d), the new lowest coefficient is at index d + 1
+        let mut j = d + 1;
+        while j > 0 {
+            gen[j] ^= ct::mul(PRIMITIVE_POLY, root, gen[j - 1]);
+            j -= 1;
+        }
+        root = ct::mul(PRIMITIVE_POLY, root, 2);
+        d += 1;
+    }
+    gen
+}
+
+table_fn! {
+    /// Encodes `data` in the default field and returns its `E` ecc bytes, usable in `const` items
+    /// so that parity of e.g. firmware headers is computed at build time.
+    ///
+    /// Not `const` with `external_tables`.
+    ///
+    /// # Example
+    #[cfg_attr(not(feature = "external_tables"), doc = "```rust")]
+    #[cfg_attr(feature = "external_tables", doc = "```ignore")]
+    /// use reed_solomon::{encode_const, Encoder};
+    ///
+    /// const DATA: [u8; 11] = *b"Hello World";
+    /// const ECC: [u8; 8] = encode_const(DATA);
+    ///
+    /// assert_eq!(Encoder::<8>::new().encode(&DATA).unwrap()[..], ECC);
+    /// ```
+    pub fn encode_const<const K: usize, const E: usize>(data: [u8; K]) -> [u8; E] {
+        assert!(K + E <= 255, "data and ecc don't fit a single block");
+
+        // Generator polynomial (x - 2^0)..(x - 2^(E - 1)) without its leading 1
+        let mut generator = [0u8; E];
+        let mut d = 0;
+        while d < E {
+            let root = crate::gf::pow(2, d as i32);
+            let mut j = d;
+            while j > 0 {
+                generator[j] ^= crate::gf::mul(root, generator[j - 1]);
+                j -= 1;
+            }
+            generator[0] ^= root;
+            d += 1;
+        }
+
+        // Remainder of data(x) * x^E / generator(x)
+        let mut ecc = [0u8; E];
+        let mut i = 0;
+        while i < K {
+            let coef = data[i] ^ if E > 0 { ecc[0] } else { 0 };
+            let mut j = 0;
+            while j < E {
+                let next = if j + 1 < E { ecc[j + 1] } else { 0 };
+                ecc[j] = next ^ crate::gf::mul(generator[j], coef);
+                j += 1;
+            }
+            i += 1;
+        }
+        ecc
+    }
+}
+
+/// Computes `ecc_len` parity bytes of a byte string at compile time with [`encode_const`],
+/// evaluating to an `[u8; ecc_len]` array which can initialize a `static` or `const`.
+///
+/// Not available in `const` context with `external_tables`.
+///
+/// # Example
+#[cfg_attr(not(feature = "external_tables"), doc = "```rust")]
+#[cfg_attr(feature = "external_tables", doc = "```ignore")]
+/// use reed_solomon::{rs_encode, Encoder};
+///
+/// static BOOT_RECORD_ECC: [u8; 8] = rs_encode!(b"boot record", 8);
+///
+/// let ecc = Encoder::<8>::new().encode(b"boot record").unwrap();
+/// assert_eq!(ecc[..], BOOT_RECORD_ECC);
+/// ```
+#[macro_export]
+macro_rules! rs_encode {
+    ($data:expr, $ecc_len:expr) => {{
+        const ECC: [u8; $ecc_len] = $crate::encode_const(*$data);
+        ECC
+    }};
 }
 
+/// Precomputed generator polynomial for 2 ecc bytes
 pub const ENCODE_GEN_2_ECC_BYTES: [u8; 3] = [1, 3, 2];
+/// Precomputed generator polynomial for 4 ecc bytes
 pub const ENCODE_GEN_4_ECC_BYTES: [u8; 5] = [1, 15, 54, 120, 64];
+/// Precomputed generator polynomial for 8 ecc bytes
 pub const ENCODE_GEN_8_ECC_BYTES: [u8; 9] = [1, 255, 11, 81, 54, 239, 173, 200, 24];
+/// Precomputed generator polynomial for 16 ecc bytes
 pub const ENCODE_GEN_16_ECC_BYTES: [u8; 17] = [1, 59, 13, 104, 189, 68, 209, 30, 8, 163, 65, 41, 229, 98, 50, 36, 59];
+/// Precomputed generator polynomial for 32 ecc bytes
+pub const ENCODE_GEN_32_ECC_BYTES: [u8; 33] = generator_poly(32);
+/// Precomputed generator polynomial for 64 ecc bytes
+pub const ENCODE_GEN_64_ECC_BYTES: [u8; 65] = generator_poly(64);
+/// Precomputed generator polynomial for 128 ecc bytes
+pub const ENCODE_GEN_128_ECC_BYTES: [u8; 129] = generator_poly(128);
 
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop)]
 mod tests {
     use std::vec::Vec;
+    use crate::EncodeIterExt;
 
     #[test]
     fn generator_poly() {
@@ -183,42 +1053,372 @@ mod tests {
 
     #[test]
     fn check_const_generators() {
+        crate::gf::init_test_tables();
         assert_eq!(super::ENCODE_GEN_2_ECC_BYTES, super::generator_poly::<3>(2));
         assert_eq!(super::ENCODE_GEN_4_ECC_BYTES, super::generator_poly::<5>(4));
         assert_eq!(super::ENCODE_GEN_8_ECC_BYTES, super::generator_poly::<9>(8));
         assert_eq!(super::ENCODE_GEN_16_ECC_BYTES, super::generator_poly::<17>(16));
+        assert_eq!(super::ENCODE_GEN_32_ECC_BYTES[..], super::field_generator_poly::<super::Gf2p8>(32, 0, 1)[..]);
+        assert_eq!(super::ENCODE_GEN_64_ECC_BYTES[..], super::field_generator_poly::<super::Gf2p8>(64, 0, 1)[..]);
+        assert_eq!(super::ENCODE_GEN_128_ECC_BYTES[..], super::field_generator_poly::<super::Gf2p8>(128, 0, 1)[..]);
+    }
+
+    #[test]
+    fn generator() {
+        crate::gf::init_test_tables();
+        let encoder = super::Encoder::<8>::new();
+        assert_eq!(super::ENCODE_GEN_8_ECC_BYTES[1..], *encoder.generator());
+        assert_eq!([24, 200, 173, 239, 54, 81, 11, 255], encoder.generator_reversed());
+        for (g, l) in encoder.generator().iter().zip(encoder.generator_log()) {
+            assert_eq!(*g, crate::gf::pow(2, *l as i32));
+        }
     }
 
     #[test]
     fn encode() {
+        crate::gf::init_test_tables();
         let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
             22, 23, 24, 25, 26, 27, 28, 29];
         let ecc = [99, 26, 219, 193, 9, 94, 186, 143];
 
-        let mut encoder = super::Encoder::<9>::new(ecc.len());
-        let encoded = encoder.encode(&data[..]);
+        let mut encoder = super::Encoder::<8>::new();
+        let encoded = encoder.encode(&data[..]).unwrap();
 
         assert_eq!(ecc, encoded);
 
-        let mut encoder = super::Encoder::<9>::new_with_precomputed_generator(&super::ENCODE_GEN_8_ECC_BYTES);
-        let encoded = encoder.encode(&data[..]);
+        let mut encoder = super::Encoder::<8>::new_with_precomputed_generator(&super::ENCODE_GEN_8_ECC_BYTES);
+        let encoded = encoder.encode(&data[..]).unwrap();
 
         assert_eq!(ecc, encoded);
     }
 
+    #[test]
+    fn with_fcr() {
+        crate::gf::init_test_tables();
+        use crate::gf::poly_math::Eval;
+
+        let data: [u8; 40] = core::array::from_fn(|i| (i * 7) as u8);
+        let mut encoder = super::Encoder::<8>::with_fcr(112);
+        let mut message = Vec::from(data);
+        message.extend_from_slice(&encoder.encode(&data).unwrap());
+        for i in 112..120 {
+            assert_eq!(0, message.eval(crate::gf::pow(2, i)));
+        }
+        assert_ne!(0, message.eval(1));
+    }
+
+    #[test]
+    fn encode_with_layout() {
+        crate::gf::init_test_tables();
+        use crate::gf::poly_math::Eval;
+        use super::Layout;
+
+        let data: [u8; 100] = core::array::from_fn(|i| (i * 7) as u8);
+        let mut encoder = super::Encoder::<8>::new();
+        let ecc = encoder.encode(&data).unwrap();
+
+        let mut out = [0; 108];
+        encoder.encode_with_layout(&data, Layout::DataFirst, &mut out).unwrap();
+        assert_eq!(data, out[..100]);
+        assert_eq!(ecc, out[100..]);
+
+        encoder.encode_with_layout(&data, Layout::ParityFirst, &mut out).unwrap();
+        assert_eq!(ecc, out[..8]);
+        assert_eq!(data, out[8..]);
+
+        encoder.encode_with_layout(&data, Layout::NonSystematic, &mut out).unwrap();
+        assert_ne!(data, out[..100]);
+        for i in 0..8 {
+            assert_eq!(0, out.eval(crate::gf::pow(2, i)));
+        }
+
+        assert_eq!(Err(super::EncoderError::BufferLenMismatch), encoder.encode_with_layout(&data, Layout::DataFirst, &mut out[..107]));
+        let mut long = [0; 256];
+        assert_eq!(Err(super::EncoderError::MessageTooLong), encoder.encode_with_layout(&[0; 248], Layout::NonSystematic, &mut long));
+    }
+
+    #[test]
+    fn lfsr_run() {
+        crate::gf::init_test_tables();
+        let data: [u8; 200] = core::array::from_fn(|i| (i * 29 + 3) as u8);
+        let encoder = super::Encoder::<32>::new();
+        for len in [0, 1, 31, 32, 33, 64, 200] {
+            let mut stepped = [0; 32];
+            for x in data[..len].iter() {
+                super::lfsr_step::<super::Gf2p8>(&encoder.generator, &encoder.lgenerator, &mut stepped, *x);
+            }
+            let mut run = [0; 32];
+            super::lfsr_run::<super::Gf2p8>(&encoder.generator, &encoder.lgenerator, &mut run, &data[..len]);
+            assert_eq!(stepped, run);
+        }
+    }
+
+    #[test]
+    fn encode_to_slice() {
+        crate::gf::init_test_tables();
+        use crate::gf::poly_math::Eval;
+
+        let data: [u8; 247] = core::array::from_fn(|i| (i * 7) as u8);
+        let mut encoder = super::Encoder::<8>::new();
+        let mut ecc = [0; 8];
+
+        encoder.encode_to_slice(&data[..30], &mut ecc).unwrap();
+        assert_eq!(encoder.encode(&data[..30]).unwrap()[..], ecc);
+
+        // A whole block
+        encoder.encode_to_slice(&data, &mut ecc).unwrap();
+        let mut message = Vec::from(data);
+        message.extend_from_slice(&ecc);
+        for i in 0..8 {
+            assert_eq!(0, message.eval(crate::gf::pow(2, i)));
+        }
+
+        assert_eq!(Err(super::EncoderError::EccLenMismatch), encoder.encode_to_slice(&data, &mut ecc[..7]));
+        assert_eq!(Err(super::EncoderError::MessageTooLong), encoder.encode_to_slice(&message[..248], &mut ecc));
+    }
+
+    #[test]
+    fn encode_buffer() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+        let mut encoder = super::Encoder::<8>::new();
+
+        let buffer = encoder.encode_buffer(&data[..100]).unwrap();
+        assert_eq!(&data[..100], buffer.data());
+        assert_eq!(encoder.encode(&data[..100]).unwrap()[..], *buffer.ecc());
+
+        assert_eq!(Err(super::EncoderError::MessageTooLong), encoder.encode_buffer(&data[..248]).map(|_| ()));
+    }
+
+    #[test]
+    fn encode_vectored() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 11) as u8);
+        let mut encoder = super::Encoder::<8>::new();
+
+        let ecc = encoder.encode_vectored(&[&data[..3], &[], &data[3..100], &data[100..247]]).unwrap();
+        assert_eq!(encoder.encode(&data[..247]).unwrap(), ecc);
+        assert_eq!(encoder.encode(&[]).unwrap(), encoder.encode_vectored(&[]).unwrap());
+        assert_eq!(Err(super::EncoderError::MessageTooLong), encoder.encode_vectored(&[&data[..200], &data[..48]]));
+    }
+
+    #[test]
+    fn encode_batch() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 17) as u8);
+        let mut encoder = super::Encoder::<8>::new();
+        let msgs: Vec<&[u8]> = data.chunks(40).collect();
+
+        let mut out = [[0xff; 8]; 8];
+        encoder.encode_batch(&msgs, &mut out).unwrap();
+        for (msg, ecc) in msgs.iter().zip(out.iter()) {
+            assert_eq!(encoder.encode(msg).unwrap(), ecc[..]);
+        }
+
+        assert_eq!(Err(super::EncoderError::BufferLenMismatch), encoder.encode_batch(&msgs, &mut out[..7]));
+        let mut untouched = [[0; 8]; 2];
+        assert_eq!(Err(super::EncoderError::MessageTooLong), encoder.encode_batch(&[&data[..3], &data[..248]], &mut untouched));
+        assert_eq!([[0; 8]; 2], untouched);
+    }
+
+    #[test]
+    #[cfg(not(feature = "external_tables"))]
+    fn encode_const() {
+        const DATA: [u8; 30] = [7; 30];
+        const ECC: [u8; 16] = super::encode_const(DATA);
+
+        let mut encoder = super::Encoder::<16>::new();
+        assert_eq!(encoder.encode(&DATA).unwrap()[..], ECC);
+        assert_eq!([0u8; 0], super::encode_const::<30, 0>(DATA));
+
+        let data: [u8; 247] = core::array::from_fn(|i| i as u8);
+        let mut ecc = [0; 8];
+        let encoder = super::Encoder::<8>::new();
+        encoder.encode_to_slice(&data, &mut ecc).unwrap();
+        assert_eq!(ecc, super::encode_const(data));
+    }
+
+    #[test]
+    #[cfg(not(feature = "external_tables"))]
+    fn rs_encode() {
+        const HEADER: &[u8; 4] = b"\x7fELF";
+        static ECC: [u8; 6] = rs_encode!(HEADER, 6);
+        assert_eq!(ECC, super::encode_const(*HEADER));
+    }
+
     #[test]
     fn encode_shorter_than_ecc_message() {
+        crate::gf::init_test_tables();
         let data = [0, 1, 2, 3, 4];
         let ecc = [44, 157, 28, 43, 61, 248, 104, 250, 152, 77];
 
-        let mut encoder = super::Encoder::<11>::new(ecc.len());
-        let encoded = encoder.encode(&data[..]);
+        let mut encoder = super::Encoder::<10>::new();
+        let encoded = encoder.encode(&data[..]).unwrap();
 
         assert_eq!(ecc, encoded);
     }
 
+    #[test]
+    fn stream() {
+        crate::gf::init_test_tables();
+        let (encoder, ecc) = super::Encoder::<4>::new().start().finalize();
+        assert_eq!(None, ecc);
+
+        let mut stream = encoder.start();
+        stream.encode_single(1);
+        let mut stream = stream.discard().start();
+        for x in [2, 3] {
+            assert_eq!(super::StreamOut::Byte(x), stream.encode_single(x));
+        }
+        let (mut encoder, ecc) = stream.finalize();
+        assert_eq!(encoder.encode(&[2, 3]).unwrap()[..], ecc.unwrap());
+    }
+
+    #[test]
+    fn block_len() {
+        crate::gf::init_test_tables();
+        let data: [u8; 150] = core::array::from_fn(|i| (i * 3) as u8);
+        let mut encoder = super::Encoder::<4>::new();
+        let mut stream = super::Encoder::<4>::new().start().with_block_len(64).unwrap();
+
+        let mut parities = Vec::new();
+        for x in data.iter() {
+            if let super::StreamOut::BlockComplete { parity, .. } = stream.encode_single(*x) {
+                parities.push(parity);
+            }
+        }
+        parities.push(stream.finalize().1.unwrap());
+
+        assert_eq!(3, parities.len());
+        for (chunk, parity) in data.chunks(64).zip(parities.iter()) {
+            assert_eq!(encoder.encode(chunk).unwrap()[..], *parity);
+        }
+
+        let invalid = Err(super::EncoderError::InvalidBlockLength);
+        assert_eq!(invalid, super::Encoder::<4>::new().start().with_block_len(0).map(|_| ()));
+        assert_eq!(invalid, super::Encoder::<4>::new().start().with_block_len(252).map(|_| ()));
+        assert!(super::Encoder::<4>::new().start().with_block_len(251).is_ok());
+    }
+
+    #[test]
+    fn block_len_boundaries() {
+        crate::gf::init_test_tables();
+        let data: [u8; 600] = core::array::from_fn(|i| (i * 7 + 1) as u8);
+        let mut encoder = super::Encoder::<4>::new();
+        for block_len in [1, 2, 250, 251] {
+            let mut stream = super::Encoder::<4>::new().start_with_block_len(block_len).unwrap();
+            let mut blocks = 0;
+            for (i, x) in data.iter().enumerate() {
+                assert_eq!(i % block_len, stream.bytes_processed());
+                if let super::StreamOut::BlockComplete { parity, .. } = stream.encode_single(*x) {
+                    let block = &data[blocks * block_len..(blocks + 1) * block_len];
+                    assert_eq!(encoder.encode(block).unwrap()[..], parity);
+                    blocks += 1;
+                }
+            }
+            assert_eq!(600 / block_len, blocks);
+            assert_eq!(block_len, stream.block_len());
+            assert_eq!(600 % block_len != 0, stream.finalize().1.is_some());
+        }
+
+        let invalid = Err(super::EncoderError::InvalidBlockLength);
+        assert_eq!(invalid, super::Encoder::<4>::new().start_with_block_len(0).map(|_| ()));
+        assert_eq!(invalid, super::Encoder::<4>::new().start_with_block_len(252).map(|_| ()));
+        assert_eq!(1, super::Encoder::<254>::new().start().block_len());
+
+        let mut stream = super::Encoder::<4>::new().start();
+        for x in data[..200].iter() {
+            stream.encode_single(*x);
+        }
+        assert_eq!(invalid, stream.with_block_len(200).map(|_| ()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn start_without_room_for_data() {
+        let _ = super::Encoder::<255>::new().start();
+    }
+
+    #[test]
+    fn sink() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 3) as u8);
+        let mut parities = Vec::new();
+        let mut sink = super::Encoder::<4>::new().start().with_sink(|ecc: &[u8; 4]| parities.push(*ecc));
+        sink.extend(data[..200].iter());
+        sink.push(data[200]);
+        sink.extend(data[201..].iter().copied());
+        let mut encoder = sink.finalize();
+
+        assert_eq!(2, parities.len());
+        assert_eq!(encoder.encode(&data[..251]).unwrap()[..], parities[0]);
+        assert_eq!(encoder.encode(&data[251..]).unwrap()[..], parities[1]);
+    }
+
+    #[test]
+    fn stream_state() {
+        crate::gf::init_test_tables();
+        let data: [u8; 100] = core::array::from_fn(|i| (i * 9) as u8);
+        let mut encoder = super::Encoder::<4>::new();
+        let mut stream = super::Encoder::<4>::new().start_with_block_len(60).unwrap();
+        for x in data[..70].iter() {
+            stream.encode_single(*x);
+        }
+
+        let state = stream.export_state();
+        assert_eq!(10, state.bytes_processed);
+        assert_eq!(60, state.block_len);
+        let mut bytes = [0; 6];
+        state.write(&mut bytes).unwrap();
+        assert_eq!(Ok(state), super::StreamState::read(&bytes));
+        assert_eq!(Err(super::EncoderError::BufferLenMismatch), state.write(&mut bytes[..5]));
+        assert_eq!(Err(super::EncoderError::BufferLenMismatch), super::StreamState::<4>::read(&bytes[1..]));
+
+        let mut resumed = super::Encoder::<4>::new().import_state(&state).unwrap();
+        for x in data[70..].iter() {
+            resumed.encode_single(*x);
+        }
+        assert_eq!(encoder.encode(&data[60..]).unwrap()[..], resumed.finalize().1.unwrap());
+
+        let invalid = Err(super::EncoderError::InvalidBlockLength);
+        let reached = super::StreamState { bytes_processed: 60, ..state };
+        assert_eq!(invalid, super::Encoder::<4>::new().import_state(&reached).map(|_| ()));
+        let too_long = super::StreamState { block_len: 252, ..state };
+        assert_eq!(invalid, super::Encoder::<4>::new().import_state(&too_long).map(|_| ()));
+    }
+
+    #[test]
+    fn byte_sink() {
+        crate::gf::init_test_tables();
+        struct Counter(usize);
+        impl super::ByteSink for Counter {
+            fn put(&mut self, _: u8) {
+                self.0 += 1;
+            }
+        }
+
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 3) as u8);
+        let mut out = Vec::new();
+        let mut stream = super::Encoder::<4>::new().start().with_byte_sink(|x| out.push(x));
+        stream.extend(data[..200].iter());
+        stream.push(data[200]);
+        stream.extend(data[201..].iter().copied());
+        let (mut encoder, _) = stream.finalize();
+
+        let expected: Vec<u8> = data.iter().copied().rs_encoded::<4>().collect();
+        assert_eq!(expected, out);
+        assert_eq!(308, out.len());
+        assert_eq!(encoder.encode(&data[251..]).unwrap()[..], out[304..]);
+
+        let mut stream = encoder.start().with_byte_sink(Counter(0));
+        stream.extend(data[..251].iter());
+        let (_, counter) = stream.finalize();
+        assert_eq!(255, counter.0);
+    }
+
     #[test]
     fn encode_large() {
+        crate::gf::init_test_tables();
         let mut data = [0; 512];
         for i in 0..512 {
             data[i] = i as u8;
@@ -235,12 +1435,17 @@ mod tests {
         ];
         let mut result = Vec::new();
 
-        let mut encoder = super::Encoder::<3>::new(2);
+        let mut stream = super::Encoder::<2>::new().start();
         for i in 0..512 {
-            let encoded = encoder.encode_single(data[i]);
-            result.extend_from_slice(&encoded[..]);
+            match stream.encode_single(data[i]) {
+                super::StreamOut::Byte(x) => result.push(x),
+                super::StreamOut::BlockComplete { last, parity } => {
+                    result.push(last);
+                    result.extend_from_slice(&parity);
+                }
+            }
         }
-        if let Ok(ecc) = encoder.finalize() {
+        if let (_, Some(ecc)) = stream.finalize() {
             result.extend_from_slice(&ecc[..]);
         }
 
@@ -0,0 +1,196 @@
+//! Systematic Reed-Solomon encoder over GF(2^16) built on the additive FFT of Lin, Chung and
+//! Han ("Novel polynomial basis and its application to Reed-Solomon erasure codes"), the same
+//! construction as Leopard-RS.
+//!
+//! The code is evaluation based: symbol `i` of a codeword is a polynomial evaluated at the field
+//! element `i` (bits of `i` taken as the coordinates in the standard basis). Parity occupies the
+//! first `m` points, `m` being `ecc_len` rounded up to a power of two, data follows from point
+//! `m`. Encoding costs `O(n log m)` multiplications instead of `O(n * ecc_len)` of the LFSR.
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::gf::gf16;
+
+/// Number of field elements, FFT points available for a codeword
+const POINTS: usize = gf16::ORDER + 1;
+
+/// Bits of a GF(2^16) element
+const BITS: usize = 16;
+
+/// Reed-Solomon encoder with 16-bit symbols using the additive FFT
+///
+/// Suited for large `ecc_len`, where [`Encoder16`](crate::Encoder16) is quadratic. The parity is
+/// **not** the one of [`Encoder16`](crate::Encoder16) and can't be checked by
+/// [`Decoder16`](crate::Decoder16). The code is MDS: any `data.len()` of the `data.len() + ecc_len`
+/// symbols are enough to recover the rest by interpolation in the same basis.
+#[derive(Debug, Clone)]
+pub struct FftEncoder16 {
+    ecc_len: usize,
+    /// `W_i(2^i)`, values normalizing the subspace polynomials
+    norms: [u16; BITS],
+}
+
+impl FftEncoder16 {
+    /// Constructs a new `FftEncoder16` producing `ecc_len` ecc symbols.
+    ///
+    /// # Panics
+    /// If `ecc_len` leaves no room for data in a codeword.
+    pub fn new(ecc_len: usize) -> Self {
+        assert!(ecc_len < POINTS / 2, "ecc_len leaves no room for data");
+
+        let mut norms = [0; BITS];
+        for i in 0..BITS {
+            norms[i] = subspace_poly(&norms[..i], 1 << i);
+        }
+
+        FftEncoder16 { ecc_len, norms }
+    }
+
+    /// Encodes passed `&[u16]` slice and returns `ecc_len` ecc symbols.
+    ///
+    /// # Panics
+    /// If `data` and the parity, rounded up to a power of two, exceed 65536 symbols.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::FftEncoder16;
+    ///
+    /// let encoder = FftEncoder16::new(1000);
+    ///
+    /// let data: Vec<u16> = (0..30000).collect();
+    /// let ecc = encoder.encode(&data);
+    /// assert_eq!(1000, ecc.len());
+    /// ```
+    pub fn encode(&self, data: &[u16]) -> Vec<u16> {
+        let mut ecc = self.parity(data);
+        ecc.truncate(self.ecc_len);
+        ecc
+    }
+
+    /// Computes all `m` parity symbols, of which only the first `ecc_len` are output
+    fn parity(&self, data: &[u16]) -> Vec<u16> {
+        if self.ecc_len == 0 {
+            return Vec::new();
+        }
+        let m = self.ecc_len.next_power_of_two();
+        assert!(data.len() + m <= POINTS, "data doesn't fit into a codeword");
+
+        // Every chunk of data lies on its own coset of the m point subspace. Interpolating the
+        // chunks and evaluating the sum of the interpolants at the first coset gives the parity
+        // which makes the whole codeword a polynomial of degree less than n - m.
+        let mut parity = vec![0; m];
+        let mut chunk_poly = vec![0; m];
+        for (c, chunk) in data.chunks(m).enumerate() {
+            chunk_poly[..chunk.len()].copy_from_slice(chunk);
+            chunk_poly[chunk.len()..].fill(0);
+            self.ifft(&mut chunk_poly, (c + 1) * m);
+            for (p, x) in parity.iter_mut().zip(chunk_poly.iter()) {
+                *p ^= *x;
+            }
+        }
+        self.fft(&mut parity, 0);
+        parity
+    }
+
+    /// Evaluates the polynomial with coefficients `values` in the novel basis at points
+    /// `offset..offset + values.len()`, in place
+    fn fft(&self, values: &mut [u16], offset: usize) {
+        let len = values.len();
+        for level in (0..len.trailing_zeros() as usize).rev() {
+            let half = 1 << level;
+            for block in (0..len).step_by(2 * half) {
+                let skew = self.skew(level, offset + block);
+                let (lo, hi) = values[block..block + 2 * half].split_at_mut(half);
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    *x ^= gf16::mul(skew, *y);
+                    *y ^= *x;
+                }
+            }
+        }
+    }
+
+    /// Inverse of [`fft`](Self::fft): interpolates values at points `offset..offset + values.len()`
+    /// into novel basis coefficients, in place
+    fn ifft(&self, values: &mut [u16], offset: usize) {
+        let len = values.len();
+        for level in 0..len.trailing_zeros() as usize {
+            let half = 1 << level;
+            for block in (0..len).step_by(2 * half) {
+                let skew = self.skew(level, offset + block);
+                let (lo, hi) = values[block..block + 2 * half].split_at_mut(half);
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    *y ^= *x;
+                    *x ^= gf16::mul(skew, *y);
+                }
+            }
+        }
+    }
+
+    /// Normalized subspace polynomial `W_level(x) / W_level(2^level)` at point `x`
+    fn skew(&self, level: usize, x: usize) -> u16 {
+        gf16::div(subspace_poly(&self.norms[..level], x as u16), self.norms[level])
+    }
+}
+
+/// Subspace polynomial `W_i(x)`, product of `x + a` over all `a < 2^i`, with `i = norms.len()`.
+///
+/// `W_(i+1)(x) = W_i(x) * W_i(x + 2^i) = W_i(x) * (W_i(x) + W_i(2^i))` since `W_i` is additive.
+fn subspace_poly(norms: &[u16], x: u16) -> u16 {
+    norms.iter().fold(x, |w, norm| gf16::mul(w, w ^ norm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fft() {
+        let encoder = FftEncoder16::new(8);
+        let coefs: Vec<u16> = (0..16).map(|i| (i * 4099 + 7) as u16).collect();
+
+        // Direct evaluation of sum coefs[k] * X_k(x), X_k being the product of the normalized
+        // subspace polynomials of the set bits of k
+        let mut values = coefs.clone();
+        encoder.fft(&mut values, 48);
+        for (j, value) in values.iter().enumerate() {
+            let x = 48 + j;
+            let expected = coefs.iter().enumerate().fold(0, |acc, (k, coef)| {
+                let basis = (0..4).filter(|i| k & (1 << i) != 0).fold(1, |b, i| gf16::mul(b, encoder.skew(i, x)));
+                acc ^ gf16::mul(*coef, basis)
+            });
+            assert_eq!(expected, *value);
+        }
+
+        encoder.ifft(&mut values, 48);
+        assert_eq!(coefs, values);
+    }
+
+    #[test]
+    fn encode() {
+        for (data_len, ecc_len) in [(100usize, 8), (1000, 24), (5, 64), (3000, 1000)] {
+            let encoder = FftEncoder16::new(ecc_len);
+            let data: Vec<u16> = (0..data_len).map(|i| (i * 7919 + 1) as u16).collect();
+            let parity = encoder.parity(&data);
+            assert_eq!(parity[..ecc_len], encoder.encode(&data)[..]);
+
+            // The codeword, zero padded to a power of two cosets, must be the evaluation of
+            // a polynomial of degree less than n - m
+            let m = parity.len();
+            let n = m * (1 + data_len.div_ceil(m)).next_power_of_two();
+            let mut codeword = parity.clone();
+            codeword.extend_from_slice(&data);
+            codeword.resize(n, 0);
+            encoder.ifft(&mut codeword, 0);
+            assert!(codeword[n - m..].iter().all(|x| *x == 0));
+            assert!(codeword[..n - m].iter().any(|x| *x != 0));
+        }
+
+        assert!(FftEncoder16::new(0).encode(&[1, 2, 3]).is_empty());
+        assert_eq!([0; 4], FftEncoder16::new(4).encode(&[0; 100])[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_long() {
+        FftEncoder16::new(4).encode(&[0; POINTS - 3]);
+    }
+}
@@ -0,0 +1,197 @@
+//! Self-describing frames for point-to-point links: `[len varint][payload][parity]`.
+//!
+//! The payload length is prefixed as an unsigned LEB128 varint (7 bits per byte, high bit set on
+//! all but the last byte) and the parity covers the prefix as well, so a damaged length is
+//! corrected like any other byte. A frame is a single codeword of at most 255 bytes, its length
+//! has to be known to the receiver, e.g. from delimiters or a fixed slot size.
+use crate::encoder::{Encoder, EncoderError};
+#[cfg(feature = "decoder")]
+use crate::{Decoder, DecoderError};
+
+/// Longest varint prefix, enough for any payload of a single codeword
+const MAX_PREFIX_LEN: usize = 2;
+
+/// Framing errors
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    /// Payload doesn't fit a single codeword together with its length prefix and ecc
+    PayloadTooLong,
+    /// Output buffer can't fit the frame
+    BufferTooSmall,
+    /// Corrected length prefix is malformed or doesn't match the frame length
+    InvalidLength,
+    /// Frame can't be corrected
+    #[cfg(feature = "decoder")]
+    Uncorrectable(DecoderError),
+    /// Frame can't be encoded
+    Unencodable(EncoderError),
+}
+
+impl From<EncoderError> for FrameError {
+    fn from(err: EncoderError) -> Self {
+        FrameError::Unencodable(err)
+    }
+}
+
+#[cfg(feature = "decoder")]
+impl From<DecoderError> for FrameError {
+    fn from(err: DecoderError) -> Self {
+        FrameError::Uncorrectable(err)
+    }
+}
+
+/// Bytes of the varint encoding of `len`
+#[inline]
+fn prefix_len(len: usize) -> usize {
+    if len < 0x80 { 1 } else { MAX_PREFIX_LEN }
+}
+
+/// Length of the frame carrying `payload_len` bytes with `ecc_len` ecc bytes
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::frame::frame_len;
+///
+/// assert_eq!(1 + 100 + 8, frame_len(100, 8));
+/// assert_eq!(2 + 200 + 8, frame_len(200, 8));
+/// ```
+pub fn frame_len(payload_len: usize, ecc_len: usize) -> usize {
+    prefix_len(payload_len) + payload_len + ecc_len
+}
+
+/// Longest payload of a frame with `ecc_len` ecc bytes, `None` if even an empty payload doesn't
+/// fit a codeword
+pub fn max_payload_len(ecc_len: usize) -> Option<usize> {
+    let len = 255usize.checked_sub(ecc_len + 1)?;
+    Some(if frame_len(len, ecc_len) > 255 { len - 1 } else { len })
+}
+
+/// Encodes `payload` into a frame at the start of `out` and returns the frame length.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::frame::{decode_frame, encode_frame};
+/// use reed_solomon::{Decoder, Encoder};
+///
+/// let encoder = Encoder::<8>::new();
+/// let decoder = Decoder::new(8);
+///
+/// let mut buf = [0; 255];
+/// let len = encode_frame(&encoder, b"ping", &mut buf).unwrap();
+/// assert_eq!([4, b'p', b'i', b'n', b'g'], buf[..5]);
+///
+/// let frame = &mut buf[..len];
+/// frame[0] = 0x7f;
+/// frame[2] = 0;
+/// assert_eq!(b"ping", decode_frame(&decoder, frame).unwrap());
+/// ```
+pub fn encode_frame<const ECC_LEN: usize>(encoder: &Encoder<ECC_LEN>, payload: &[u8], out: &mut [u8]) -> Result<usize, FrameError> {
+    if max_payload_len(ECC_LEN).is_none_or(|max| payload.len() > max) {
+        return Err(FrameError::PayloadTooLong);
+    }
+    let len = frame_len(payload.len(), ECC_LEN);
+    if out.len() < len {
+        return Err(FrameError::BufferTooSmall);
+    }
+
+    let prefix = prefix_len(payload.len());
+    let (data, ecc) = out[..len].split_at_mut(len - ECC_LEN);
+    data[0] = payload.len() as u8 & 0x7f;
+    if prefix == MAX_PREFIX_LEN {
+        data[0] |= 0x80;
+        data[1] = (payload.len() >> 7) as u8;
+    }
+    data[prefix..].copy_from_slice(payload);
+    encoder.encode_to_slice(data, ecc)?;
+    Ok(len)
+}
+
+/// Corrects a received frame in place, checks its length prefix and returns the payload.
+///
+/// Non-canonical prefixes, e.g. `0x80 0x00` for 0, are rejected as
+/// [`InvalidLength`](FrameError::InvalidLength) like any other mismatch.
+#[cfg(feature = "decoder")]
+pub fn decode_frame<'a>(decoder: &Decoder, frame: &'a mut [u8]) -> Result<&'a [u8], FrameError> {
+    let ecc_len = decoder.ecc_len();
+    if frame.len() > 255 || frame.len() <= ecc_len {
+        return Err(FrameError::InvalidLength);
+    }
+    decoder.correct_in_place(frame, None)?;
+
+    let mut len = 0;
+    let mut prefix = 0;
+    loop {
+        let byte = frame[prefix];
+        len |= ((byte & 0x7f) as usize) << (7 * prefix);
+        prefix += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if prefix == MAX_PREFIX_LEN || prefix == frame.len() - ecc_len {
+            return Err(FrameError::InvalidLength);
+        }
+    }
+    if prefix != prefix_len(len) || prefix + len + ecc_len != frame.len() {
+        return Err(FrameError::InvalidLength);
+    }
+    Ok(&frame[prefix..prefix + len])
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        crate::gf::init_test_tables();
+        let encoder = Encoder::<10>::new();
+        let decoder = Decoder::new(10);
+        assert_eq!(Some(243), max_payload_len(10));
+
+        let mut buf = [0; 255];
+        for payload_len in [0, 1, 127, 128, 243] {
+            let payload: std::vec::Vec<u8> = (0..payload_len).map(|i| i as u8 ^ 0xa5).collect();
+            let len = encode_frame(&encoder, &payload, &mut buf).unwrap();
+            assert_eq!(frame_len(payload_len, 10), len);
+
+            // Both prefix bytes and some payload damaged
+            let frame = &mut buf[..len];
+            frame[0] ^= 0xff;
+            frame[1] ^= 0x01;
+            frame[len - 1] ^= 0x10;
+            assert_eq!(&payload[..], decode_frame(&decoder, frame).unwrap());
+        }
+
+        assert_eq!(Err(FrameError::PayloadTooLong), encode_frame(&encoder, &[0; 244], &mut buf));
+        assert_eq!(Err(FrameError::BufferTooSmall), encode_frame(&encoder, &[0; 10], &mut buf[..20]));
+
+        // No room for a payload next to 254 or more ecc bytes
+        assert_eq!(Some(0), max_payload_len(254));
+        assert_eq!(None, max_payload_len(255));
+        assert_eq!(Err(FrameError::PayloadTooLong), encode_frame(&Encoder::<255>::new(), &[], &mut buf));
+    }
+
+    #[test]
+    fn invalid() {
+        crate::gf::init_test_tables();
+        let mut encoder = Encoder::<4>::new();
+        let decoder = Decoder::new(4);
+
+        // Valid codewords with a wrong, overlong or non-canonical prefix
+        for data in [&[5, 1, 2][..], &[0x80, 0x80, 0x01], &[0x80, 0x00], &[0x81]] {
+            let mut frame = std::vec::Vec::from(data);
+            frame.extend_from_slice(&encoder.encode(data).unwrap());
+            assert_eq!(Err(FrameError::InvalidLength), decode_frame(&decoder, &mut frame));
+        }
+
+        let mut buf = [0; 16];
+        let len = encode_frame(&encoder, b"abc", &mut buf).unwrap();
+        buf[..5].fill(0xee);
+        assert_eq!(
+            Err(FrameError::Uncorrectable(DecoderError::TooManyErrors)),
+            decode_frame(&decoder, &mut buf[..len])
+        );
+        assert_eq!(Err(FrameError::InvalidLength), decode_frame(&decoder, &mut [0; 4]));
+    }
+}
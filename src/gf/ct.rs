@@ -0,0 +1,76 @@
+//! Constant-time GF(2^8) arithmetics.
+//!
+//! Table lookups leak the indices, i.e. secret field elements, through the data cache. With
+//! `constant_time` feature multiplication is a branch-free shift-and-add (Russian peasant) loop
+//! and the other operations are built on top of it, so their timing depends only on public
+//! inputs such as the primitive polynomial or the exponent.
+
+/// Multiplies two elements of the field generated by `prim`
+#[inline]
+pub(crate) const fn mul(prim: u16, x: u8, y: u8) -> u8 {
+    let mut a = x as u16;
+    let mut product = 0u16;
+    let mut i = 0;
+    while i < 8 {
+        // All ones if the bit is set, zero otherwise
+        product ^= a & 0u16.wrapping_sub((y >> i) as u16 & 1);
+        a <<= 1;
+        a ^= prim & 0u16.wrapping_sub(a >> 8);
+        i += 1;
+    }
+    product as u8
+}
+
+/// Raises `x` to the non-negative `power` by square-and-multiply over all 8 bits of it
+#[inline]
+pub(crate) const fn pow(prim: u16, x: u8, power: u8) -> u8 {
+    let mut result = 1;
+    let mut i = 8;
+    while i > 0 {
+        i -= 1;
+        result = mul(prim, result, result);
+        let mask = 0u8.wrapping_sub((power >> i) & 1);
+        result ^= (mul(prim, result, x) ^ result) & mask;
+    }
+    result
+}
+
+/// One if `x` is zero, zero otherwise
+#[inline]
+pub(crate) const fn is_zero(x: u8) -> u8 {
+    ((x as u16).wrapping_sub(1) >> 8) as u8 & 1
+}
+
+/// Multiplicative inverse, `x^254`; zero is mapped to zero
+#[inline]
+pub(crate) const fn inverse(prim: u16, x: u8) -> u8 {
+    pow(prim, x, 254)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gf::Gf2p8;
+
+    #[test]
+    fn matches_tables() {
+        type F = Gf2p8<0x187>;
+        let (exp, log) = (F::EXP, F::LOG);
+
+        for x in 0..=255u8 {
+            for y in 0..=255u8 {
+                let product = if x == 0 || y == 0 {
+                    0
+                } else {
+                    exp[(log[x as usize] as usize + log[y as usize] as usize) % 255]
+                };
+                assert_eq!(product, super::mul(0x187, x, y));
+            }
+
+            assert_eq!((x == 0) as u8, super::is_zero(x));
+            if x != 0 {
+                assert_eq!(exp[255 - log[x as usize] as usize], super::inverse(0x187, x));
+                assert_eq!(exp[log[x as usize] as usize * 3 % 255], super::pow(0x187, x, 3));
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Application supplied EXP/LOG tables.
+//!
+//! With the `external_tables` feature the crate does not embed its lookup tables. Instead the
+//! application places them wherever it likes (e.g. memory-mapped QSPI flash) and registers them
+//! with [`init_tables`] before the first encode or decode.
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use super::{EXP_SIZE, LOG_SIZE};
+
+static EXP_PTR: AtomicPtr<[u8; EXP_SIZE]> = AtomicPtr::new(ptr::null_mut());
+static LOG_PTR: AtomicPtr<[u8; LOG_SIZE]> = AtomicPtr::new(ptr::null_mut());
+
+/// Error returned by [`init_tables`] when a supplied table does not describe the 0x11d field
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TablesError {
+    /// EXP table contents are invalid
+    InvalidExp,
+    /// LOG table contents are invalid
+    InvalidLog,
+}
+
+/// Registers the EXP (512 bytes, 256 with `compact_tables`) and LOG (256 bytes) tables for the
+/// 0x11d primitive polynomial.
+///
+/// Both tables are fully validated before they are used; the previous tables (if any) stay in
+/// place on error.
+pub fn init_tables(exp: &'static [u8; EXP_SIZE],
+                   log: &'static [u8; LOG_SIZE])
+                   -> Result<(), TablesError> {
+    let mut x: u16 = 1;
+    for (i, e) in exp.iter().enumerate() {
+        let expected = if i < 255 { x as u8 } else { exp[i - 255] };
+        if *e != expected {
+            return Err(TablesError::InvalidExp);
+        }
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= 0x11d;
+        }
+    }
+
+    if log[0] != 0 || (0..255).any(|i| log[exp[i] as usize] as usize != i) {
+        return Err(TablesError::InvalidLog);
+    }
+
+    EXP_PTR.store(ptr::from_ref(exp).cast_mut(), Ordering::Release);
+    LOG_PTR.store(ptr::from_ref(log).cast_mut(), Ordering::Release);
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn exp_table() -> &'static [u8; EXP_SIZE] {
+    let p = EXP_PTR.load(Ordering::Acquire);
+    assert!(!p.is_null(), "GF tables are not initialized, call init_tables first");
+    // Safety: pointer was created from a 'static shared reference in init_tables
+    unsafe { &*p }
+}
+
+#[inline(always)]
+pub(crate) fn log_table() -> &'static [u8; LOG_SIZE] {
+    let p = LOG_PTR.load(Ordering::Acquire);
+    assert!(!p.is_null(), "GF tables are not initialized, call init_tables first");
+    // Safety: pointer was created from a 'static shared reference in init_tables
+    unsafe { &*p }
+}
+
+/// Registers tables generated at compile time, for the crate's own doctests and integration
+/// tests which can't reach the test-only tables
+#[doc(hidden)]
+pub fn init_generated_tables() {
+    static EXP: [u8; EXP_SIZE] = super::make_exp_table(super::PRIMITIVE_POLY);
+    static LOG: [u8; LOG_SIZE] = super::make_log_table(super::PRIMITIVE_POLY);
+    init_tables(&EXP, &LOG).unwrap();
+}
+
+/// Registers the embedded tables once, for tests that encode or decode
+#[cfg(test)]
+pub(crate) fn init_test_tables() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| init_tables(&super::EXP, &super::LOG).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf::{EXP, LOG};
+
+    #[test]
+    fn init_tables() {
+        init_test_tables();
+
+        let mut bad_exp = EXP;
+        bad_exp[200] ^= 1;
+        let mut bad_log = LOG;
+        bad_log[7] ^= 1;
+        let bad_exp: &'static _ = std::boxed::Box::leak(std::boxed::Box::new(bad_exp));
+        let bad_log: &'static _ = std::boxed::Box::leak(std::boxed::Box::new(bad_log));
+
+        assert_eq!(Err(TablesError::InvalidExp), super::init_tables(bad_exp, &LOG));
+        assert_eq!(Err(TablesError::InvalidLog), super::init_tables(&EXP, bad_log));
+        assert_eq!(Ok(()), super::init_tables(&EXP, &LOG));
+        assert_eq!(6, crate::gf::mul(3, 2));
+    }
+}
@@ -0,0 +1,277 @@
+//! `GaloisField` trait and GF(2^8) generated by an arbitrary primitive polynomial.
+//!
+//! Tables of such fields are computed at compile time, so e.g. `Gf2p8<0x187>` (CCSDS) costs
+//! nothing until it's used. The default field shares the tables of the parent module.
+use core::fmt::Debug;
+use super::{exp_index, exp_table, log_table, EXP_SIZE, LOG_SIZE, PRIMITIVE_POLY};
+
+/// Finite field of characteristic 2 with table based arithmetics.
+///
+/// Codecs, [`Polynom`](super::poly::Polynom) and [`poly_math`](super::poly_math) are generic
+/// over it, fields themselves are zero-sized marker types.
+pub trait GaloisField: Copy + Default + Debug + PartialEq + Eq {
+    /// Field element
+    type Elem: Copy + Default + Debug + PartialEq + Eq + 'static;
+
+    /// Number of non-zero elements, which is also the maximal codeword length
+    const ORDER: usize;
+
+    /// Logarithm table usable in const context: `LOG_TABLE[2^i] = i`, `LOG_TABLE[0]` is unused
+    const LOG_TABLE: &'static [Self::Elem];
+
+    /// `2^i`, powers wrap around modulo `ORDER`
+    fn exp(i: usize) -> Self::Elem;
+
+    /// Logarithm of a non-zero element
+    fn log(x: Self::Elem) -> usize;
+
+    /// Multiplies two field elements
+    fn mul(x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Divides `x` by `y`, `y` must not be zero
+    fn div(x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Raises `x` to the (possibly negative) `power`
+    fn pow(x: Self::Elem, power: i32) -> Self::Elem;
+
+    /// Multiplicative inverse of a non-zero element
+    fn inverse(x: Self::Elem) -> Self::Elem;
+}
+
+/// Builds EXP table for the primitive polynomial `prim`, `EXP[i] = 2^i`.
+///
+/// Fails (at compile time, if evaluated in a const context) if `prim` is not a primitive
+/// polynomial of degree 8.
+pub const fn make_exp_table(prim: u16) -> [u8; EXP_SIZE] {
+    assert!(prim & 0x100 != 0 && prim < 0x200, "primitive polynomial must have degree 8");
+
+    let mut exp = [0; EXP_SIZE];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < EXP_SIZE {
+        exp[i] = x as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= prim;
+        }
+        i += 1;
+        assert!(i % 255 == 0 || x != 1, "polynomial is not primitive");
+    }
+    exp
+}
+
+/// Builds LOG table for the primitive polynomial `prim`, `LOG[2^i] = i`, `LOG[0]` is unused.
+pub const fn make_log_table(prim: u16) -> [u8; LOG_SIZE] {
+    let exp = make_exp_table(prim);
+    let mut log = [0; LOG_SIZE];
+    let mut i = 0;
+    while i < 255 {
+        log[exp[i] as usize] = i as u8;
+        i += 1;
+    }
+    log
+}
+
+/// Builds full multiplication table for the primitive polynomial `prim`, `MUL[x][y] = x * y`.
+pub const fn make_mul_table(prim: u16) -> [[u8; LOG_SIZE]; LOG_SIZE] {
+    let exp = make_exp_table(prim);
+    let log = make_log_table(prim);
+    let mut mul = [[0; LOG_SIZE]; LOG_SIZE];
+    let mut x = 1;
+    while x < LOG_SIZE {
+        let mut y = 1;
+        while y < LOG_SIZE {
+            mul[x][y] = exp[(log[x] as usize + log[y] as usize) % 255];
+            y += 1;
+        }
+        x += 1;
+    }
+    mul
+}
+
+/// GF(2^8) generated by primitive polynomial `PRIM`, 0x11d unless specified
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::gf::Gf2p8;
+///
+/// type Ccsds = Gf2p8<0x187>;
+///
+/// assert_eq!(0x87, Ccsds::mul(0x80, 2));
+/// assert_eq!(1, Ccsds::mul(0x80, Ccsds::inverse(0x80)));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Gf2p8<const PRIM: u16 = PRIMITIVE_POLY>;
+
+impl<const PRIM: u16> Gf2p8<PRIM> {
+    /// Exponent table: `EXP[i] = 2^i`
+    pub const EXP: [u8; EXP_SIZE] = make_exp_table(PRIM);
+    /// Logarithm table: `LOG[2^i] = i`, `LOG[0]` is unused
+    pub const LOG: [u8; LOG_SIZE] = make_log_table(PRIM);
+
+    table_fn! {
+        #[inline(always)]
+        pub(crate) fn tables() -> (&'static [u8; EXP_SIZE], &'static [u8; LOG_SIZE]) {
+            if PRIM == PRIMITIVE_POLY {
+                (exp_table(), log_table())
+            } else {
+                (&Self::EXP, &Self::LOG)
+            }
+        }
+    }
+
+    table_fn! {
+        /// Multiplies two field elements
+        #[inline]
+        pub fn mul(x: u8, y: u8) -> u8 {
+            if cfg!(feature = "constant_time") {
+                return super::ct::mul(PRIM, x, y);
+            }
+
+            #[cfg(feature = "mul_table")]
+            if PRIM == PRIMITIVE_POLY {
+                return super::MUL[x as usize][y as usize];
+            }
+
+            if x == 0 || y == 0 {
+                0
+            } else {
+                let (exp, log) = Self::tables();
+                let log_x = uncheck!(log[x as usize]);
+                let log_y = uncheck!(log[y as usize]);
+                let exp_index = exp_index(log_x as usize +
+                                          log_y as usize);
+
+                uncheck!(exp[exp_index])
+            }
+        }
+    }
+
+    table_fn! {
+        /// Divides `x` by `y`, `y` must not be zero
+        #[inline]
+        pub fn div(x: u8, y: u8) -> u8 {
+            debug_assert!(y != 0);
+            if cfg!(feature = "constant_time") {
+                return super::ct::mul(PRIM, x, super::ct::inverse(PRIM, y));
+            }
+
+            if x == 0 {
+                0
+            } else {
+                let (exp, log) = Self::tables();
+                let log_x = uncheck!(log[x as usize]) as usize;
+                let log_y = uncheck!(log[y as usize]) as usize;
+                let exp_index = (log_x + 255 - log_y) % 255;
+
+                uncheck!(exp[exp_index])
+            }
+        }
+    }
+
+    table_fn! {
+        /// Raises `x` to the (possibly negative) `power`
+        #[inline]
+        pub fn pow(x: u8, power: i32) -> u8 {
+            if cfg!(feature = "constant_time") {
+                // Zero is raised as if it were one, same as with the tables where `LOG[0] = 0`
+                let x = x | super::ct::is_zero(x);
+                return super::ct::pow(PRIM, x, power.rem_euclid(255) as u8);
+            }
+
+            let (exp, log) = Self::tables();
+            let mut i = uncheck!(log[x as usize]) as i32
+                    * power
+                    % 255;
+
+            if i < 0 {
+                i += 255;
+            }
+
+            uncheck!(exp[i as usize])
+        }
+    }
+
+    table_fn! {
+        /// Multiplicative inverse of a non-zero element
+        #[inline]
+        pub fn inverse(x: u8) -> u8 {
+            if cfg!(feature = "constant_time") {
+                return super::ct::inverse(PRIM, x);
+            }
+
+            let (exp, log) = Self::tables();
+            let exp_index = 255 - uncheck!(log[x as usize]);
+            uncheck!(exp[exp_index as usize])
+        }
+    }
+}
+
+impl<const PRIM: u16> GaloisField for Gf2p8<PRIM> {
+    type Elem = u8;
+
+    const ORDER: usize = 255;
+    const LOG_TABLE: &'static [u8] = &Self::LOG;
+
+    #[inline(always)]
+    fn exp(i: usize) -> u8 {
+        let (exp, _) = Self::tables();
+        // Reduced index stays within the table whatever the caller passes
+        uncheck!(exp[i % Self::ORDER])
+    }
+
+    #[inline(always)]
+    fn log(x: u8) -> usize {
+        let (_, log) = Self::tables();
+        uncheck!(log[x as usize]) as usize
+    }
+
+    #[inline]
+    fn mul(x: u8, y: u8) -> u8 {
+        Self::mul(x, y)
+    }
+
+    #[inline]
+    fn div(x: u8, y: u8) -> u8 {
+        Self::div(x, y)
+    }
+
+    #[inline]
+    fn pow(x: u8, power: i32) -> u8 {
+        Self::pow(x, power)
+    }
+
+    #[inline]
+    fn inverse(x: u8) -> u8 {
+        Self::inverse(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ccsds() {
+        type F = Gf2p8<0x187>;
+
+        assert_eq!(0x87, F::EXP[8]);
+        for x in 1..=255u8 {
+            assert_eq!(1, F::mul(x, F::inverse(x)));
+            assert_eq!(x, F::div(F::mul(x, 0x35), 0x35));
+            assert_eq!(F::mul(x, x), F::pow(x, 2));
+        }
+    }
+
+    #[test]
+    fn exp_wraps() {
+        crate::gf::init_test_tables();
+        type F = Gf2p8;
+
+        for i in 0..255 {
+            assert_eq!(F::exp(i), F::exp(i + 255));
+            assert_eq!(F::exp(i), F::exp(i + 255 * 4000));
+        }
+        assert_eq!(1_000_000 % 255, F::log(F::exp(1_000_000)));
+    }
+}
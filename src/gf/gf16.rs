@@ -0,0 +1,154 @@
+//! GF(2^16) generated by primitive polynomial 0x1100b.
+//!
+//! 16-bit symbols allow codewords up to 65535 symbols long, so a whole 4-64 KiB block can be
+//! protected as a single codeword. Tables take 384 KiB and are built at compile time; they are
+//! linked only if the field is used.
+use super::GaloisField;
+
+/// Primitive polynomial of GF(2^16)
+pub const PRIMITIVE_POLY: u32 = 0x1100b;
+
+/// Number of non-zero field elements, maximal codeword length
+pub const ORDER: usize = 65535;
+
+/// Length of the EXP table, doubled so that `mul` never has to wrap the index
+pub const EXP_SIZE: usize = 2 * ORDER;
+/// Length of the LOG table
+pub const LOG_SIZE: usize = ORDER + 1;
+
+const fn make_exp_table() -> [u16; EXP_SIZE] {
+    let mut exp = [0; EXP_SIZE];
+    let mut x: u32 = 1;
+    let mut i = 0;
+    while i < EXP_SIZE {
+        exp[i] = x as u16;
+        x <<= 1;
+        if x & 0x10000 != 0 {
+            x ^= PRIMITIVE_POLY;
+        }
+        i += 1;
+    }
+    exp
+}
+
+const fn make_log_table() -> [u16; LOG_SIZE] {
+    let exp = make_exp_table();
+    let mut log = [0; LOG_SIZE];
+    let mut i = 0;
+    while i < ORDER {
+        log[exp[i] as usize] = i as u16;
+        i += 1;
+    }
+    log
+}
+
+/// Exponent table: `EXP[i] = 2^i`
+pub static EXP: [u16; EXP_SIZE] = make_exp_table();
+/// Logarithm table: `LOG[2^i] = i`, `LOG[0]` is unused
+pub static LOG: [u16; LOG_SIZE] = make_log_table();
+
+/// Multiplies two field elements
+#[inline]
+pub fn mul(x: u16, y: u16) -> u16 {
+    if x == 0 || y == 0 {
+        0
+    } else {
+        let log_x = LOG[x as usize] as usize;
+        let log_y = LOG[y as usize] as usize;
+        EXP[log_x + log_y]
+    }
+}
+
+/// Divides `x` by `y`, `y` must not be zero
+#[inline]
+pub fn div(x: u16, y: u16) -> u16 {
+    debug_assert!(y != 0);
+    if x == 0 {
+        0
+    } else {
+        let log_x = LOG[x as usize] as usize;
+        let log_y = LOG[y as usize] as usize;
+        EXP[(log_x + ORDER - log_y) % ORDER]
+    }
+}
+
+/// Raises `x` to the (possibly negative) `power`
+#[inline]
+pub fn pow(x: u16, power: i64) -> u16 {
+    let i = (LOG[x as usize] as i64 * power).rem_euclid(ORDER as i64);
+    EXP[i as usize]
+}
+
+/// Multiplicative inverse of a non-zero element
+#[inline]
+pub fn inverse(x: u16) -> u16 {
+    EXP[ORDER - LOG[x as usize] as usize]
+}
+
+/// GF(2^16) as a [`GaloisField`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Gf2p16;
+
+impl GaloisField for Gf2p16 {
+    type Elem = u16;
+
+    const ORDER: usize = ORDER;
+    const LOG_TABLE: &'static [u16] = &LOG;
+
+    #[inline(always)]
+    fn exp(i: usize) -> u16 {
+        EXP[i % ORDER]
+    }
+
+    #[inline(always)]
+    fn log(x: u16) -> usize {
+        LOG[x as usize] as usize
+    }
+
+    #[inline]
+    fn mul(x: u16, y: u16) -> u16 {
+        mul(x, y)
+    }
+
+    #[inline]
+    fn div(x: u16, y: u16) -> u16 {
+        div(x, y)
+    }
+
+    #[inline]
+    fn pow(x: u16, power: i32) -> u16 {
+        pow(x, power as i64)
+    }
+
+    #[inline]
+    fn inverse(x: u16) -> u16 {
+        inverse(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables() {
+        // Generator must go through every non-zero element exactly once
+        assert_eq!(1, EXP[ORDER]);
+        assert!(EXP[1..ORDER].iter().all(|x| *x != 1));
+        for x in 1..=ORDER {
+            assert_eq!(x as u16, EXP[LOG[x] as usize]);
+        }
+    }
+
+    #[test]
+    fn arithmetics() {
+        for x in (1..=ORDER as u16).step_by(97) {
+            assert_eq!(1, mul(x, inverse(x)));
+            assert_eq!(x, div(mul(x, 0x1234), 0x1234));
+            assert_eq!(mul(x, mul(x, x)), pow(x, 3));
+            assert_eq!(inverse(x), pow(x, -1));
+        }
+        assert_eq!(0, mul(0, 0x8000));
+        assert_eq!(0x100b, mul(0x8000, 2));
+    }
+}
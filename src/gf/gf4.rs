@@ -0,0 +1,126 @@
+//! GF(2^4) generated by primitive polynomial 0x13.
+//!
+//! 4-bit symbols give 15 symbols long codewords, which suit very short frames. Elements are
+//! stored in the low nibble of `u8`.
+use super::GaloisField;
+
+/// Primitive polynomial of GF(2^4)
+pub const PRIMITIVE_POLY: u8 = 0x13;
+
+/// Number of non-zero field elements, maximal codeword length
+pub const ORDER: usize = 15;
+
+/// Exponent table: `EXP[i] = 2^i`, doubled so that `mul` never has to wrap the index
+pub const EXP: [u8; 2 * ORDER] = [
+    1, 2, 4, 8, 3, 6, 12, 11, 5, 10, 7, 14, 15, 13, 9,
+    1, 2, 4, 8, 3, 6, 12, 11, 5, 10, 7, 14, 15, 13, 9,
+];
+
+/// Logarithm table: `LOG[2^i] = i`, `LOG[0]` is unused
+pub const LOG: [u8; ORDER + 1] = [0, 0, 1, 4, 2, 8, 5, 10, 3, 14, 9, 7, 6, 13, 11, 12];
+
+/// Multiplies two field elements
+#[inline]
+pub fn mul(x: u8, y: u8) -> u8 {
+    if x == 0 || y == 0 {
+        0
+    } else {
+        EXP[LOG[x as usize] as usize + LOG[y as usize] as usize]
+    }
+}
+
+/// Divides `x` by `y`, `y` must not be zero
+#[inline]
+pub fn div(x: u8, y: u8) -> u8 {
+    debug_assert!(y != 0);
+    if x == 0 {
+        0
+    } else {
+        EXP[LOG[x as usize] as usize + ORDER - LOG[y as usize] as usize]
+    }
+}
+
+/// Raises `x` to the (possibly negative) `power`
+#[inline]
+pub fn pow(x: u8, power: i32) -> u8 {
+    let i = (LOG[x as usize] as i32 * power).rem_euclid(ORDER as i32);
+    EXP[i as usize]
+}
+
+/// Multiplicative inverse of a non-zero element
+#[inline]
+pub fn inverse(x: u8) -> u8 {
+    EXP[ORDER - LOG[x as usize] as usize]
+}
+
+/// GF(2^4) as a [`GaloisField`], e.g. for `Encoder::<4, Gf2p4>`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Gf2p4;
+
+impl GaloisField for Gf2p4 {
+    type Elem = u8;
+
+    const ORDER: usize = ORDER;
+    const LOG_TABLE: &'static [u8] = &LOG;
+
+    #[inline(always)]
+    fn exp(i: usize) -> u8 {
+        EXP[i % ORDER]
+    }
+
+    #[inline(always)]
+    fn log(x: u8) -> usize {
+        LOG[x as usize] as usize
+    }
+
+    #[inline]
+    fn mul(x: u8, y: u8) -> u8 {
+        mul(x, y)
+    }
+
+    #[inline]
+    fn div(x: u8, y: u8) -> u8 {
+        div(x, y)
+    }
+
+    #[inline]
+    fn pow(x: u8, power: i32) -> u8 {
+        pow(x, power)
+    }
+
+    #[inline]
+    fn inverse(x: u8) -> u8 {
+        inverse(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables() {
+        let mut x = 1u8;
+        for e in EXP.iter() {
+            assert_eq!(x, *e);
+            x <<= 1;
+            if x & 0x10 != 0 {
+                x ^= PRIMITIVE_POLY;
+            }
+        }
+        for x in 1..=ORDER {
+            assert_eq!(x as u8, EXP[LOG[x] as usize]);
+        }
+    }
+
+    #[test]
+    fn arithmetics() {
+        for x in 1..16 {
+            assert_eq!(1, mul(x, inverse(x)));
+            assert_eq!(x, div(mul(x, 7), 7));
+            assert_eq!(mul(x, x), pow(x, 2));
+            assert_eq!(inverse(x), pow(x, -1));
+        }
+        assert_eq!(3, mul(8, 2));
+    }
+}
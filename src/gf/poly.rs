@@ -1,27 +1,63 @@
+//! Fixed-capacity polynomial storage
+use core::marker::PhantomData;
+use super::{GaloisField, Gf2p8};
+
+/// Fixed-capacity polynomial over byte field `F`, coefficients stored
+/// from the highest power down
 #[derive(Copy)]
-pub struct Polynom {
+pub struct Polynom<F: GaloisField<Elem = u8> = Gf2p8> {
     array: [u8; crate::POLYNOMIAL_MAX_LENGTH],
     length: usize,
     dirty: bool,
+    field: PhantomData<F>,
 }
 
-impl Polynom {
+impl<F: GaloisField<Elem = u8>> Polynom<F> {
+    /// Constructs an empty polynomial
     #[inline]
-    pub fn new() -> Polynom {
+    pub const fn new() -> Self {
         Polynom {
             array: [0; crate::POLYNOMIAL_MAX_LENGTH],
             length: 0,
             dirty: false,
+            field: PhantomData,
         }
     }
 
+    /// Constructs a zero polynomial with `len` coefficients
     #[inline]
-    pub fn with_length(len: usize) -> Polynom {
+    pub const fn with_length(len: usize) -> Self {
         let mut p = Polynom::new();
         p.length = len;
         p
     }
 
+    /// Constructs a polynomial from an array of coefficients, highest power first. Being `const`,
+    /// it lets generator polynomials and fixed locators live in `const` and `static` items.
+    ///
+    /// # Panics
+    /// If `N` is larger than 256, at compile time in a const context.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// // (x + 1)(x + 2)
+    /// const GENERATOR: Polynom = Polynom::from_array([1, 3, 2]);
+    /// assert_eq!([1, 3, 2], *GENERATOR);
+    /// ```
+    pub const fn from_array<const N: usize>(coefs: [u8; N]) -> Self {
+        assert!(N <= crate::POLYNOMIAL_MAX_LENGTH, "too many coefficients for a Polynom");
+        let mut p = Polynom::with_length(N);
+        let mut i = 0;
+        while i < N {
+            p.array[i] = coefs[i];
+            i += 1;
+        }
+        p
+    }
+
+    /// Resizes polynomial, new coefficients are zeroed
     #[inline]
     pub fn set_length(&mut self, new_len: usize) {
         let old_len = self.len();
@@ -38,39 +74,185 @@ impl Polynom {
         }
     }
 
+    /// Number of coefficients
     #[inline]
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Returns `true` if polynomial has no coefficients
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Degree of the polynomial, i.e. power of the highest non-zero coefficient, or `None` for
+    /// the zero polynomial
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// let poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[0, 0, 3, 1][..]);
+    /// assert_eq!(Some(1), poly.degree());
+    /// assert_eq!(None, Polynom::<reed_solomon::gf::Gf2p8>::with_length(3).degree());
+    /// ```
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        self.as_poly_slice().degree()
+    }
+
+    /// Returns `true` if all coefficients are zero, including when there are none
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.as_poly_slice().is_zero()
+    }
+
+    /// Borrows coefficients as a [`PolySlice`]
+    #[inline]
+    pub fn as_poly_slice(&self) -> PolySlice<'_, F> {
+        PolySlice::new(self)
+    }
+
+    /// Drops leading zero coefficients, so that the zero polynomial becomes empty and
+    /// `len() == degree() + 1` otherwise
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// let mut poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[0, 0, 3, 1][..]);
+    /// poly.trim();
+    /// assert_eq!([3, 1], *poly);
+    /// ```
+    pub fn trim(&mut self) {
+        let shift = self.iter().take_while(|x| **x == 0).count();
+        let len = self.len() - shift;
+        self.array.copy_within(shift..shift + len, 0);
+        // Whatever was past the end is zeroed, so it doesn't need to be on growth
+        self.array[len..].fill(0);
+        self.length = len;
+        self.dirty = false;
+    }
+
+    /// Reverses coefficients order
     #[inline]
     pub fn reverse(mut self) -> Self {
         (*self).reverse();
         self
     }
 
+    /// Appends coefficient to the end (lowest power)
+    ///
+    /// # Panics
+    /// If the polynomial is full, see [`try_push`](Self::try_push).
     #[inline]
     pub fn push(&mut self, x: u8) {
         self.array[self.length] = x;
         self.length += 1;
     }
+
+    /// Appends coefficient to the end (lowest power), errors if the polynomial is full
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// let mut poly = Polynom::<reed_solomon::gf::Gf2p8>::with_length(256);
+    /// assert!(poly.try_push(1).is_err());
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, x: u8) -> Result<(), CapacityError> {
+        if self.length == crate::POLYNOMIAL_MAX_LENGTH {
+            return Err(CapacityError);
+        }
+        self.push(x);
+        Ok(())
+    }
+
+    /// Constructs a polynomial from coefficients, errors if there are more than it can hold.
+    ///
+    /// `TryFrom<&[u8]>` can't be implemented next to the infallible `From<&[u8]>`, which
+    /// panics instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// let poly = Polynom::<reed_solomon::gf::Gf2p8>::try_from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!([1, 2, 3], *poly);
+    /// assert!(Polynom::<reed_solomon::gf::Gf2p8>::try_from_slice(&[0; 300]).is_err());
+    /// ```
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, CapacityError> {
+        if slice.len() > crate::POLYNOMIAL_MAX_LENGTH {
+            return Err(CapacityError);
+        }
+        let mut poly = Polynom::with_length(slice.len());
+        poly[..].copy_from_slice(slice);
+        Ok(poly)
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> Polynom<F> {
+    /// Collects coefficients from an iterator, errors if there are more than it can hold.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    /// use reed_solomon::gf::Gf2p8;
+    ///
+    /// let poly = Polynom::<Gf2p8>::try_from_iter((1..4).rev()).unwrap();
+    /// assert_eq!([3, 2, 1], *poly);
+    /// assert!(Polynom::<Gf2p8>::try_from_iter(core::iter::repeat_n(0, 300)).is_err());
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, CapacityError> {
+        let mut poly = Polynom::new();
+        for x in iter {
+            poly.try_push(x)?;
+        }
+        Ok(poly)
+    }
 }
 
-impl Clone for Polynom {
+/// Collects coefficients, highest power first. Coefficients past the capacity of 256 are
+/// dropped, use [`Polynom::try_from_iter`] to detect that.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::Polynom;
+///
+/// let poly: Polynom = [1u8, 2, 3].iter().map(|x| x * 2).collect();
+/// assert_eq!([2, 4, 6], *poly);
+/// ```
+impl<F: GaloisField<Elem = u8>> FromIterator<u8> for Polynom<F> {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut poly = Polynom::new();
+        for x in iter.into_iter().take(crate::POLYNOMIAL_MAX_LENGTH) {
+            poly.push(x);
+        }
+        poly
+    }
+}
+
+/// Error returned when a polynomial would exceed its fixed capacity of 256 coefficients
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl<F: GaloisField<Elem = u8>> Clone for Polynom<F> {
     #[inline]
-    fn clone(&self) -> Polynom {
+    fn clone(&self) -> Self {
         *self
     }
 }
 
-impl Default for Polynom {
+impl<F: GaloisField<Elem = u8>> Default for Polynom<F> {
     fn default() -> Self {
         Self::new()
     }
 }
 
 use core::ops::Deref;
-impl Deref for Polynom {
+impl<F: GaloisField<Elem = u8>> Deref for Polynom<F> {
     type Target = [u8];
     #[inline]
     fn deref(&self) -> &Self::Target {
@@ -80,7 +262,7 @@ impl Deref for Polynom {
 }
 
 use core::ops::DerefMut;
-impl DerefMut for Polynom {
+impl<F: GaloisField<Elem = u8>> DerefMut for Polynom<F> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         let len = self.len();
@@ -88,28 +270,211 @@ impl DerefMut for Polynom {
     }
 }
 
-impl<'a> From<&'a [u8]> for Polynom {
+impl<'a, F: GaloisField<Elem = u8>> From<&'a [u8]> for Polynom<F> {
+    /// # Panics
+    /// If the slice is longer than 256 coefficients, see [`Polynom::try_from_slice`].
     #[inline]
-    fn from(slice: &'a [u8]) -> Polynom {
-        debug_assert!(slice.len() <= crate::POLYNOMIAL_MAX_LENGTH);
+    fn from(slice: &'a [u8]) -> Self {
         let mut poly = Polynom::with_length(slice.len());
         poly[..].copy_from_slice(slice);
         poly
     }
 }
 
+/// Borrowed polynomial over byte field `F`, coefficients from the highest power down
+///
+/// Polynomial math runs directly on the caller's slice, e.g. a received block, without copying
+/// it into a [`Polynom`] first. Results that need storage are still returned as [`Polynom`].
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly::PolySlice;
+///
+/// let received = [1, 0, 3];
+/// let poly = PolySlice::<reed_solomon::gf::Gf2p8>::new(&received);
+/// assert_eq!(Some(2), poly.degree());
+/// assert_eq!(7, poly.eval(2));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct PolySlice<'a, F: GaloisField<Elem = u8> = Gf2p8> {
+    coefs: &'a [u8],
+    field: PhantomData<F>,
+}
+
+impl<'a, F: GaloisField<Elem = u8>> PolySlice<'a, F> {
+    /// Wraps `coefs`, highest power first
+    #[inline]
+    pub const fn new(coefs: &'a [u8]) -> Self {
+        PolySlice {
+            coefs,
+            field: PhantomData,
+        }
+    }
+
+    /// Underlying coefficients, with the lifetime of the borrowed slice
+    #[inline]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.coefs
+    }
+
+    /// Degree of the polynomial, see [`Polynom::degree`]
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        let shift = self.coefs.iter().position(|x| *x != 0)?;
+        Some(self.coefs.len() - 1 - shift)
+    }
+
+    /// Returns `true` if all coefficients are zero, including when there are none
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.coefs.iter().all(|x| *x == 0)
+    }
+
+    /// Copies coefficients into a [`Polynom`]
+    ///
+    /// # Errors
+    /// If there are more than 256 coefficients.
+    #[inline]
+    pub fn to_polynom(&self) -> Result<Polynom<F>, CapacityError> {
+        Polynom::try_from_slice(self.coefs)
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> Deref for PolySlice<'_, F> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.coefs
+    }
+}
+
+impl<'a, F: GaloisField<Elem = u8>> From<&'a [u8]> for PolySlice<'a, F> {
+    #[inline]
+    fn from(coefs: &'a [u8]) -> Self {
+        PolySlice::new(coefs)
+    }
+}
+
+impl<'a, F: GaloisField<Elem = u8>> From<&'a Polynom<F>> for PolySlice<'a, F> {
+    #[inline]
+    fn from(poly: &'a Polynom<F>) -> Self {
+        poly.as_poly_slice()
+    }
+}
+
+/// Polynomials are equal if they have the same coefficients, regardless of what is left in
+/// the storage past their length. Leading zeros count, see [`Polynom::trim`].
+impl<F: GaloisField<Elem = u8>> PartialEq for Polynom<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> Eq for Polynom<F> {}
+
+impl<F: GaloisField<Elem = u8>> core::hash::Hash for Polynom<F> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self[..].hash(state);
+    }
+}
+
+/// Serialized as a sequence of coefficients, highest power first
+#[cfg(feature = "serde")]
+impl<F: GaloisField<Elem = u8>> serde::Serialize for Polynom<F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Accepts a sequence of coefficients or a byte string, of at most 256 coefficients
+#[cfg(feature = "serde")]
+impl<'de, F: GaloisField<Elem = u8>> serde::Deserialize<'de> for Polynom<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CoefsVisitor<F: GaloisField<Elem = u8>>(PhantomData<F>);
+
+        impl<'de, F: GaloisField<Elem = u8>> serde::de::Visitor<'de> for CoefsVisitor<F> {
+            type Value = Polynom<F>;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("at most 256 polynomial coefficients")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut poly = Polynom::new();
+                while let Some(x) = seq.next_element()? {
+                    poly.try_push(x).map_err(|_| serde::de::Error::invalid_length(poly.len() + 1, &self))?;
+                }
+                Ok(poly)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                Polynom::try_from_slice(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_seq(CoefsVisitor(PhantomData))
+    }
+}
+
 use core::fmt;
-impl fmt::Debug for Polynom {
+impl<F: GaloisField<Elem = u8>> fmt::Debug for Polynom<F> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "{:?}", &self[..])
     }
 }
 
+/// Renders the polynomial as an expression such as `x^3 + 5x + 1`, skipping zero terms
+impl<F: GaloisField<Elem = u8>> fmt::Display for Polynom<F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for (i, x) in self.iter().enumerate() {
+            if *x == 0 {
+                continue;
+            }
+            if !first {
+                fmt.write_str(" + ")?;
+            }
+            first = false;
+
+            let power = self.len() - 1 - i;
+            if *x != 1 || power == 0 {
+                write!(fmt, "{}", x)?;
+            }
+            match power {
+                0 => {}
+                1 => fmt.write_str("x")?,
+                _ => write!(fmt, "x^{}", power)?,
+            }
+        }
+
+        if first {
+            fmt.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn const_constructors() {
+        const EMPTY: super::Polynom = super::Polynom::new();
+        const ZERO: super::Polynom = super::Polynom::with_length(3);
+        static LOCATOR: super::Polynom = super::Polynom::from_array([1, 0, 7, 9]);
+
+        assert!(EMPTY.is_empty());
+        assert_eq!([0, 0, 0], *ZERO);
+        assert_eq!([1, 0, 7, 9], *LOCATOR);
+        assert_eq!(LOCATOR, polynom![1, 0, 7, 9]);
+        assert_eq!(256, super::Polynom::<crate::gf::Gf2p8>::from_array([3; 256]).len());
+    }
+
     #[test]
     fn push() {
-        let mut poly = polynom![];
+        let mut poly: super::Polynom = polynom![];
         for i in 0..10 {
             poly.push(i);
             for j in 0..(i as usize) {
@@ -118,9 +483,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_push() {
+        let mut poly = super::Polynom::<crate::gf::Gf2p8>::try_from_slice(&[7; 255]).unwrap();
+        assert_eq!(Ok(()), poly.try_push(1));
+        assert_eq!(256, poly.len());
+        assert_eq!(Err(super::CapacityError), poly.try_push(2));
+        assert_eq!(256, poly.len());
+        assert_eq!(Err(super::CapacityError), super::Polynom::<crate::gf::Gf2p8>::try_from_slice(&[0; 257]).map(|_| ()));
+    }
+
+    #[test]
+    fn trim() {
+        let mut poly: super::Polynom = polynom![0, 0, 5, 0, 1];
+        assert_eq!(Some(2), poly.degree());
+        assert!(!poly.is_zero());
+
+        poly.set_length(3);
+        poly.trim();
+        assert_eq!([5], *poly);
+        assert_eq!(Some(0), poly.degree());
+        assert!(!poly.dirty);
+        poly.set_length(3);
+        assert_eq!([5, 0, 0], *poly);
+
+        let mut zero: super::Polynom = polynom![0; 4];
+        assert!(zero.is_zero());
+        assert_eq!(None, zero.degree());
+        zero.trim();
+        assert!(zero.is_empty());
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn from_iter() {
+        let poly: super::Polynom = (0..10).collect();
+        assert_eq!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9], *poly);
+
+        let truncated: super::Polynom = core::iter::repeat_n(7, 300).collect();
+        assert_eq!(256, truncated.len());
+
+        let exact = super::Polynom::<crate::gf::Gf2p8>::try_from_iter(core::iter::repeat_n(7, 256)).unwrap();
+        assert_eq!(*truncated, *exact);
+        let overflow = super::Polynom::<crate::gf::Gf2p8>::try_from_iter(core::iter::repeat_n(0, 257));
+        assert_eq!(Err(super::CapacityError), overflow.map(|_| ()));
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |poly: &super::Polynom| {
+            let mut hasher = DefaultHasher::new();
+            poly.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let mut shrunk: super::Polynom = polynom![1, 2, 3, 4];
+        shrunk.set_length(2);
+        let poly: super::Polynom = polynom![1, 2];
+        assert_eq!(poly, shrunk);
+        assert_eq!(hash(&poly), hash(&shrunk));
+
+        let leading_zero: super::Polynom = polynom![0, 1, 2];
+        assert_ne!(poly, leading_zero);
+        assert_ne!(poly, polynom![1, 3]);
+    }
+
+    #[test]
+    fn display() {
+        use std::string::ToString;
+
+        let poly: super::Polynom = polynom![1, 0, 5, 1];
+        assert_eq!("x^3 + 5x + 1", poly.to_string());
+        let poly: super::Polynom = polynom![0, 7, 1, 0];
+        assert_eq!("7x^2 + x", poly.to_string());
+        let poly: super::Polynom = polynom![0, 0];
+        assert_eq!("0", poly.to_string());
+        assert_eq!("0", super::Polynom::<crate::gf::Gf2p8>::new().to_string());
+    }
+
     #[test]
     fn reverse() {
-        let poly = polynom![5, 4, 3, 2, 1, 0];
+        let poly: super::Polynom = polynom![5, 4, 3, 2, 1, 0];
         for (i, x) in poly.reverse().iter().enumerate() {
             assert_eq!(i, *x as usize);
         }
@@ -128,7 +574,7 @@ mod tests {
 
     #[test]
     fn set_length() {
-        let mut poly = polynom![1; 8];
+        let mut poly: super::Polynom = polynom![1; 8];
         poly.set_length(2);
         poly.set_length(6);
 
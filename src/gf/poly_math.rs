@@ -1,61 +1,63 @@
+//! Polynomial arithmetic over GF(2^8) on plain `[u8]` slices (highest power first)
+//! and building blocks of the decoder.
+//!
+//! Slice traits work in the default field, decoder building blocks take the field
+//! as their first type parameter.
 use core::cmp::max;
-use crate::gf::poly::Polynom;
-use crate::gf;
+use heapless::Vec;
+use crate::gf::poly::{PolySlice, Polynom};
+use crate::gf::{GaloisField, Gf2p8};
 
+/// Multiplication by a scalar
 pub trait Scale {
+    /// Returns polynomial multiplied by `x`
     fn scale(&self, x: u8) -> Polynom;
+    /// Multiplies polynomial by `x` in place
     fn scale_assign(&mut self, x: u8) -> &mut Self;
 }
 
+/// Polynomial addition
 pub trait Add {
+    /// Returns sum of two polynomials
     fn add(&self, rhs: &Self) -> Polynom;
+    /// Adds `rhs` in place, `rhs` must not be longer than `self`
     fn add_assign(&mut self, rhs: &Self) -> &mut Self;
 }
 
+/// Polynomial multiplication
 pub trait Mul {
+    /// Returns product of two polynomials
     fn mul(&self, rhs: &Self) -> Polynom;
 }
 
+/// Polynomial division
 pub trait Div {
+    /// Returns quotient and remainder of division by `rhs`
     fn div(&self, rhs: &Self) -> (Polynom, Polynom);
 }
 
+/// Polynomial evaluation
 pub trait Eval {
+    /// Evaluates polynomial at `x`
     fn eval(&self, x: u8) -> u8;
 }
 
 impl Scale for [u8] {
     #[inline]
     fn scale(&self, x: u8) -> Polynom {
-        let mut poly = Polynom::from(self);
-        poly.scale_assign(x);
-        poly
+        Polynom::from(self).scale(x)
     }
 
     #[inline]
     fn scale_assign(&mut self, x: u8) -> &mut Self {
-        for px in self.iter_mut() {
-            *px = gf::mul(*px, x);
-        }
+        scale_slice::<Gf2p8>(self, x);
         self
     }
 }
 
 impl Add for [u8] {
     fn add(&self, rhs: &Self) -> Polynom {
-        let mut poly = Polynom::with_length(max(self.len(), rhs.len()));
-
-        for (i, x) in self.iter().enumerate() {
-            let index = i + poly.len() - self.len();
-            uncheck_mut!(poly[index]) = *x;
-        }
-
-        for (i, x) in rhs.iter().enumerate() {
-            let index = i + poly.len() - rhs.len();
-            uncheck_mut!(poly[index]) ^= *x;
-        }
-
-        poly
+        add_slices(self, rhs)
     }
 
     fn add_assign(&mut self, rhs: &Self) -> &mut Self {
@@ -68,62 +70,874 @@ impl Add for [u8] {
 impl Mul for [u8] {
     #[inline]
     fn mul(&self, rhs: &Self) -> Polynom {
-        let mut poly = Polynom::with_length(self.len() + rhs.len() - 1);
+        mul_slices(self, rhs)
+    }
+}
 
-        for (j, rhs_x) in rhs.iter().enumerate() {
-            for (i, self_x) in self.iter().enumerate() {
-                uncheck_mut!(poly[i + j]) ^= gf::mul(*self_x, *rhs_x);
+impl Div for [u8] {
+    fn div(&self, rhs: &Self) -> (Polynom, Polynom) {
+        div_slices(self, rhs)
+    }
+}
+
+impl Eval for [u8] {
+    #[inline]
+    fn eval(&self, x: u8) -> u8 {
+        eval::<Gf2p8>(self, x)
+    }
+}
+
+/// Arithmetic over the field of the polynomial. Unlike the traits above, which are implemented
+/// for plain slices of the default field, these methods respect `F`.
+impl<F: GaloisField<Elem = u8>> Polynom<F> {
+    /// Returns polynomial multiplied by `x`
+    #[inline]
+    pub fn scale(&self, x: u8) -> Self {
+        let mut poly = *self;
+        poly.scale_assign(x);
+        poly
+    }
+
+    /// Multiplies polynomial by `x` in place
+    #[inline]
+    pub fn scale_assign(&mut self, x: u8) -> &mut Self {
+        scale_slice::<F>(self, x);
+        self
+    }
+
+    /// Returns polynomial multiplied by `x^k`, i.e. with `k` zero coefficients appended
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// // (x + 2) * x^2
+    /// let poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[1, 2][..]);
+    /// assert_eq!([1, 2, 0, 0], *poly.shift_left(2));
+    /// ```
+    ///
+    /// # Panics
+    /// If the product has more than 256 coefficients.
+    #[inline]
+    pub fn shift_left(&self, k: usize) -> Self {
+        let mut poly = *self;
+        poly.shift_left_assign(k);
+        poly
+    }
+
+    /// Multiplies polynomial by `x^k` in place, see [`shift_left`](Self::shift_left)
+    #[inline]
+    pub fn shift_left_assign(&mut self, k: usize) -> &mut Self {
+        let len = self.len() + k;
+        assert!(len <= crate::POLYNOMIAL_MAX_LENGTH, "too many coefficients for a Polynom");
+        self.set_length(len);
+        self
+    }
+
+    /// Returns sum of two polynomials
+    #[inline]
+    pub fn add(&self, rhs: &[u8]) -> Self {
+        add_slices(self, rhs)
+    }
+
+    /// Returns product of two polynomials
+    #[inline]
+    pub fn mul(&self, rhs: &[u8]) -> Self {
+        mul_slices(self, rhs)
+    }
+
+    /// Adds `rhs` in place, growing the polynomial if `rhs` is longer, without a temporary copy
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// let mut poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[1, 2][..]);
+    /// poly.add_assign(&[3, 4, 5]);
+    /// assert_eq!([3, 5, 7], *poly);
+    /// ```
+    pub fn add_assign(&mut self, rhs: &[u8]) -> &mut Self {
+        let len = self.len();
+        if rhs.len() > len {
+            let grow = rhs.len() - len;
+            self.set_length(rhs.len());
+            self.copy_within(..len, grow);
+            self[..grow].fill(0);
+        }
+
+        let offset = self.len() - rhs.len();
+        for (x, rhs_x) in self[offset..].iter_mut().zip(rhs.iter()) {
+            *x ^= *rhs_x;
+        }
+        self
+    }
+
+    /// Multiplies by `rhs` in place without a temporary copy
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Polynom;
+    ///
+    /// let mut poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[1, 2][..]);
+    /// poly.mul_assign(&[1, 3]);
+    /// assert_eq!([1, 1, 6], *poly);
+    /// ```
+    pub fn mul_assign(&mut self, rhs: &[u8]) -> &mut Self {
+        let len = self.len();
+        if len == 0 || rhs.is_empty() {
+            self.set_length(0);
+            return self;
+        }
+
+        self.set_length(len + rhs.len() - 1);
+        // Coefficient k only depends on coefficients of self up to k, so going from the lowest
+        // power up overwrites each of them after its last use
+        for k in (0..self.len()).rev() {
+            let first = k.saturating_sub(rhs.len() - 1);
+            let mut y = 0;
+            for i in first..len.min(k + 1) {
+                y ^= F::mul(uncheck!(self[i]), uncheck!(rhs[k - i]));
             }
+            uncheck_mut!(self[k]) = y;
         }
+        self
+    }
 
-        poly
+    /// Returns quotient and remainder of division by monic `rhs`
+    #[inline]
+    pub fn div(&self, rhs: &[u8]) -> (Self, Self) {
+        div_slices(self, rhs)
+    }
+
+    /// Returns quotient and remainder of division by any non-zero `rhs`, unlike [`div`](Self::div)
+    /// which needs a monic divisor. Leading zeros of `rhs` are ignored and the remainder is
+    /// returned without leading zeros.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Polynom;
+    ///
+    /// let dividend = Polynom::<reed_solomon::gf::Gf2p8>::from(&[6, 0, 3][..]);
+    /// let (quotient, remainder) = dividend.div_mod(&[2, 1]);
+    ///
+    /// assert_eq!(*dividend, *(quotient.mul(&[2, 1]).add(&remainder)));
+    /// ```
+    ///
+    /// # Panics
+    /// If `rhs` is the zero polynomial.
+    pub fn div_mod(&self, rhs: &[u8]) -> (Self, Self) {
+        let shift = rhs.iter().take_while(|&&v| v == 0).count();
+        assert!(shift < rhs.len(), "division by zero polynomial");
+        div_rem::<F>(self, &rhs[shift..])
+    }
+
+    /// Formal derivative, one coefficient shorter. In characteristic 2 `i * a_i` is `a_i` for
+    /// odd `i` and zero for even `i`, so only odd powers survive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Polynom;
+    ///
+    /// // x^3 + 5x^2 + 7x + 9
+    /// let poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[1, 5, 7, 9][..]);
+    /// assert_eq!([1, 0, 7], *poly.derivative());
+    /// ```
+    pub fn derivative(&self) -> Self {
+        derivative_slice(self)
+    }
+
+    /// Evaluates polynomial at `x` with Horner's rule, see [`eval`](fn@eval)
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Polynom;
+    ///
+    /// let poly = Polynom::<reed_solomon::gf::Gf2p8>::from(&[1, 0, 3][..]);
+    /// assert_eq!(7, poly.eval(2));
+    /// ```
+    #[inline]
+    pub fn eval(&self, x: u8) -> u8 {
+        eval::<F>(self, x)
     }
 }
 
-impl Div for [u8] {
-    fn div(&self, rhs: &Self) -> (Polynom, Polynom) {
-        let mut poly = Polynom::from(self);
+impl<F: GaloisField<Elem = u8>> PolySlice<'_, F> {
+    /// Same as [`Polynom::add`]
+    #[inline]
+    pub fn add(&self, rhs: &[u8]) -> Polynom<F> {
+        add_slices(self, rhs)
+    }
+
+    /// Same as [`Polynom::mul`]
+    #[inline]
+    pub fn mul(&self, rhs: &[u8]) -> Polynom<F> {
+        mul_slices(self, rhs)
+    }
+
+    /// Same as [`Polynom::div_mod`]
+    pub fn div_mod(&self, rhs: &[u8]) -> (Polynom<F>, Polynom<F>) {
+        let shift = rhs.iter().take_while(|&&v| v == 0).count();
+        assert!(shift < rhs.len(), "division by zero polynomial");
+        div_rem::<F>(self, &rhs[shift..])
+    }
+
+    /// Same as [`Polynom::derivative`]
+    #[inline]
+    pub fn derivative(&self) -> Polynom<F> {
+        derivative_slice(self)
+    }
+
+    /// Same as [`Polynom::eval`], for any number of coefficients
+    #[inline]
+    pub fn eval(&self, x: u8) -> u8 {
+        eval::<F>(self, x)
+    }
+}
+
+/// `a + b`, same as [`Polynom::add`]
+impl<F: GaloisField<Elem = u8>> core::ops::Add for Polynom<F> {
+    type Output = Self;
 
-        // If divisor's degree (len-1) is bigger, all dividend is a remainder
-        let divisor_degree = rhs.len() - 1;
-        if self.len() < divisor_degree {
-            return (Polynom::new(), poly);
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        add_slices(&self, &rhs)
+    }
+}
+
+/// `a - b`, which is the same as `a + b` in characteristic 2
+impl<F: GaloisField<Elem = u8>> core::ops::Sub for Polynom<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        add_slices(&self, &rhs)
+    }
+}
+
+/// `a * b`, same as [`Polynom::mul`]
+impl<F: GaloisField<Elem = u8>> core::ops::Mul for Polynom<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        mul_slices(&self, &rhs)
+    }
+}
+
+/// `a * x` for a scalar `x`, same as [`Polynom::scale`]
+impl<F: GaloisField<Elem = u8>> core::ops::Mul<u8> for Polynom<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, x: u8) -> Self {
+        scale_slice::<F>(&mut self, x);
+        self
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> core::ops::AddAssign for Polynom<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        Polynom::add_assign(self, &rhs);
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> core::ops::SubAssign for Polynom<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        Polynom::add_assign(self, &rhs);
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> core::ops::MulAssign for Polynom<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        Polynom::mul_assign(self, &rhs);
+    }
+}
+
+impl<F: GaloisField<Elem = u8>> core::ops::MulAssign<u8> for Polynom<F> {
+    #[inline]
+    fn mul_assign(&mut self, x: u8) {
+        self.scale_assign(x);
+    }
+}
+
+#[inline]
+fn scale_slice<F: GaloisField<Elem = u8>>(poly: &mut [u8], x: u8) {
+    for px in poly.iter_mut() {
+        *px = F::mul(*px, x);
+    }
+}
+
+fn add_slices<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8]) -> Polynom<F> {
+    let mut poly = Polynom::with_length(max(lhs.len(), rhs.len()));
+
+    for (i, x) in lhs.iter().enumerate() {
+        let index = i + poly.len() - lhs.len();
+        uncheck_mut!(poly[index]) = *x;
+    }
+
+    for (i, x) in rhs.iter().enumerate() {
+        let index = i + poly.len() - rhs.len();
+        uncheck_mut!(poly[index]) ^= *x;
+    }
+
+    poly
+}
+
+fn derivative_slice<F: GaloisField<Elem = u8>>(poly: &[u8]) -> Polynom<F> {
+    let mut derivative = Polynom::with_length(poly.len().saturating_sub(1));
+    let degree = derivative.len();
+    for (i, x) in derivative.iter_mut().enumerate() {
+        if (degree - i) % 2 == 1 {
+            *x = poly[i];
+        }
+    }
+    derivative
+}
+
+#[inline]
+fn mul_slices<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8]) -> Polynom<F> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Polynom::new();
+    }
+    let mut poly = Polynom::with_length(lhs.len() + rhs.len() - 1);
+    if lhs.len().min(rhs.len()) < KARATSUBA_THRESHOLD {
+        mul_add_schoolbook::<F>(lhs, rhs, &mut poly);
+    } else {
+        mul_add_karatsuba::<F>(lhs, rhs, &mut poly);
+    }
+    poly
+}
+
+/// Factor length below which Karatsuba costs more than it saves
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Scratch space of a whole Karatsuba recursion, see `mul_add_into`. Factors of a product that
+/// fits a polynomial are at most 128 coefficients long when balanced, which needs
+/// 255 + 127 + 63 bytes.
+const KARATSUBA_SCRATCH_LEN: usize = 7 * crate::POLYNOMIAL_MAX_LENGTH / 4;
+
+/// Kept out of line so that the scratch space is only on the stack for long factors
+#[inline(never)]
+fn mul_add_karatsuba<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8], out: &mut [u8]) {
+    let mut scratch = [0; KARATSUBA_SCRATCH_LEN];
+    mul_add_into::<F>(lhs, rhs, out, &mut scratch);
+}
+
+/// Adds the product of `lhs` and `rhs` into `out`, which must hold at least
+/// `lhs.len() + rhs.len() - 1` coefficients.
+///
+/// Coefficient `i + j` of the product only depends on the positions of the factors,
+/// so the same splitting works with the highest power first.
+///
+/// Each level takes `4 * ceil(n / 2) - 1` bytes of `scratch` for balanced factors of `n`
+/// coefficients and passes the rest down, so the recursion shares a single buffer.
+fn mul_add_into<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8], out: &mut [u8], scratch: &mut [u8]) {
+    let (long, short) = if lhs.len() >= rhs.len() { (lhs, rhs) } else { (rhs, lhs) };
+    if short.len() < KARATSUBA_THRESHOLD {
+        mul_add_schoolbook::<F>(lhs, rhs, out);
+        return;
+    }
+    if long.len() > short.len() {
+        for (k, chunk) in long.chunks(short.len()).enumerate() {
+            mul_add_into::<F>(chunk, short, &mut out[k * short.len()..], scratch);
+        }
+        return;
+    }
+
+    // a = a0 + x^m a1, b = b0 + x^m b1 in index order:
+    // ab = a0 b0 + x^m ((a0 + a1)(b0 + b1) + a0 b0 + a1 b1) + x^2m a1 b1
+    let n = long.len();
+    let m = n / 2;
+    let (a0, a1) = long.split_at(m);
+    let (b0, b1) = short.split_at(m);
+
+    let (product, scratch) = scratch.split_at_mut(2 * (n - m) - 1);
+    let z0 = &mut product[..2 * m - 1];
+    z0.fill(0);
+    mul_add_into::<F>(a0, b0, z0, scratch);
+    xor_into(&mut out[..2 * m - 1], z0);
+    xor_into(&mut out[m..3 * m - 1], z0);
+
+    let z2 = &mut product[..];
+    z2.fill(0);
+    mul_add_into::<F>(a1, b1, z2, scratch);
+    xor_into(&mut out[2 * m..], z2);
+    xor_into(&mut out[m..], z2);
+
+    let (a_sum, scratch) = scratch.split_at_mut(n - m);
+    let (b_sum, scratch) = scratch.split_at_mut(n - m);
+    a_sum.copy_from_slice(a1);
+    b_sum.copy_from_slice(b1);
+    xor_into(a_sum, a0);
+    xor_into(b_sum, b0);
+    mul_add_into::<F>(a_sum, b_sum, &mut out[m..], scratch);
+}
+
+fn mul_add_schoolbook<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8], out: &mut [u8]) {
+    for (j, rhs_x) in rhs.iter().enumerate() {
+        for (i, self_x) in lhs.iter().enumerate() {
+            uncheck_mut!(out[i + j]) ^= F::mul(*self_x, *rhs_x);
         }
+    }
+}
+
+/// Adds `rhs` into the start of `out`
+#[inline]
+fn xor_into(out: &mut [u8], rhs: &[u8]) {
+    for (x, rhs_x) in out.iter_mut().zip(rhs.iter()) {
+        *x ^= *rhs_x;
+    }
+}
+
+fn div_slices<F: GaloisField<Elem = u8>>(lhs: &[u8], rhs: &[u8]) -> (Polynom<F>, Polynom<F>) {
+    let mut poly = Polynom::from(lhs);
 
-        for i in 0..(self.len() - divisor_degree) {
-            let coef = uncheck!(poly[i]);
-            if coef != 0 {
-                for j in 1..rhs.len() {
-                    if rhs[j] != 0 {
-                        uncheck_mut!(poly[i + j]) ^= gf::mul(rhs[j], coef);
-                    }
+    // If divisor's degree (len-1) is bigger, all dividend is a remainder
+    let divisor_degree = rhs.len() - 1;
+    if lhs.len() < divisor_degree {
+        return (Polynom::new(), poly);
+    }
+
+    for i in 0..(lhs.len() - divisor_degree) {
+        let coef = uncheck!(poly[i]);
+        if coef != 0 {
+            for j in 1..rhs.len() {
+                if rhs[j] != 0 {
+                    uncheck_mut!(poly[i + j]) ^= F::mul(rhs[j], coef);
                 }
             }
         }
+    }
+
+    let separator = lhs.len() - (rhs.len() - 1);
+
+    // Quotient is after separator
+    let remainder = Polynom::from(&poly[separator..]);
+
+    // And reminder is before separator, so just shrink to it
+    poly.set_length(separator);
+
+    (poly, remainder)
+}
+
+/// Evaluates polynomial `poly` (highest power first) at `x` in the field `F` with Horner's rule,
+/// the empty polynomial evaluates to zero.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::eval;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// // x^2 + 3 at x = 2
+/// assert_eq!(7, eval::<Gf2p8>(&[1, 0, 3], 2));
+/// assert_eq!(0, eval::<Gf2p8>(&[], 2));
+/// ```
+#[inline]
+pub fn eval<F: GaloisField<Elem = u8>>(poly: &[u8], x: u8) -> u8 {
+    poly.iter().fold(0, |y, px| F::mul(y, x) ^ px)
+}
+
+/// Computes the syndrome polynomial of `codeword` for a code with `ecc_len` ecc bytes and
+/// generator roots `2^(fcr + i)`: `synd[i]` is the codeword evaluated at the `i`-th root.
+/// All coefficients are zero if the codeword is not damaged.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::syndrome_poly;
+/// use reed_solomon::gf::Gf2p8;
+/// use reed_solomon::Encoder;
+///
+/// let mut codeword = b"Hello".to_vec();
+/// codeword.extend_from_slice(&Encoder::<4>::new().encode(b"Hello").unwrap());
+/// assert!(syndrome_poly::<Gf2p8>(&codeword, 4, 0).is_zero());
+///
+/// codeword[1] = 0;
+/// assert!(!syndrome_poly::<Gf2p8>(&codeword, 4, 0).is_zero());
+/// ```
+pub fn syndrome_poly<F: GaloisField<Elem = u8>>(codeword: &[u8], ecc_len: usize, fcr: u8) -> Polynom<F> {
+    split_syndrome_poly(codeword, &[], ecc_len, fcr, 1)
+}
+
+/// Same as [`syndrome_poly`] for a codeword split into `data` and `ecc` parts and roots
+/// `2^(prim * (fcr + i))`
+pub(crate) fn split_syndrome_poly<F: GaloisField<Elem = u8>>(data: &[u8], ecc: &[u8], ecc_len: usize, fcr: u8, prim: u8) -> Polynom<F> {
+    let mut synd = Polynom::with_length(ecc_len);
+    for (i, s) in synd.iter_mut().enumerate() {
+        *s = split_syndrome::<F>(data, ecc, root_power::<F>(fcr, prim, i));
+    }
+    synd
+}
+
+/// Single syndrome: the codeword split into `data` and `ecc` parts evaluated at `2^power`
+#[inline]
+pub(crate) fn split_syndrome<F: GaloisField<Elem = u8>>(data: &[u8], ecc: &[u8], power: usize) -> u8 {
+    let x = F::pow(2, power as i32);
+    // Horner's rule continued from data into ecc
+    ecc.iter().fold(eval::<F>(data, x), |y, px| F::mul(y, x) ^ px)
+}
+
+/// Power of 2 of the `i`-th root of a generator polynomial
+#[inline]
+pub(crate) fn root_power<F: GaloisField<Elem = u8>>(fcr: u8, prim: u8, i: usize) -> usize {
+    prim as usize * (fcr as usize + i) % F::ORDER
+}
+
+/// Computes errata locator polynomial from coefficient degrees of errata positions
+/// (`msg_len - 1 - pos` for a position `pos` in the message).
+///
+/// The result can be passed to [`berlekamp_massey`] as the erasure locator.
+pub fn errata_locator<F: GaloisField<Elem = u8>>(coef_pos: &[u8]) -> Polynom<F> {
+    let mut e_loc = polynom![1];
+
+    let add_lhs = [1];
+    let mut add_rhs = [0, 0];
+    for i in coef_pos.iter() {
+        add_rhs[0] = F::pow(2, *i as i32);
+        e_loc.mul_assign(&add_lhs.add(&add_rhs));
+    }
+
+    e_loc
+}
+
+/// Berlekamp-Massey algorithm, computes error locator polynomial from `nsym` syndromes.
+///
+/// `erase_loc` is an optional erasure locator (see [`errata_locator`]) to start from, and
+/// `erase_count` is the number of erasures already accounted for, either by `erase_loc`
+/// or by Forney syndromes.
+///
+/// Returns `None` if there are more errors than `nsym` syndromes can locate, or if the inputs
+/// are inconsistent: fewer than `nsym` syndromes, more than `nsym` erasures, or an erasure
+/// locator of more than `erase_count + 1` coefficients.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::berlekamp_massey;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// let synd = [79, 25, 0, 160, 198, 122, 192, 169, 232];
+/// let err_loc = berlekamp_massey::<Gf2p8>(&synd, 9, None, 3).unwrap();
+///
+/// assert_eq!([193, 144, 121, 1], *err_loc);
+/// ```
+#[allow(non_snake_case)]
+pub fn berlekamp_massey<F: GaloisField<Elem = u8>>(synd: &[u8],
+                        nsym: usize,
+                        erase_loc: Option<&[u8]>,
+                        erase_count: usize)
+                        -> Option<Polynom<F>> {
+    // Indexing below relies on these, `synd` is caller-supplied
+    if synd.len() < nsym || erase_count > nsym || erase_loc.is_some_and(|loc| loc.len() > erase_count + 1) {
+        return None;
+    }
+
+    let (mut err_loc, mut old_loc): (Polynom<F>, Polynom<F>) = if let Some(erase_loc) = erase_loc {
+        (Polynom::<F>::from(erase_loc), Polynom::<F>::from(erase_loc))
+    } else {
+        (polynom![1], polynom![1])
+    };
+
+    let synd_shift = if synd.len() > nsym {
+        synd.len() - nsym
+    } else {
+        0
+    };
+
+    for i in 0..(nsym - erase_count) {
+        let K = if erase_loc.is_some() {
+            erase_count + i + synd_shift
+        } else {
+            i + synd_shift
+        };
+
+        let mut delta = uncheck!(synd[K]);
+        for j in 1..err_loc.len() {
+            let d_index = err_loc.len() - j - 1;
+            delta ^= F::mul(err_loc[d_index], uncheck!(synd[K - j]));
+        }
+
+        old_loc.shift_left_assign(1);
+
+        if delta != 0 {
+            if old_loc.len() > err_loc.len() {
+                let new_loc = old_loc.scale(delta);
+                old_loc = err_loc.scale(F::inverse(delta));
+                err_loc = new_loc;
+            }
+
+            err_loc += old_loc * delta;
+        }
+    }
+
+    let shift = err_loc.iter().take_while(|&&v| v == 0).count();
+    let err_loc = Polynom::<F>::from(&err_loc[shift..]);
+
+    let errs = err_loc.len() - 1;
+    let errs = if erase_count > errs {
+        erase_count
+    } else {
+        (errs - erase_count) * 2 + erase_count
+    };
 
-        let separator = self.len() - (rhs.len() - 1);
+    if errs > nsym {
+        None
+    } else {
+        Some(err_loc)
+    }
+}
+
+/// Sugiyama's extended Euclidean algorithm, an alternative to [`berlekamp_massey`] with the same
+/// inputs and output, except that the erasure locator can't be given and erasures have to be
+/// accounted for by Forney syndromes.
+///
+/// Returns `None` if there are more errors than `nsym` syndromes can locate, or if there are
+/// fewer than `nsym - erase_count` syndromes or more than `nsym` erasures.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::euclidean;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// let synd = [79, 25, 0, 160, 198, 122, 192, 169, 232];
+/// let err_loc = euclidean::<Gf2p8>(&synd, 9, 3).unwrap();
+///
+/// assert_eq!([193, 144, 121, 1], *err_loc);
+/// ```
+pub fn euclidean<F: GaloisField<Elem = u8>>(synd: &[u8], nsym: usize, erase_count: usize) -> Option<Polynom<F>> {
+    let m = nsym.checked_sub(erase_count)?;
+    if synd.len() < m {
+        return None;
+    }
+
+    // r(x) = x^m and S(x), t(x) = 0 and 1
+    let mut r_prev: Polynom<F> = polynom![1].shift_left(m);
+    let mut r = trim::<F>(&Polynom::<F>::from(&synd[..m]).reverse());
+    let mut t_prev = polynom![0];
+    let mut t = polynom![1];
 
-        // Quotient is after separator
-        let remainder = Polynom::from(&poly[separator..]);
+    while r.len() > m / 2 {
+        let (q, rem) = div_rem::<F>(&r_prev, &r);
+        let t_next = t_prev + q * t;
 
-        // And reminder is before separator, so just shrink to it
-        poly.set_length(separator);
+        r_prev = r;
+        r = rem;
+        t_prev = t;
+        t = trim::<F>(&t_next);
+    }
+
+    let lowest = *t.last()?;
+    if lowest == 0 {
+        return None;
+    }
+    let err_loc = t.scale(F::inverse(lowest));
 
-        (poly, remainder)
+    let errs = err_loc.len() - 1;
+    if errs * 2 + erase_count > nsym {
+        None
+    } else {
+        Some(err_loc)
     }
 }
 
-impl Eval for [u8] {
-    #[inline]
-    fn eval(&self, x: u8) -> u8 {
-        let mut y = self[0];
-        for px in self.iter().skip(1) {
-            y = gf::mul(y, x) ^ px;
+/// Extended Euclidean algorithm, returns `(g, s, t)` such that `s * a + t * b = g`, where `g` is
+/// the monic greatest common divisor of `a` and `b` (empty if both are zero).
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::extended_euclid;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// // (x + 1)(x + 2) and (x + 2)(x + 3)
+/// let (g, s, t) = extended_euclid::<Gf2p8>(&[1, 3, 2], &[1, 1, 6]);
+/// assert_eq!([1, 2], *g);
+///
+/// // Same polynomial up to leading zeros
+/// let combination = s.mul(&[1, 3, 2]).add(&t.mul(&[1, 1, 6]));
+/// assert_eq!([0, 1, 2], *combination);
+/// ```
+pub fn extended_euclid<F: GaloisField<Elem = u8>>(a: &[u8], b: &[u8]) -> (Polynom<F>, Polynom<F>, Polynom<F>) {
+    let (mut r_prev, mut r) = (trim::<F>(a), trim::<F>(b));
+    let (mut s_prev, mut s): (Polynom<F>, Polynom<F>) = (polynom![1], Polynom::new());
+    let (mut t_prev, mut t): (Polynom<F>, Polynom<F>) = (Polynom::new(), polynom![1]);
+
+    while !r.is_empty() {
+        let (q, rem) = div_rem::<F>(&r_prev, &r);
+        let s_next = trim::<F>(&s_prev.add(&q.mul_nonempty(&s)));
+        let t_next = trim::<F>(&t_prev.add(&q.mul_nonempty(&t)));
+
+        r_prev = r;
+        r = rem;
+        s_prev = s;
+        s = s_next;
+        t_prev = t;
+        t = t_next;
+    }
+
+    match r_prev.first() {
+        Some(&lead) => {
+            let lead_inv = F::inverse(lead);
+            (r_prev.scale(lead_inv), s_prev.scale(lead_inv), t_prev.scale(lead_inv))
+        }
+        None => (r_prev, s_prev, t_prev),
+    }
+}
+
+/// Monic greatest common divisor of two polynomials, empty if both are zero.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::gcd;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// // (x + 1)(x + 2) and (x + 2)(x + 3)
+/// assert_eq!([1, 2], *gcd::<Gf2p8>(&[1, 3, 2], &[1, 1, 6]));
+/// ```
+pub fn gcd<F: GaloisField<Elem = u8>>(a: &[u8], b: &[u8]) -> Polynom<F> {
+    extended_euclid::<F>(a, b).0
+}
+
+impl<F: GaloisField<Elem = u8>> Polynom<F> {
+    /// Product which treats an empty factor as zero
+    fn mul_nonempty(&self, rhs: &[u8]) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            Polynom::new()
+        } else {
+            self.mul(rhs)
+        }
+    }
+}
+
+/// Strips leading zero coefficients
+fn trim<F: GaloisField<Elem = u8>>(p: &[u8]) -> Polynom<F> {
+    let mut poly = Polynom::<F>::from(p);
+    poly.trim();
+    poly
+}
+
+/// Division by a divisor with a non-zero leading coefficient, which doesn't have to be monic.
+/// Remainder is returned without leading zeros.
+fn div_rem<F: GaloisField<Elem = u8>>(dividend: &[u8], divisor: &[u8]) -> (Polynom<F>, Polynom<F>) {
+    if dividend.len() < divisor.len() {
+        return (polynom![0], trim(dividend));
+    }
+
+    let lead_inv = F::inverse(divisor[0]);
+    let mut rem = Polynom::<F>::from(dividend);
+    let mut quotient = Polynom::<F>::with_length(dividend.len() - divisor.len() + 1);
+
+    for i in 0..quotient.len() {
+        let coef = F::mul(rem[i], lead_inv);
+        quotient[i] = coef;
+        if coef != 0 {
+            for (j, d) in divisor.iter().enumerate() {
+                rem[i + j] ^= F::mul(*d, coef);
+            }
+        }
+    }
+
+    (quotient, trim::<F>(&rem[quotient.len()..]))
+}
+
+/// Computes error evaluator polynomial `S(x) * err_loc(x) mod x^nsym`, where `S(x)` is the
+/// syndrome polynomial with `synd[i]` (as returned by `Decoder::syndromes`) being its `x^i`
+/// coefficient.
+pub fn error_evaluator<F: GaloisField<Elem = u8>>(synd: &[u8], err_loc: &[u8], nsym: usize) -> Polynom<F> {
+    let synd = Polynom::<F>::from(synd).reverse();
+    let product = synd.mul(err_loc);
+
+    if product.len() > nsym {
+        Polynom::<F>::from(&product[product.len() - nsym..])
+    } else {
+        product
+    }
+}
+
+/// Forney algorithm, computes error magnitudes at errata with coefficient degrees `coef_pos`
+/// from error evaluator (see [`error_evaluator`]) and errata locator polynomials.
+///
+/// Returns `None` if locator has a repeated root or there are more than `N` errata.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::{errata_locator, error_evaluator, forney};
+/// use reed_solomon::gf::Gf2p8;
+///
+/// // Single error of magnitude 5 at coefficient degree 3
+/// let synd = [5, 40, 93, 210];
+/// let err_loc = errata_locator::<Gf2p8>(&[3]);
+/// let err_eval = error_evaluator::<Gf2p8>(&synd, &err_loc, err_loc.len() - 1);
+///
+/// assert_eq!([5], *forney::<Gf2p8, 4>(&err_eval, &err_loc, &[3]).unwrap());
+/// ```
+pub fn forney<F: GaloisField<Elem = u8>, const N: usize>(err_eval: &[u8], err_loc: &[u8], coef_pos: &[u8]) -> Option<Vec<u8, N>> {
+    let err_loc_prime = Polynom::<F>::from(err_loc).derivative();
+
+    let mut magnitudes = Vec::new();
+    for px in coef_pos.iter() {
+        let x = F::pow(2, *px as i32);
+        let x_inv = F::inverse(x);
+
+        let denominator = err_loc_prime.eval(x_inv);
+        if denominator == 0 {
+            return None;
         }
-        y
+
+        let y = F::mul(x, eval::<F>(err_eval, x_inv));
+        magnitudes.push(F::div(y, denominator)).ok()?;
     }
+
+    Some(magnitudes)
 }
 
+/// Chien search, finds roots of error locator polynomial `err_loc` (as returned by
+/// [`berlekamp_massey`]) and returns corresponding error positions in a message of `msg_len`
+/// bytes, from the last one to the first.
+///
+/// Returns `None` if number of roots doesn't match locator degree, i.e. errors can't be located,
+/// if there are more than `N` of them, if `err_loc` is empty or if `msg_len` exceeds 255 bytes,
+/// so that positions fit in `u8`.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf::poly_math::chien_search;
+/// use reed_solomon::gf::Gf2p8;
+///
+/// let err_pos = chien_search::<Gf2p8, 8>(&[193, 144, 121, 1], 20).unwrap();
+/// assert_eq!([5, 4, 3], *err_pos);
+/// ```
+pub fn chien_search<F: GaloisField<Elem = u8>, const N: usize>(err_loc: &[u8], msg_len: usize) -> Option<Vec<u8, N>> {
+    let errs = err_loc.len().checked_sub(1)?;
+    if msg_len > 255 {
+        return None;
+    }
+
+    let mut err_pos = Vec::new();
+
+    for i in 0..msg_len {
+        if eval::<F>(err_loc, F::pow(2, -(i as i32))) == 0 {
+            err_pos.push((msg_len - 1 - i) as u8).ok()?;
+        }
+    }
+
+    if err_pos.len() != errs {
+        None
+    } else {
+        Some(err_pos)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -131,6 +945,7 @@ mod tests {
 
     #[test]
     fn scale() {
+        crate::gf::init_test_tables();
         let poly = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
         let answer = [0, 3, 6, 5, 12, 15, 10, 9, 24, 27];
         assert_eq!(answer, *(poly.scale(3)));
@@ -138,6 +953,7 @@ mod tests {
 
     #[test]
     fn scale_assign() {
+        crate::gf::init_test_tables();
         let mut poly = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
         let answer = [0, 3, 6, 5, 12, 15, 10, 9, 24, 27];
         assert_eq!(answer,
@@ -162,6 +978,7 @@ mod tests {
 
     #[test]
     fn mul() {
+        crate::gf::init_test_tables();
         let px = [0, 5, 10, 15, 20];
         let py = [3, 9, 17, 24, 75];
         assert_eq!([0, 15, 51, 30, 153, 193, 53, 115, 245], *(px.mul(&py)));
@@ -173,8 +990,186 @@ mod tests {
         assert_eq!([0, 15, 51, 15, 210, 138, 244], *(py.mul(&px)));
     }
 
+    #[test]
+    fn operators() {
+        crate::gf::init_test_tables();
+        let px: Polynom = polynom![0, 5, 10, 15, 20];
+        let py: Polynom = polynom![3, 9, 17, 24, 75];
+        assert_eq!(*px.mul(&py), *(px * py));
+        assert_eq!(*px.add(&py), *(px + py));
+        assert_eq!(*(px + py), *(px - py));
+        assert_eq!(*px.scale(3), *(px * 3));
+
+        let short: Polynom = polynom![1, 2];
+        assert_eq!([0, 5, 10, 14, 22], *(px - short));
+
+        assert!((Polynom::<Gf2p8>::new() * Polynom::new()).is_empty());
+        assert!((px * Polynom::new()).is_empty());
+    }
+
+    #[test]
+    fn div_mod() {
+        crate::gf::init_test_tables();
+        let dividend: Polynom = polynom![7, 3, 0, 9, 200, 1];
+        for divisor in [&[5, 1, 2][..], &[0, 0, 3, 4], &[9], &[1, 2, 3, 4, 5, 6, 7]] {
+            let (quotient, remainder) = dividend.div_mod(divisor);
+            assert_eq!(*dividend, *trim::<Gf2p8>(&quotient.mul(divisor).add(&remainder)));
+            assert!(remainder.len() < trim::<Gf2p8>(divisor).len());
+        }
+
+        let monic = [1, 4, 2];
+        let (quotient, remainder) = dividend.div(&monic);
+        let (q, r) = dividend.div_mod(&monic);
+        assert_eq!(*quotient, *q);
+        assert_eq!(*trim::<Gf2p8>(&remainder), *r);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_mod_by_zero() {
+        let dividend: Polynom = polynom![7, 3];
+        dividend.div_mod(&[0, 0]);
+    }
+
+    #[test]
+    fn extended_euclid() {
+        crate::gf::init_test_tables();
+        // (x + 1)(x + 2)(x + 3) and 7(x + 2)(x + 3)(x + 5)
+        let common = [1u8, 1, 6];
+        let a = [1u8, 1].mul(&common);
+        let b = [7u8, 35].mul(&common);
+
+        let (g, s, t) = super::extended_euclid::<Gf2p8>(&a, &b);
+        assert_eq!(common, *g);
+        assert_eq!(*g, *trim::<Gf2p8>(&s.mul(&a).add(&t.mul(&b))));
+        assert_eq!(common, *super::gcd::<Gf2p8>(&b, &a));
+
+        assert_eq!([1], *super::gcd::<Gf2p8>(&[1, 1], &[1, 2]));
+        assert_eq!([1, 1], *super::gcd::<Gf2p8>(&[0, 3, 3], &[]));
+        assert!(super::gcd::<Gf2p8>(&[], &[0]).is_empty());
+
+        // Generator divides x^255 - 1
+        let generator = crate::generator_poly::<9>(8);
+        let mut x255 = [0; 256];
+        x255[0] = 1;
+        x255[255] = 1;
+        assert_eq!(generator, *super::gcd::<Gf2p8>(&x255, &generator));
+    }
+
+    #[test]
+    fn derivative() {
+        let poly: Polynom = polynom![3, 1, 5, 7, 9];
+        assert_eq!([0, 1, 0, 7], *poly.derivative());
+        let constant: Polynom = polynom![4];
+        assert!(constant.derivative().is_empty());
+        assert!(Polynom::<Gf2p8>::new().derivative().is_empty());
+    }
+
+    #[test]
+    fn karatsuba() {
+        crate::gf::init_test_tables();
+        for (lhs_len, rhs_len) in [(32, 32), (33, 65), (64, 64), (100, 37), (128, 128), (129, 127), (200, 40)] {
+            let lhs: Polynom = (0..lhs_len).map(|i| (i * 37 + 11) as u8).collect();
+            let rhs: Polynom = (0..rhs_len).map(|i| (i * 101 + 3) as u8).collect();
+
+            let mut expected = [0; 256];
+            super::mul_add_schoolbook::<Gf2p8>(&lhs, &rhs, &mut expected);
+            assert_eq!(expected[..lhs_len + rhs_len - 1], *lhs.mul(&rhs));
+        }
+    }
+
+    #[test]
+    fn poly_slice() {
+        crate::gf::init_test_tables();
+        let received = [0, 5, 10, 15, 20];
+        let view = PolySlice::<Gf2p8>::new(&received);
+        let poly: Polynom = polynom![0, 5, 10, 15, 20];
+
+        assert_eq!(Some(3), view.degree());
+        assert_eq!(poly.eval(7), view.eval(7));
+        assert_eq!(poly.add(&[3, 9]), view.add(&[3, 9]));
+        assert_eq!(poly.mul(&[3, 9]), view.mul(&[3, 9]));
+        assert_eq!(poly.div_mod(&[1, 9]), view.div_mod(&[1, 9]));
+        assert_eq!(poly.derivative(), view.derivative());
+        assert_eq!(Ok(poly), view.to_polynom());
+        assert_eq!(&received[..], poly.as_poly_slice().as_slice());
+
+        let long = [1; 300];
+        let view = PolySlice::<Gf2p8>::new(&long);
+        assert_eq!(Some(299), view.degree());
+        assert_eq!(0, view.eval(1));
+        assert_eq!(Err(crate::gf::poly::CapacityError), view.to_polynom());
+    }
+
+    #[test]
+    fn shift_left() {
+        crate::gf::init_test_tables();
+        let poly: Polynom = polynom![5, 0, 7];
+        assert_eq!(poly.mul(&[1, 0, 0]), poly.shift_left(2));
+        assert_eq!(poly, poly.shift_left(0));
+
+        let mut dirty = poly;
+        dirty.set_length(1);
+        dirty.shift_left_assign(2);
+        assert_eq!([5, 0, 0], *dirty);
+        assert_eq!(256, poly.shift_left(253).len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn shift_left_overflow() {
+        let poly: Polynom = polynom![5, 0, 7];
+        poly.shift_left(254);
+    }
+
+    #[test]
+    fn syndrome_poly() {
+        crate::gf::init_test_tables();
+        let data = [7, 20, 30, 40, 50];
+        let mut codeword = data.to_vec();
+        codeword.extend_from_slice(&crate::Encoder::<6>::with_roots(3, 1).encode(&data).unwrap());
+        assert_eq!([0; 6], *super::syndrome_poly::<Gf2p8>(&codeword, 6, 3));
+
+        codeword[2] ^= 0x40;
+        let synd = super::syndrome_poly::<Gf2p8>(&codeword, 6, 3);
+        for (i, s) in synd.iter().enumerate() {
+            assert_eq!(super::eval::<Gf2p8>(&codeword, <Gf2p8>::pow(2, 3 + i as i32)), *s);
+        }
+        assert_eq!(synd, split_syndrome_poly(&codeword[..5], &codeword[5..], 6, 3, 1));
+    }
+
+    #[test]
+    fn assign() {
+        crate::gf::init_test_tables();
+        let px: Polynom = polynom![0, 5, 10, 15, 20];
+        let py: Polynom = polynom![3, 9, 17];
+
+        let mut product = px;
+        product.mul_assign(&py);
+        assert_eq!(px.mul(&py), product);
+        let mut product = py;
+        product *= px;
+        assert_eq!(py.mul(&px), product);
+
+        let mut sum = py;
+        sum.add_assign(&px);
+        assert_eq!(px.add(&py), sum);
+        let mut sum = px;
+        sum -= py;
+        assert_eq!(px.add(&py), sum);
+
+        let mut scaled = px;
+        scaled *= 3;
+        assert_eq!(px.scale(3), scaled);
+
+        let mut empty = px;
+        empty.mul_assign(&[]);
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn div() {
+        crate::gf::init_test_tables();
         let px = [0, 5, 10, 15, 20];
         let py = [3, 9, 17, 24, 75];
 
@@ -202,12 +1197,96 @@ mod tests {
 
     #[test]
     fn eval() {
+        crate::gf::init_test_tables();
         let p = [0, 5, 10, 15, 20];
         let tests = [4, 7, 21, 87, 35, 255];
         let answers = [213, 97, 132, 183, 244, 92];
 
         for i in 0..tests.len() {
             assert_eq!(answers[i], p.eval(tests[i]));
+            assert_eq!(answers[i], Polynom::<Gf2p8>::from(&p[..]).eval(tests[i]));
         }
+        assert_eq!(0, Polynom::<Gf2p8>::new().eval(3));
+
+        let ccsds = [1, 0, 1];
+        assert_eq!(crate::gf::Gf2p8::<0x187>::mul(0x80, 0x80) ^ 1, super::eval::<crate::gf::Gf2p8<0x187>>(&ccsds, 0x80));
+    }
+
+    #[test]
+    fn errata_locator() {
+        crate::gf::init_test_tables();
+        let e_pos = [19, 18, 17, 14, 15, 16];
+        assert_eq!([134, 207, 111, 227, 24, 150, 1], *super::errata_locator::<Gf2p8>(&e_pos));
+    }
+
+    #[test]
+    fn berlekamp_massey() {
+        crate::gf::init_test_tables();
+        let synd = [79, 25, 0, 160, 198, 122, 192, 169, 232];
+        assert_eq!([193, 144, 121, 1], *super::berlekamp_massey::<Gf2p8>(&synd, 9, None, 3).unwrap());
+
+        // Single error with coefficient degree 11, locator is 1 + 2^11 x
+        let synd = [7, 162, 172, 245, 176, 71, 58, 180];
+        assert_eq!([232, 1], *super::berlekamp_massey::<Gf2p8>(&synd, 8, None, 0).unwrap());
+
+        let synd = [0, 0, 1];
+        assert!(super::berlekamp_massey::<Gf2p8>(&synd, 3, None, 0).is_none());
+
+        // Inconsistent inputs
+        assert!(super::berlekamp_massey::<Gf2p8>(&[1, 2], 8, None, 0).is_none());
+        assert!(super::berlekamp_massey::<Gf2p8>(&synd, 3, None, 4).is_none());
+        assert!(super::berlekamp_massey::<Gf2p8>(&synd, 3, Some(&[1, 2, 3]), 1).is_none());
+    }
+
+    #[test]
+    fn chien_search() {
+        crate::gf::init_test_tables();
+        assert_eq!([5, 4, 3], *super::chien_search::<Gf2p8, 3>(&[193, 144, 121, 1], 20).unwrap());
+        assert!(super::chien_search::<Gf2p8, 2>(&[193, 144, 121, 1], 20).is_none());
+        assert!(super::chien_search::<Gf2p8, 8>(&[181, 134, 1], 12).is_none());
+        assert!(super::chien_search::<Gf2p8, 8>(&[], 12).is_none());
+        assert!(super::chien_search::<Gf2p8, 8>(&[193, 144, 121, 1], 256).is_none());
+    }
+
+    #[test]
+    fn error_evaluator() {
+        crate::gf::init_test_tables();
+        let synd = [64, 42, 242, 59, 109, 56, 78, 103, 232];
+        let err_loc = [134, 207, 111, 227, 24, 150, 1];
+
+        assert_eq!([148, 151, 175, 126, 68, 64], *super::error_evaluator::<Gf2p8>(&synd, &err_loc, 6));
+    }
+
+    #[test]
+    fn forney() {
+        crate::gf::init_test_tables();
+        let synd = [64, 42, 242, 59, 109, 56, 78, 103, 232];
+        let coef_pos = [19, 18, 17, 14, 15, 16];
+        let err_loc = super::errata_locator::<Gf2p8>(&coef_pos);
+        let err_eval = super::error_evaluator::<Gf2p8>(&synd, &err_loc, err_loc.len() - 1);
+
+        assert_eq!([104, 101, 108, 34, 109, 110],
+                   *super::forney::<Gf2p8, 6>(&err_eval, &err_loc, &coef_pos).unwrap());
+        assert!(super::forney::<Gf2p8, 5>(&err_eval, &err_loc, &coef_pos).is_none());
+    }
+
+    #[test]
+    fn euclidean() {
+        crate::gf::init_test_tables();
+        let synd = [79, 25, 0, 160, 198, 122, 192, 169, 232];
+        assert_eq!([193, 144, 121, 1], *super::euclidean::<Gf2p8>(&synd, 9, 3).unwrap());
+
+        let synd = [7, 162, 172, 245, 176, 71, 58, 180];
+        assert_eq!([232, 1], *super::euclidean::<Gf2p8>(&synd, 8, 0).unwrap());
+
+        let synd = [0, 0, 0, 0];
+        assert_eq!([1], *super::euclidean::<Gf2p8>(&synd, 4, 0).unwrap());
+
+        let synd = [0, 0, 1];
+        assert!(super::euclidean::<Gf2p8>(&synd, 3, 0).is_none());
+
+        // Inconsistent inputs
+        assert!(super::euclidean::<Gf2p8>(&[1, 2], 8, 0).is_none());
+        assert!(super::euclidean::<Gf2p8>(&synd, 3, 4).is_none());
     }
 }
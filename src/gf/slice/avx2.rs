@@ -0,0 +1,90 @@
+//! AVX2 backend, 32 bytes per iteration.
+//!
+//! Products of the constant and all 16 values of a nibble fit a 128-bit lane, so every byte is
+//! multiplied with two `pshufb` lookups, one per nibble.
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use super::{nibble_tables, scalar};
+
+/// Loads nibble tables of `c` into both 128-bit lanes
+#[inline]
+#[target_feature(enable = "avx2")]
+fn tables(c: u8) -> (__m256i, __m256i) {
+    let [lo, hi] = nibble_tables(c);
+    // SAFETY: the tables are 16 bytes long, the loads are unaligned
+    unsafe {
+        let lo = _mm_loadu_si128(lo.as_ptr() as *const __m128i);
+        let hi = _mm_loadu_si128(hi.as_ptr() as *const __m128i);
+        (_mm256_broadcastsi128_si256(lo), _mm256_broadcastsi128_si256(hi))
+    }
+}
+
+/// Multiplies 32 bytes by the constant whose nibble tables are `lo` and `hi`
+#[inline]
+#[target_feature(enable = "avx2")]
+fn mul32(x: __m256i, lo: __m256i, hi: __m256i) -> __m256i {
+    let mask = _mm256_set1_epi8(0x0f);
+    let x_lo = _mm256_and_si256(x, mask);
+    let x_hi = _mm256_and_si256(_mm256_srli_epi16(x, 4), mask);
+    _mm256_xor_si256(_mm256_shuffle_epi8(lo, x_lo), _mm256_shuffle_epi8(hi, x_hi))
+}
+
+/// Same as [`scalar::mul_slice`], the CPU must support AVX2
+#[target_feature(enable = "avx2")]
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let (lo, hi) = tables(c);
+    let mut src_chunks = src.chunks_exact(32);
+    let mut dst_chunks = dst.chunks_exact_mut(32);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 32 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm256_loadu_si256(s.as_ptr() as *const __m256i);
+            _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, mul32(x, lo, hi));
+        }
+    }
+    scalar::mul_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+/// Same as [`scalar::mul_add_slice`], the CPU must support AVX2
+#[target_feature(enable = "avx2")]
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let (lo, hi) = tables(c);
+    let mut src_chunks = src.chunks_exact(32);
+    let mut dst_chunks = dst.chunks_exact_mut(32);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 32 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm256_loadu_si256(s.as_ptr() as *const __m256i);
+            let y = _mm256_loadu_si256(d.as_ptr() as *const __m256i);
+            _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, _mm256_xor_si256(y, mul32(x, lo, hi)));
+        }
+    }
+    scalar::mul_add_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{cpu, scalar};
+
+    #[test]
+    fn matches_scalar() {
+        crate::gf::init_test_tables();
+        if !cpu::has(cpu::AVX2) {
+            return;
+        }
+
+        let src: [u8; 259] = core::array::from_fn(|i| (i * 31) as u8);
+        for c in 0..=255 {
+            let (mut expected, mut actual) = ([7; 259], [7; 259]);
+            scalar::mul_add_slice(c, &src, &mut expected);
+            unsafe { super::mul_add_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+
+            scalar::mul_slice(c, &src, &mut expected);
+            unsafe { super::mul_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+        }
+    }
+}
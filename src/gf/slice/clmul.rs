@@ -0,0 +1,97 @@
+//! Table-free backend built on `pclmulqdq` carry-less multiplication.
+//!
+//! Eight bytes are spread over 16-bit lanes, multiplied by the constant without carries and
+//! reduced modulo 0x11d by folding the high bytes back twice with `x^8 = 0x1d`.
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use super::scalar;
+
+/// `x^8` modulo the primitive polynomial of the default field
+const X8: i32 = 0x1d;
+
+/// Carry-less multiplication of every 16-bit lane of `x` by the low qword of `c`.
+///
+/// Lanes must hold at most 8 significant bits and `c` at most 8, so that products stay in
+/// their lanes.
+#[inline]
+#[target_feature(enable = "pclmulqdq,sse2")]
+fn clmul16(x: __m128i, c: __m128i) -> __m128i {
+    let lo = _mm_clmulepi64_si128(x, c, 0x00);
+    let hi = _mm_clmulepi64_si128(x, c, 0x01);
+    _mm_unpacklo_epi64(lo, hi)
+}
+
+/// Multiplies 8 bytes in the low qword of `x` by `c`, products are returned in the low qword
+#[inline]
+#[target_feature(enable = "pclmulqdq,sse2")]
+fn mul8(x: __m128i, c: __m128i) -> __m128i {
+    let zero = _mm_setzero_si128();
+    let x8 = _mm_cvtsi32_si128(X8);
+
+    let p = clmul16(_mm_unpacklo_epi8(x, zero), c);
+    let q = clmul16(_mm_srli_epi16(p, 8), x8);
+    let r = clmul16(_mm_srli_epi16(q, 8), x8);
+
+    let product = _mm_and_si128(_mm_xor_si128(_mm_xor_si128(p, q), r), _mm_set1_epi16(0xff));
+    _mm_packus_epi16(product, zero)
+}
+
+/// Same as [`scalar::mul_slice`], the CPU must support `pclmulqdq`
+#[target_feature(enable = "pclmulqdq,sse2")]
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let c_reg = _mm_cvtsi32_si128(c as i32);
+    let mut src_chunks = src.chunks_exact(8);
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 8 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm_loadl_epi64(s.as_ptr() as *const __m128i);
+            _mm_storel_epi64(d.as_mut_ptr() as *mut __m128i, mul8(x, c_reg));
+        }
+    }
+    scalar::mul_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+/// Same as [`scalar::mul_add_slice`], the CPU must support `pclmulqdq`
+#[target_feature(enable = "pclmulqdq,sse2")]
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let c_reg = _mm_cvtsi32_si128(c as i32);
+    let mut src_chunks = src.chunks_exact(8);
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 8 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm_loadl_epi64(s.as_ptr() as *const __m128i);
+            let y = _mm_loadl_epi64(d.as_ptr() as *const __m128i);
+            _mm_storel_epi64(d.as_mut_ptr() as *mut __m128i, _mm_xor_si128(y, mul8(x, c_reg)));
+        }
+    }
+    scalar::mul_add_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{cpu, scalar};
+
+    #[test]
+    fn matches_scalar() {
+        crate::gf::init_test_tables();
+        if !cpu::has(cpu::PCLMULQDQ) {
+            return;
+        }
+
+        let src: [u8; 259] = core::array::from_fn(|i| (i * 31) as u8);
+        for c in 0..=255 {
+            let (mut expected, mut actual) = ([7; 259], [7; 259]);
+            scalar::mul_add_slice(c, &src, &mut expected);
+            unsafe { super::mul_add_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+
+            scalar::mul_slice(c, &src, &mut expected);
+            unsafe { super::mul_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+        }
+    }
+}
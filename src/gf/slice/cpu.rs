@@ -0,0 +1,71 @@
+//! x86 CPU feature detection with `cpuid`, which unlike `is_x86_feature_detected!` doesn't
+//! need `std`. The result is cached after the first query.
+#[cfg(target_arch = "x86")]
+use core::arch::x86 as arch;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64 as arch;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// `pclmulqdq` carry-less multiplication
+pub(crate) const PCLMULQDQ: u32 = 1 << 0;
+/// 256-bit AVX registers, including OS support for saving them
+pub(crate) const AVX: u32 = 1 << 1;
+/// Galois field new instructions
+pub(crate) const GFNI: u32 = 1 << 2;
+/// 256-bit integer operations, including OS support for AVX registers
+pub(crate) const AVX2: u32 = 1 << 3;
+/// 128-bit byte shuffles
+pub(crate) const SSSE3: u32 = 1 << 4;
+
+/// Set once the features were detected
+const DETECTED: u32 = 1 << 31;
+
+static FEATURES: AtomicU32 = AtomicU32::new(0);
+
+/// Checks that all `features` are supported by the CPU
+#[inline]
+pub(crate) fn has(features: u32) -> bool {
+    let mut detected = FEATURES.load(Ordering::Relaxed);
+    if detected & DETECTED == 0 {
+        detected = detect() | DETECTED;
+        FEATURES.store(detected, Ordering::Relaxed);
+    }
+    detected & features == features
+}
+
+fn detect() -> u32 {
+    let leaf1 = arch::__cpuid(1);
+
+    let mut features = 0;
+    // SSE2 is needed to move data in and out of xmm registers
+    let sse2 = leaf1.edx & (1 << 26) != 0;
+    if sse2 && leaf1.ecx & (1 << 1) != 0 {
+        features |= PCLMULQDQ;
+    }
+    if sse2 && leaf1.ecx & (1 << 9) != 0 {
+        features |= SSSE3;
+    }
+
+    // The OS must save ymm registers on context switches: XCR0 has both SSE and AVX state
+    let osxsave = leaf1.ecx & (1 << 27) != 0;
+    // SAFETY: `xgetbv` is enabled by the OS if OSXSAVE is set
+    if osxsave && leaf1.ecx & (1 << 28) != 0 && unsafe { xcr0() } & 0b110 == 0b110 {
+        features |= AVX;
+    }
+
+    if arch::__cpuid(0).eax >= 7 {
+        let leaf7 = arch::__cpuid_count(7, 0);
+        if sse2 && leaf7.ecx & (1 << 8) != 0 {
+            features |= GFNI;
+        }
+        if features & AVX != 0 && leaf7.ebx & (1 << 5) != 0 {
+            features |= AVX2;
+        }
+    }
+    features
+}
+
+#[target_feature(enable = "xsave")]
+unsafe fn xcr0() -> u64 {
+    arch::_xgetbv(0)
+}
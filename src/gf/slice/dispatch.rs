@@ -0,0 +1,147 @@
+//! Backend selection.
+//!
+//! The fastest backend supported by the CPU is detected on first use and remembered, so that
+//! a single binary runs well on every machine it's distributed to. `set_backend` overrides the
+//! choice, e.g. to benchmark backends against each other.
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+use super::cpu;
+
+/// Implementation of the slice operations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backend {
+    /// Portable table based loop, always supported
+    Scalar,
+    /// x86 `pclmulqdq` carry-less multiplication
+    Clmul,
+    /// x86 SSSE3 `pshufb` nibble lookups
+    Ssse3,
+    /// x86 AVX2 `vpshufb` nibble lookups
+    Avx2,
+    /// x86 GFNI affine transformations
+    Gfni,
+    /// ARM NEON `tbl` nibble lookups, available if NEON is enabled at compile time
+    Neon,
+    /// `core::simd` nibble lookups, available with `portable_simd` feature
+    PortableSimd,
+}
+
+/// Backends from the fastest to the slowest
+const PREFERENCE: [Backend; 7] = [
+    Backend::Gfni,
+    Backend::Avx2,
+    Backend::Neon,
+    Backend::Ssse3,
+    Backend::PortableSimd,
+    Backend::Clmul,
+    Backend::Scalar,
+];
+
+/// Selected backend, `NONE` until the first use
+static SELECTED: AtomicU8 = AtomicU8::new(NONE);
+const NONE: u8 = u8::MAX;
+
+impl Backend {
+    /// Checks whether the backend can run on this CPU and build
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Clmul => cpu::has(cpu::PCLMULQDQ),
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Ssse3 => cpu::has(cpu::SSSE3),
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Avx2 => cpu::has(cpu::AVX2),
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Gfni => cpu::has(cpu::GFNI | cpu::AVX),
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))]
+            Backend::Neon => true,
+            #[cfg(all(feature = "portable_simd", not(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))))]
+            Backend::PortableSimd => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// The fastest backend supported by this CPU and build
+    pub fn detect() -> Backend {
+        PREFERENCE.into_iter()
+            .find(|backend| backend.is_supported())
+            .unwrap_or(Backend::Scalar)
+    }
+
+    fn from_u8(x: u8) -> Backend {
+        PREFERENCE.into_iter()
+            .find(|backend| *backend as u8 == x)
+            .unwrap_or(Backend::Scalar)
+    }
+}
+
+/// Backend used by [`mul_slice`](super::mul_slice) and [`mul_add_slice`](super::mul_add_slice),
+/// detected on first use unless set with [`set_backend`].
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::gf;
+///
+/// assert!(gf::backend().is_supported());
+/// ```
+pub fn backend() -> Backend {
+    let selected = SELECTED.load(Ordering::Relaxed);
+    if selected != NONE {
+        return Backend::from_u8(selected);
+    }
+
+    let detected = Backend::detect();
+    SELECTED.store(detected as u8, Ordering::Relaxed);
+    detected
+}
+
+/// Overrides the backend for the whole program, errors if it's not supported.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::gf::{self, Backend};
+///
+/// gf::set_backend(Backend::Scalar).unwrap();
+/// assert_eq!(Backend::Scalar, gf::backend());
+///
+/// // Back to the fastest one
+/// gf::set_backend(Backend::detect()).unwrap();
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn set_backend(backend: Backend) -> Result<(), ()> {
+    if !backend.is_supported() {
+        return Err(());
+    }
+
+    SELECTED.store(backend as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf;
+
+    #[test]
+    fn every_backend() {
+        crate::gf::init_test_tables();
+        let src: [u8; 100] = core::array::from_fn(|i| (i * 31) as u8);
+        for backend in PREFERENCE {
+            if set_backend(backend).is_err() {
+                assert!(!backend.is_supported());
+                continue;
+            }
+
+            assert_eq!(backend, super::backend());
+            let mut dst = [0; 100];
+            gf::mul_slice(0x53, &src, &mut dst);
+            for (d, s) in dst.iter().zip(src.iter()) {
+                assert_eq!(gf::mul(0x53, *s), *d);
+            }
+        }
+
+        set_backend(Backend::detect()).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+//! GFNI backend, 32 bytes per instruction.
+//!
+//! `gf2p8mulb` is fixed to the AES polynomial 0x11b, so it can't be used for 0x11d. Instead
+//! multiplication by a constant, which is linear over GF(2), is expressed as an 8x8 bit matrix
+//! and applied with `gf2p8affineqb`.
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use super::scalar;
+
+/// Bit matrix of multiplication by `c` in `gf2p8affineqb` layout: byte `7 - i` holds the row
+/// of product bit `i`, its bit `j` is bit `i` of `c * 2^j`
+fn matrix(c: u8) -> i64 {
+    let mut m = 0u64;
+    for j in 0..8 {
+        let column = crate::gf::mul(c, 1 << j);
+        for i in 0..8 {
+            m |= (((column >> i) & 1) as u64) << ((7 - i) * 8 + j);
+        }
+    }
+    m as i64
+}
+
+/// Same as [`scalar::mul_slice`], the CPU must support GFNI and AVX
+#[target_feature(enable = "gfni,avx")]
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let m = _mm256_set1_epi64x(matrix(c));
+    let mut src_chunks = src.chunks_exact(32);
+    let mut dst_chunks = dst.chunks_exact_mut(32);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 32 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm256_loadu_si256(s.as_ptr() as *const __m256i);
+            _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, _mm256_gf2p8affine_epi64_epi8(x, m, 0));
+        }
+    }
+    scalar::mul_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+/// Same as [`scalar::mul_add_slice`], the CPU must support GFNI and AVX
+#[target_feature(enable = "gfni,avx")]
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let m = _mm256_set1_epi64x(matrix(c));
+    let mut src_chunks = src.chunks_exact(32);
+    let mut dst_chunks = dst.chunks_exact_mut(32);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 32 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm256_loadu_si256(s.as_ptr() as *const __m256i);
+            let y = _mm256_loadu_si256(d.as_ptr() as *const __m256i);
+            let product = _mm256_gf2p8affine_epi64_epi8(x, m, 0);
+            _mm256_storeu_si256(d.as_mut_ptr() as *mut __m256i, _mm256_xor_si256(y, product));
+        }
+    }
+    scalar::mul_add_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{cpu, scalar};
+
+    #[test]
+    fn matches_scalar() {
+        crate::gf::init_test_tables();
+        if !cpu::has(cpu::GFNI | cpu::AVX) {
+            return;
+        }
+
+        let src: [u8; 259] = core::array::from_fn(|i| (i * 31) as u8);
+        for c in 0..=255 {
+            let (mut expected, mut actual) = ([7; 259], [7; 259]);
+            scalar::mul_add_slice(c, &src, &mut expected);
+            unsafe { super::mul_add_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+
+            scalar::mul_slice(c, &src, &mut expected);
+            unsafe { super::mul_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Bulk operations over whole byte slices in the default field.
+//!
+//! These are the building blocks of vectorized encoding and matrix based erasure coding.
+//! Each operation dispatches to the fastest backend supported by the CPU, the table based
+//! scalar one is always present.
+mod dispatch;
+mod scalar;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+mod cpu;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+mod clmul;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+mod gfni;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+mod avx2;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+mod ssse3;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))]
+mod neon;
+#[cfg(all(feature = "portable_simd", not(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))))]
+mod portable;
+
+pub use self::dispatch::{backend, set_backend, Backend};
+
+/// Number of elements `inverse_slice` inverts per table inversion, bounds its stack usage
+const INVERSE_BATCH: usize = 64;
+
+/// Calls the operation of the selected backend
+macro_rules! dispatch {
+    ($op:ident($($arg:expr),*)) => {
+        // SAFETY: only backends supported by the CPU can be selected
+        match backend() {
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Gfni => unsafe { gfni::$op($($arg),*) },
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Avx2 => unsafe { avx2::$op($($arg),*) },
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Ssse3 => unsafe { ssse3::$op($($arg),*) },
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")))]
+            Backend::Clmul => unsafe { clmul::$op($($arg),*) },
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))]
+            Backend::Neon => unsafe { neon::$op($($arg),*) },
+            #[cfg(all(feature = "portable_simd", not(all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe")))))]
+            Backend::PortableSimd => portable::$op($($arg),*),
+            _ => scalar::$op($($arg),*),
+        }
+    };
+}
+
+/// Products of `c` and every low nibble value, then of every high nibble value, for shuffle
+/// based backends: `c * x = lo[x & 0xf] ^ hi[x >> 4]`
+#[cfg(any(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "forbid_unsafe")), feature = "portable_simd",
+          all(target_arch = "aarch64", target_feature = "neon", not(feature = "forbid_unsafe"))))]
+fn nibble_tables(c: u8) -> [[u8; 16]; 2] {
+    let lo = core::array::from_fn(|i| super::mul(c, i as u8));
+    let hi = core::array::from_fn(|i| super::mul(c, (i as u8) << 4));
+    [lo, hi]
+}
+
+/// Multiplies every element of `src` by `c` and stores products in `dst`.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf;
+///
+/// let mut dst = [0; 3];
+/// gf::mul_slice(2, &[1, 0x80, 0], &mut dst);
+/// assert_eq!([2, 0x1d, 0], dst);
+/// ```
+pub fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len());
+    dispatch!(mul_slice(c, src, dst))
+}
+
+/// Multiplies every element of `src` by `c` and adds (XOR-s) products to `dst`.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf;
+///
+/// let mut dst = [1, 1, 1];
+/// gf::mul_add_slice(2, &[1, 0x80, 0], &mut dst);
+/// assert_eq!([3, 0x1c, 1], dst);
+/// ```
+pub fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len());
+    dispatch!(mul_add_slice(c, src, dst))
+}
+
+/// Replaces every non-zero element of `xs` with its multiplicative inverse, zeros are kept.
+///
+/// Uses Montgomery's trick: elements are inverted in batches, each batch costs a single
+/// inversion and three multiplications per element.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::gf;
+///
+/// let mut xs = [1, 2, 0, 0x53];
+/// gf::inverse_slice(&mut xs);
+/// assert_eq!([1, gf::inverse(2), 0, gf::inverse(0x53)], xs);
+/// ```
+pub fn inverse_slice(xs: &mut [u8]) {
+    let mut prefix = [0u8; INVERSE_BATCH];
+    for batch in xs.chunks_mut(INVERSE_BATCH) {
+        // prefix[i] is the product of all non-zero elements before i
+        let mut acc = 1;
+        for (p, x) in prefix.iter_mut().zip(batch.iter()) {
+            *p = acc;
+            if *x != 0 {
+                acc = super::mul(acc, *x);
+            }
+        }
+
+        let mut inv = super::inverse(acc);
+        for (p, x) in prefix.iter().zip(batch.iter_mut()).rev() {
+            if *x != 0 {
+                let next = super::mul(inv, *x);
+                *x = super::mul(inv, *p);
+                inv = next;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gf;
+
+    #[test]
+    fn mul_slice() {
+        crate::gf::init_test_tables();
+        let src: [u8; 256] = core::array::from_fn(|i| i as u8);
+        for c in [0, 1, 2, 0x53, 0xff] {
+            let mut dst = [0xaa; 256];
+            super::mul_slice(c, &src, &mut dst);
+            for (d, s) in dst.iter().zip(src.iter()) {
+                assert_eq!(gf::mul(c, *s), *d);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_slice() {
+        crate::gf::init_test_tables();
+        let mut xs: [u8; 300] = core::array::from_fn(|i| (i * 13) as u8);
+        super::inverse_slice(&mut xs);
+        for (i, x) in xs.iter().enumerate() {
+            let orig = (i * 13) as u8;
+            if orig == 0 {
+                assert_eq!(0, *x);
+            } else {
+                assert_eq!(1, gf::mul(orig, *x));
+            }
+        }
+    }
+
+    #[test]
+    fn mul_add_slice() {
+        crate::gf::init_test_tables();
+        let src: [u8; 256] = core::array::from_fn(|i| i as u8);
+        for c in [0, 1, 2, 0x53, 0xff] {
+            let mut dst: [u8; 256] = core::array::from_fn(|i| (i * 7) as u8);
+            super::mul_add_slice(c, &src, &mut dst);
+            for (i, d) in dst.iter().enumerate() {
+                assert_eq!(gf::mul(c, src[i]) ^ (i * 7) as u8, *d);
+            }
+        }
+    }
+}
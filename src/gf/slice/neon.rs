@@ -0,0 +1,74 @@
+//! NEON backend, 16 bytes per iteration.
+//!
+//! Products of the constant and all 16 values of a nibble fit a single vector register, so
+//! every byte is multiplied with two `tbl` lookups, one per nibble.
+use core::arch::aarch64::*;
+use super::{nibble_tables, scalar};
+
+/// Multiplies 16 bytes by the constant whose nibble tables are `lo` and `hi`
+#[inline]
+#[target_feature(enable = "neon")]
+fn mul16(x: uint8x16_t, lo: uint8x16_t, hi: uint8x16_t) -> uint8x16_t {
+    let x_lo = vandq_u8(x, vdupq_n_u8(0x0f));
+    let x_hi = vshrq_n_u8::<4>(x);
+    veorq_u8(vqtbl1q_u8(lo, x_lo), vqtbl1q_u8(hi, x_hi))
+}
+
+/// Same as [`scalar::mul_slice`]
+#[target_feature(enable = "neon")]
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let [lo, hi] = nibble_tables(c);
+    // SAFETY: the tables are 16 bytes long
+    let (lo, hi) = unsafe { (vld1q_u8(lo.as_ptr()), vld1q_u8(hi.as_ptr())) };
+
+    let mut src_chunks = src.chunks_exact(16);
+    let mut dst_chunks = dst.chunks_exact_mut(16);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 16 bytes long
+        unsafe {
+            let x = vld1q_u8(s.as_ptr());
+            vst1q_u8(d.as_mut_ptr(), mul16(x, lo, hi));
+        }
+    }
+    scalar::mul_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+/// Same as [`scalar::mul_add_slice`]
+#[target_feature(enable = "neon")]
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let [lo, hi] = nibble_tables(c);
+    // SAFETY: the tables are 16 bytes long
+    let (lo, hi) = unsafe { (vld1q_u8(lo.as_ptr()), vld1q_u8(hi.as_ptr())) };
+
+    let mut src_chunks = src.chunks_exact(16);
+    let mut dst_chunks = dst.chunks_exact_mut(16);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 16 bytes long
+        unsafe {
+            let x = vld1q_u8(s.as_ptr());
+            let y = vld1q_u8(d.as_ptr());
+            vst1q_u8(d.as_mut_ptr(), veorq_u8(y, mul16(x, lo, hi)));
+        }
+    }
+    scalar::mul_add_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::scalar;
+
+    #[test]
+    fn matches_scalar() {
+        let src: [u8; 259] = core::array::from_fn(|i| (i * 31) as u8);
+        for c in 0..=255 {
+            let (mut expected, mut actual) = ([7; 259], [7; 259]);
+            scalar::mul_add_slice(c, &src, &mut expected);
+            unsafe { super::mul_add_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+
+            scalar::mul_slice(c, &src, &mut expected);
+            unsafe { super::mul_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Portable table based implementation, always available.
+use crate::gf::{exp_index, exp_table, log_table};
+
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    if cfg!(feature = "constant_time") {
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d = crate::gf::mul(c, *s);
+        }
+        return;
+    }
+
+    match c {
+        0 => dst.fill(0),
+        1 => dst.copy_from_slice(src),
+        _ => {
+            let (exp, log) = (exp_table(), log_table());
+            let log_c = log[c as usize] as usize;
+            for (d, s) in dst.iter_mut().zip(src.iter()) {
+                *d = if *s == 0 { 0 } else { exp[exp_index(log_c + log[*s as usize] as usize)] };
+            }
+        }
+    }
+}
+
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    if cfg!(feature = "constant_time") {
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d ^= crate::gf::mul(c, *s);
+        }
+        return;
+    }
+
+    match c {
+        0 => {}
+        1 => {
+            for (d, s) in dst.iter_mut().zip(src.iter()) {
+                *d ^= s;
+            }
+        }
+        _ => {
+            let (exp, log) = (exp_table(), log_table());
+            let log_c = log[c as usize] as usize;
+            for (d, s) in dst.iter_mut().zip(src.iter()) {
+                if *s != 0 {
+                    *d ^= exp[exp_index(log_c + log[*s as usize] as usize)];
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! SSSE3 backend, 16 bytes per iteration.
+//!
+//! The 128-bit variant of the AVX2 backend for older x86 CPUs: every byte is multiplied with
+//! two `pshufb` lookups into the nibble tables of the constant.
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use super::{nibble_tables, scalar};
+
+/// Loads nibble tables of `c`
+#[inline]
+#[target_feature(enable = "ssse3")]
+fn tables(c: u8) -> (__m128i, __m128i) {
+    let [lo, hi] = nibble_tables(c);
+    // SAFETY: the tables are 16 bytes long, the loads are unaligned
+    unsafe {
+        (_mm_loadu_si128(lo.as_ptr() as *const __m128i), _mm_loadu_si128(hi.as_ptr() as *const __m128i))
+    }
+}
+
+/// Multiplies 16 bytes by the constant whose nibble tables are `lo` and `hi`
+#[inline]
+#[target_feature(enable = "ssse3")]
+fn mul16(x: __m128i, lo: __m128i, hi: __m128i) -> __m128i {
+    let mask = _mm_set1_epi8(0x0f);
+    let x_lo = _mm_and_si128(x, mask);
+    let x_hi = _mm_and_si128(_mm_srli_epi16(x, 4), mask);
+    _mm_xor_si128(_mm_shuffle_epi8(lo, x_lo), _mm_shuffle_epi8(hi, x_hi))
+}
+
+/// Same as [`scalar::mul_slice`], the CPU must support SSSE3
+#[target_feature(enable = "ssse3")]
+pub(crate) fn mul_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let (lo, hi) = tables(c);
+    let mut src_chunks = src.chunks_exact(16);
+    let mut dst_chunks = dst.chunks_exact_mut(16);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 16 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+            _mm_storeu_si128(d.as_mut_ptr() as *mut __m128i, mul16(x, lo, hi));
+        }
+    }
+    scalar::mul_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+/// Same as [`scalar::mul_add_slice`], the CPU must support SSSE3
+#[target_feature(enable = "ssse3")]
+pub(crate) fn mul_add_slice(c: u8, src: &[u8], dst: &mut [u8]) {
+    let (lo, hi) = tables(c);
+    let mut src_chunks = src.chunks_exact(16);
+    let mut dst_chunks = dst.chunks_exact_mut(16);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        // SAFETY: both chunks are 16 bytes long, the loads and stores are unaligned
+        unsafe {
+            let x = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+            let y = _mm_loadu_si128(d.as_ptr() as *const __m128i);
+            _mm_storeu_si128(d.as_mut_ptr() as *mut __m128i, _mm_xor_si128(y, mul16(x, lo, hi)));
+        }
+    }
+    scalar::mul_add_slice(c, src_chunks.remainder(), dst_chunks.into_remainder());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{cpu, scalar};
+
+    #[test]
+    fn matches_scalar() {
+        crate::gf::init_test_tables();
+        if !cpu::has(cpu::SSSE3) {
+            return;
+        }
+
+        let src: [u8; 259] = core::array::from_fn(|i| (i * 31) as u8);
+        for c in 0..=255 {
+            let (mut expected, mut actual) = ([7; 259], [7; 259]);
+            scalar::mul_add_slice(c, &src, &mut expected);
+            unsafe { super::mul_add_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+
+            scalar::mul_slice(c, &src, &mut expected);
+            unsafe { super::mul_slice(c, &src, &mut actual) };
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! EXP/LOG tables of a GF(2^8) chosen at runtime.
+//!
+//! [`Gf2p8`](super::Gf2p8) fixes the primitive polynomial at compile time. When it is only known
+//! at runtime, e.g. read from a peer's configuration, `GfTables` holds the 768 bytes of tables
+//! in a value which can live in a `static`, on the stack or on the heap.
+use super::{exp_index, make_exp_table, make_log_table, EXP_SIZE, LOG_SIZE};
+
+/// EXP/LOG tables of GF(2^8) generated by a primitive polynomial
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::gf::GfTables;
+///
+/// static DEFAULT: GfTables = GfTables::new(0x11d);
+/// let ccsds = GfTables::try_new(0x187).unwrap();
+///
+/// assert_eq!(0x1d, DEFAULT.mul(0x80, 2));
+/// assert_eq!(0x87, ccsds.mul(0x80, 2));
+/// assert!(GfTables::try_new(0x100).is_none());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct GfTables {
+    prim: u16,
+    exp: [u8; EXP_SIZE],
+    log: [u8; LOG_SIZE],
+}
+
+impl GfTables {
+    /// Builds tables for primitive polynomial `prim`.
+    ///
+    /// Panics (at compile time in a const context) if `prim` is not a primitive polynomial
+    /// of degree 8.
+    pub const fn new(prim: u16) -> Self {
+        GfTables {
+            prim,
+            exp: make_exp_table(prim),
+            log: make_log_table(prim),
+        }
+    }
+
+    /// Builds tables for primitive polynomial `prim`, returns `None` if it's not
+    /// a primitive polynomial of degree 8.
+    pub fn try_new(prim: u16) -> Option<Self> {
+        if is_primitive(prim) {
+            Some(Self::new(prim))
+        } else {
+            None
+        }
+    }
+
+    /// Primitive polynomial of the field
+    #[inline]
+    pub fn primitive_poly(&self) -> u16 {
+        self.prim
+    }
+
+    /// Exponent table: `exp()[i] = 2^i`
+    #[inline]
+    pub fn exp(&self) -> &[u8; EXP_SIZE] {
+        &self.exp
+    }
+
+    /// Logarithm table: `log()[2^i] = i`, `log()[0]` is unused
+    #[inline]
+    pub fn log(&self) -> &[u8; LOG_SIZE] {
+        &self.log
+    }
+
+    /// Multiplies two field elements
+    #[inline]
+    pub fn mul(&self, x: u8, y: u8) -> u8 {
+        if x == 0 || y == 0 {
+            0
+        } else {
+            let (exp, log) = (&self.exp, &self.log);
+            let log_x = uncheck!(log[x as usize]);
+            let log_y = uncheck!(log[y as usize]);
+            uncheck!(exp[exp_index(log_x as usize + log_y as usize)])
+        }
+    }
+
+    /// Divides `x` by `y`, `y` must not be zero
+    #[inline]
+    pub fn div(&self, x: u8, y: u8) -> u8 {
+        debug_assert!(y != 0);
+        if x == 0 {
+            0
+        } else {
+            let (exp, log) = (&self.exp, &self.log);
+            let log_x = uncheck!(log[x as usize]) as usize;
+            let log_y = uncheck!(log[y as usize]) as usize;
+            uncheck!(exp[(log_x + 255 - log_y) % 255])
+        }
+    }
+
+    /// Raises `x` to the (possibly negative) `power`
+    #[inline]
+    pub fn pow(&self, x: u8, power: i32) -> u8 {
+        let (exp, log) = (&self.exp, &self.log);
+        let i = (uncheck!(log[x as usize]) as i32 * power).rem_euclid(255);
+        uncheck!(exp[i as usize])
+    }
+
+    /// Multiplicative inverse of a non-zero element
+    #[inline]
+    pub fn inverse(&self, x: u8) -> u8 {
+        let (exp, log) = (&self.exp, &self.log);
+        let exp_index = 255 - uncheck!(log[x as usize]);
+        uncheck!(exp[exp_index as usize])
+    }
+}
+
+impl core::fmt::Debug for GfTables {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "GfTables({:#x})", self.prim)
+    }
+}
+
+/// Checks that `prim` has degree 8 and `x` generates all 255 non-zero elements modulo it
+fn is_primitive(prim: u16) -> bool {
+    if prim & 0x100 == 0 || prim >= 0x200 {
+        return false;
+    }
+
+    let mut x: u16 = 1;
+    for i in 1..=255 {
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= prim;
+        }
+        if x == 1 {
+            return i == 255;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf::Gf2p8;
+
+    #[test]
+    fn matches_const_fields() {
+        let default = GfTables::new(0x11d);
+        let ccsds = GfTables::try_new(0x187).unwrap();
+
+        assert_eq!(&Gf2p8::<0x11d>::EXP, default.exp());
+        assert_eq!(&Gf2p8::<0x187>::LOG, ccsds.log());
+
+        for x in 1..=255u8 {
+            assert_eq!(Gf2p8::<0x187>::mul(x, 0x35), ccsds.mul(x, 0x35));
+            assert_eq!(Gf2p8::<0x187>::div(x, 0x35), ccsds.div(x, 0x35));
+            assert_eq!(Gf2p8::<0x187>::pow(x, -3), ccsds.pow(x, -3));
+            assert_eq!(Gf2p8::<0x187>::inverse(x), ccsds.inverse(x));
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        // All 16 primitive polynomials of degree 8
+        let primitive = [0x11d, 0x12b, 0x12d, 0x14d, 0x15f, 0x163, 0x165, 0x169,
+                         0x171, 0x187, 0x18d, 0x1a9, 0x1c3, 0x1cf, 0x1e7, 0x1f5];
+        for prim in 0..0x400 {
+            assert_eq!(primitive.contains(&prim), GfTables::try_new(prim).is_some());
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! Block interleaving of codewords, spreading channel bursts over several codewords.
+//!
+//! A block is a matrix of `D` codewords of `n` bytes written as rows and transmitted column by
+//! column: byte `j` of codeword `i` goes out at position `j * D + i`. A burst of up to `D * t`
+//! channel bytes then damages at most `t` bytes of every codeword.
+//!
+//! [`interleave`] and [`deinterleave`] transpose whole blocks. [`Interleaver`] and
+//! [`Deinterleaver`] do the same byte by byte for streams, one byte out for every byte in,
+//! delayed by one block and starting with a block of zeros.
+//!
+//! [`ConvolutionalInterleaver`] and [`ConvolutionalDeinterleaver`] spread bursts with delay lines
+//! of increasing length instead, with half the memory and delay of a block interleaver.
+
+/// Longest codeword
+const MAX_LEN: usize = 255;
+
+/// Writes a block of `D` concatenated codewords to `out` in channel order.
+///
+/// # Panics
+/// If the lengths of `codewords` and `out` differ or aren't a multiple of `D`.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::interleave::{deinterleave, interleave};
+///
+/// let codewords = [1, 2, 3, 4, 5, 6];
+/// let mut channel = [0; 6];
+/// interleave::<2>(&codewords, &mut channel);
+/// assert_eq!([1, 4, 2, 5, 3, 6], channel);
+///
+/// let mut restored = [0; 6];
+/// deinterleave::<2>(&channel, &mut restored);
+/// assert_eq!(codewords, restored);
+/// ```
+pub fn interleave<const D: usize>(codewords: &[u8], out: &mut [u8]) {
+    assert_eq!(codewords.len(), out.len(), "output length must match the block");
+    assert!(codewords.len().is_multiple_of(D), "block length must be a multiple of the depth");
+    let len = codewords.len() / D;
+    for (k, x) in codewords.iter().enumerate() {
+        out[k % len * D + k / len] = *x;
+    }
+}
+
+/// Inverse of [`interleave`]: restores `D` concatenated codewords from a block in channel order.
+///
+/// # Panics
+/// If the lengths of `channel` and `out` differ or aren't a multiple of `D`.
+pub fn deinterleave<const D: usize>(channel: &[u8], out: &mut [u8]) {
+    assert_eq!(channel.len(), out.len(), "output length must match the block");
+    assert!(channel.len().is_multiple_of(D), "block length must be a multiple of the depth");
+    let len = channel.len() / D;
+    for (k, x) in out.iter_mut().enumerate() {
+        *x = channel[k % len * D + k / len];
+    }
+}
+
+/// Two blocks of `D` rows, one filling up while the other one is drained
+#[derive(Debug, Clone)]
+struct Blocks<const D: usize> {
+    rows: [[[u8; MAX_LEN]; D]; 2],
+    len: usize,
+    index: usize,
+    current: usize,
+}
+
+impl<const D: usize> Blocks<D> {
+    fn new(codeword_len: usize) -> Self {
+        assert!(D > 0, "depth must not be zero");
+        assert!((1..=MAX_LEN).contains(&codeword_len), "codeword length must be 1 to 255");
+        Blocks {
+            rows: [[[0; MAX_LEN]; D]; 2],
+            len: codeword_len,
+            index: 0,
+            current: 0,
+        }
+    }
+
+    /// Stores the next byte of the current block row by row if `by_rows` or else column by
+    /// column, and returns the byte of the previous block at the same index in the other order
+    fn push(&mut self, byte: u8, by_rows: bool) -> u8 {
+        let k = self.index;
+        let row_major = (k / self.len, k % self.len);
+        let column_major = (k % D, k / D);
+        let ((wr, wc), (rr, rc)) = if by_rows { (row_major, column_major) } else { (column_major, row_major) };
+
+        self.rows[self.current][wr][wc] = byte;
+        let out = self.rows[1 - self.current][rr][rc];
+
+        self.index += 1;
+        if self.index == D * self.len {
+            self.index = 0;
+            self.current = 1 - self.current;
+        }
+        out
+    }
+
+    fn reset(&mut self) {
+        self.rows = [[[0; MAX_LEN]; D]; 2];
+        self.index = 0;
+        self.current = 0;
+    }
+}
+
+/// Streaming block interleaver of depth `D`, taking codeword bytes and producing channel bytes
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::interleave::{Deinterleaver, Interleaver};
+///
+/// let mut interleaver = Interleaver::<4>::new(10);
+/// let mut deinterleaver = Deinterleaver::<4>::new(10);
+/// let delay = 2 * interleaver.block_len();
+///
+/// for i in 0..200u32 {
+///     let out = deinterleaver.push(interleaver.push(i as u8));
+///     if i as usize >= delay {
+///         assert_eq!((i as usize - delay) as u8, out);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interleaver<const D: usize> {
+    blocks: Blocks<D>,
+}
+
+impl<const D: usize> Interleaver<D> {
+    /// Constructs an interleaver of codewords of `codeword_len` bytes
+    ///
+    /// # Panics
+    /// If `D` is zero or `codeword_len` is not in `1..=255`.
+    pub fn new(codeword_len: usize) -> Self {
+        Interleaver { blocks: Blocks::new(codeword_len) }
+    }
+
+    /// Bytes of a block, the delay of the interleaver
+    pub fn block_len(&self) -> usize {
+        D * self.blocks.len
+    }
+
+    /// Takes the next codeword byte and returns the next channel byte
+    pub fn push(&mut self, byte: u8) -> u8 {
+        self.blocks.push(byte, true)
+    }
+
+    /// Forgets the buffered bytes, e.g. to resynchronize on a block boundary
+    pub fn reset(&mut self) {
+        self.blocks.reset();
+    }
+}
+
+/// Streaming block deinterleaver of depth `D`, taking channel bytes and producing codeword bytes
+#[derive(Debug, Clone)]
+pub struct Deinterleaver<const D: usize> {
+    blocks: Blocks<D>,
+}
+
+impl<const D: usize> Deinterleaver<D> {
+    /// Constructs a deinterleaver of codewords of `codeword_len` bytes
+    ///
+    /// # Panics
+    /// If `D` is zero or `codeword_len` is not in `1..=255`.
+    pub fn new(codeword_len: usize) -> Self {
+        Deinterleaver { blocks: Blocks::new(codeword_len) }
+    }
+
+    /// Bytes of a block, the delay of the deinterleaver
+    pub fn block_len(&self) -> usize {
+        D * self.blocks.len
+    }
+
+    /// Takes the next channel byte and returns the next codeword byte
+    pub fn push(&mut self, byte: u8) -> u8 {
+        self.blocks.push(byte, false)
+    }
+
+    /// Forgets the buffered bytes, e.g. to resynchronize on a block boundary
+    pub fn reset(&mut self) {
+        self.blocks.reset();
+    }
+}
+
+/// `I` branches of up to `I - 1` cells of `M` bytes, branch `j` delaying by `cells(j) * M` bytes
+#[derive(Debug, Clone)]
+struct Branches<const I: usize, const M: usize> {
+    cells: [[[u8; M]; I]; I],
+    heads: [usize; I],
+    branch: usize,
+}
+
+impl<const I: usize, const M: usize> Branches<I, M> {
+    fn new() -> Self {
+        assert!(I > 0 && M > 0, "branches and cell size must not be zero");
+        Branches {
+            cells: [[[0; M]; I]; I],
+            heads: [0; I],
+            branch: 0,
+        }
+    }
+
+    /// Feeds `byte` into the current branch, made of `cells(branch)` cells, and switches to the
+    /// next one
+    fn push(&mut self, byte: u8, cells: impl Fn(usize) -> usize) -> u8 {
+        let j = self.branch;
+        self.branch = (j + 1) % I;
+
+        let len = cells(j) * M;
+        if len == 0 {
+            return byte;
+        }
+        let line = &mut self.cells[j].as_flattened_mut()[..len];
+        let head = self.heads[j];
+        let out = core::mem::replace(&mut line[head], byte);
+        self.heads[j] = (head + 1) % len;
+        out
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Convolutional (Forney) interleaver with `I` branches, branch `j` delaying by `j * M` bytes,
+/// e.g. `I = 12, M = 17` in DVB. Consecutive bytes go to consecutive branches.
+///
+/// Unlike the block interleaver the delay lines hold only `I * (I - 1) * M / 2` bytes on each side
+/// for the same burst spreading. Storage is fixed at `I * I * M` bytes.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::interleave::{ConvolutionalDeinterleaver, ConvolutionalInterleaver};
+///
+/// let mut interleaver = ConvolutionalInterleaver::<12, 17>::new();
+/// let mut deinterleaver = ConvolutionalDeinterleaver::<12, 17>::new();
+/// let delay = ConvolutionalInterleaver::<12, 17>::DELAY;
+/// assert_eq!(12 * 11 * 17, delay);
+///
+/// for i in 0..5000usize {
+///     let out = deinterleaver.push(interleaver.push(i as u8));
+///     if i >= delay {
+///         assert_eq!((i - delay) as u8, out);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConvolutionalInterleaver<const I: usize, const M: usize> {
+    branches: Branches<I, M>,
+}
+
+impl<const I: usize, const M: usize> ConvolutionalInterleaver<I, M> {
+    /// Bytes between a byte entering the interleaver and leaving the deinterleaver
+    pub const DELAY: usize = I * (I - 1) * M;
+
+    /// Constructs an interleaver with empty delay lines, starting at branch 0
+    ///
+    /// # Panics
+    /// If `I` or `M` is zero.
+    pub fn new() -> Self {
+        ConvolutionalInterleaver { branches: Branches::new() }
+    }
+
+    /// Takes the next byte and returns the next channel byte
+    pub fn push(&mut self, byte: u8) -> u8 {
+        self.branches.push(byte, |j| j)
+    }
+
+    /// Branch taking the next byte
+    pub fn branch(&self) -> usize {
+        self.branches.branch
+    }
+
+    /// Empties the delay lines and goes back to branch 0
+    pub fn reset(&mut self) {
+        self.branches.reset();
+    }
+}
+
+impl<const I: usize, const M: usize> Default for ConvolutionalInterleaver<I, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convolutional deinterleaver matching [`ConvolutionalInterleaver`], branch `j` delaying by
+/// `(I - 1 - j) * M` bytes
+#[derive(Debug, Clone)]
+pub struct ConvolutionalDeinterleaver<const I: usize, const M: usize> {
+    branches: Branches<I, M>,
+}
+
+impl<const I: usize, const M: usize> ConvolutionalDeinterleaver<I, M> {
+    /// Constructs a deinterleaver with empty delay lines, starting at branch 0
+    ///
+    /// # Panics
+    /// If `I` or `M` is zero.
+    pub fn new() -> Self {
+        ConvolutionalDeinterleaver { branches: Branches::new() }
+    }
+
+    /// Takes the next channel byte and returns the next byte
+    pub fn push(&mut self, byte: u8) -> u8 {
+        self.branches.push(byte, |j| I - 1 - j)
+    }
+
+    /// Branch taking the next byte, has to be synchronized with the interleaver, e.g. by
+    /// aligning on sync bytes
+    pub fn branch(&self) -> usize {
+        self.branches.branch
+    }
+
+    /// Empties the delay lines and goes back to branch 0
+    pub fn reset(&mut self) {
+        self.branches.reset();
+    }
+}
+
+impl<const I: usize, const M: usize> Default for ConvolutionalDeinterleaver<I, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks() {
+        let codewords: [u8; 12] = core::array::from_fn(|i| i as u8);
+        let mut channel = [0; 12];
+        interleave::<3>(&codewords, &mut channel);
+        assert_eq!([0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11], channel);
+
+        // The streaming interleaver produces the same block, one block late
+        let mut interleaver = Interleaver::<3>::new(4);
+        let mut streamed = [0; 12];
+        for (x, y) in codewords.iter().chain(codewords.iter()).zip(0..) {
+            let out = interleaver.push(*x);
+            if y >= 12 {
+                streamed[y - 12] = out;
+            }
+        }
+        assert_eq!(channel, streamed);
+
+        let mut restored = [0; 12];
+        deinterleave::<3>(&channel, &mut restored);
+        assert_eq!(codewords, restored);
+    }
+
+    #[test]
+    #[cfg(feature = "decoder")]
+    fn burst() {
+        crate::gf::init_test_tables();
+        use crate::{Decoder, Encoder};
+
+        // 4 codewords with 4 ecc bytes survive a burst of 8 channel bytes
+        let encoder = Encoder::<4>::new();
+        let decoder = Decoder::new(4);
+        let mut codewords = [0; 4 * 20];
+        for (i, codeword) in codewords.chunks_mut(20).enumerate() {
+            codeword[..16].fill(i as u8 + 1);
+            let (data, ecc) = codeword.split_at_mut(16);
+            encoder.encode_to_slice(data, ecc).unwrap();
+        }
+
+        let mut channel = [0; 80];
+        interleave::<4>(&codewords, &mut channel);
+        channel[33..41].fill(0xff);
+        let mut received = [0; 80];
+        deinterleave::<4>(&channel, &mut received);
+
+        for (codeword, original) in received.chunks_mut(20).zip(codewords.chunks(20)) {
+            assert_eq!(2, decoder.correct_in_place(codeword, None).unwrap());
+            assert_eq!(original, codeword);
+        }
+    }
+
+    #[test]
+    fn reset() {
+        let mut interleaver = Interleaver::<2>::new(3);
+        for x in 1..5 {
+            interleaver.push(x);
+        }
+        interleaver.reset();
+        for x in 0..6 {
+            assert_eq!(0, interleaver.push(x));
+        }
+        assert_eq!(0, interleaver.push(0));
+        assert_eq!(3, interleaver.push(0));
+    }
+
+    #[test]
+    fn convolutional() {
+        // Branch j delays by j * M bytes, i.e. by j visits of the commutator
+        let mut interleaver = ConvolutionalInterleaver::<3, 2>::new();
+        let out: std::vec::Vec<u8> = (1..=15).map(|x| interleaver.push(x)).collect();
+        assert_eq!([1, 0, 0, 4, 0, 0, 7, 2, 0, 10, 5, 0, 13, 8, 3], out[..]);
+        assert_eq!(0, interleaver.branch());
+
+        let mut deinterleaver = ConvolutionalDeinterleaver::<3, 2>::new();
+        let flushed = out.into_iter().chain((0..12).map(|_| interleaver.push(0)));
+        let back: std::vec::Vec<u8> = flushed.map(|x| deinterleaver.push(x)).collect();
+        let delay = ConvolutionalInterleaver::<3, 2>::DELAY;
+        assert_eq!((1..=15).collect::<std::vec::Vec<u8>>(), back[delay..delay + 15]);
+
+        // A burst of I * t channel bytes leaves pairs of errors I * M - 1 bytes apart, so no
+        // more than t + 1 of them fall within I * M bytes, e.g. a packet
+        let mut interleaver = ConvolutionalInterleaver::<12, 17>::new();
+        let mut deinterleaver = ConvolutionalDeinterleaver::<12, 17>::new();
+        let mut hits = std::vec::Vec::new();
+        for i in 0..6000usize {
+            let mut x = interleaver.push(1);
+            if (3000..3024).contains(&i) {
+                x = 0;
+            }
+            if deinterleaver.push(x) == 0 && i >= ConvolutionalInterleaver::<12, 17>::DELAY {
+                hits.push(i);
+            }
+        }
+        assert_eq!(24, hits.len());
+        for h in hits.iter() {
+            assert!(hits.iter().filter(|x| (*h..h + 12 * 17).contains(*x)).count() <= 3);
+        }
+
+        deinterleaver.reset();
+        assert_eq!(0, deinterleaver.branch());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::encoder::{Encoder, Filling, StreamOut};
+
+/// Adds [`rs_encoded`](EncodeIterExt::rs_encoded) to byte iterators
+pub trait EncodeIterExt: Iterator<Item = u8> + Sized {
+    /// Yields the bytes of this iterator followed by `ECC_LEN` parity bytes after each full
+    /// block of `255 - ECC_LEN` bytes, and after the last partial block.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{EncodeIterExt, Encoder};
+    ///
+    /// let encoded: Vec<u8> = b"Hello World".iter().copied().rs_encoded::<8>().collect();
+    ///
+    /// assert_eq!(b"Hello World", &encoded[..11]);
+    /// assert_eq!(Encoder::<8>::new().encode(b"Hello World").unwrap(), encoded[11..]);
+    /// ```
+    fn rs_encoded<const ECC_LEN: usize>(self) -> RsEncoded<Self, ECC_LEN> {
+        RsEncoded {
+            iter: self,
+            stream: Some(Encoder::new().start()),
+            parity: [0; ECC_LEN],
+            pending: ECC_LEN,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u8>> EncodeIterExt for I {}
+
+/// Iterator returned by [`EncodeIterExt::rs_encoded`]
+#[derive(Debug)]
+pub struct RsEncoded<I, const ECC_LEN: usize> {
+    iter: I,
+    /// `None` once the inner iterator is exhausted
+    stream: Option<Filling<ECC_LEN>>,
+    parity: [u8; ECC_LEN],
+    /// Index of the next parity byte to yield, `ECC_LEN` if there are none
+    pending: usize,
+}
+
+impl<I: Iterator<Item = u8>, const ECC_LEN: usize> Iterator for RsEncoded<I, ECC_LEN> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pending < ECC_LEN {
+            self.pending += 1;
+            return Some(self.parity[self.pending - 1]);
+        }
+
+        let stream = self.stream.as_mut()?;
+        match self.iter.next() {
+            Some(x) => {
+                if let StreamOut::BlockComplete { parity, .. } = stream.encode_single(x) {
+                    self.parity = parity;
+                    self.pending = 0;
+                }
+                Some(x)
+            }
+            None => {
+                let (_, ecc) = self.stream.take()?.finalize();
+                self.parity = ecc?;
+                self.pending = 0;
+                self.next()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+    use super::EncodeIterExt;
+
+    #[test]
+    fn rs_encoded() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 5) as u8);
+        let mut encoder = crate::Encoder::<4>::new();
+
+        let mut expected = Vec::from(&data[..251]);
+        expected.extend_from_slice(&encoder.encode(&data[..251]).unwrap());
+        expected.extend_from_slice(&data[251..]);
+        expected.extend_from_slice(&encoder.encode(&data[251..]).unwrap());
+        assert_eq!(expected, data.iter().copied().rs_encoded::<4>().collect::<Vec<_>>());
+
+        let full = data[..251].iter().copied().rs_encoded::<4>().count();
+        assert_eq!(255, full);
+        assert_eq!(0, core::iter::empty().rs_encoded::<4>().count());
+    }
+}
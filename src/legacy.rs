@@ -0,0 +1,172 @@
+//! Encoder parameterized by its ecc storage size, `ecc_len + 1`, as it was before
+//! [`Encoder`](crate::Encoder) took the ecc length itself.
+//!
+//! Switch `legacy::Encoder::<9>::new(8)` to `Encoder::<8>::new()`.
+#![allow(deprecated)]
+use core::marker::PhantomData;
+use heapless::Vec;
+use crate::buffer::Buffer;
+use crate::encoder::{field_generator_poly, lfsr_run, lfsr_step, log_generator, EncoderError};
+use crate::gf::{GaloisField, Gf2p8};
+
+/// Reed-Solomon BCH encoder whose first parameter is the ecc length plus one
+#[deprecated(note = "use `Encoder::<ECC_LEN>`, which takes the ecc length instead of the storage size")]
+#[derive(Debug)]
+pub struct Encoder<const ECC_BYTE_COUNT_STORE: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    generator: [u8; ECC_BYTE_COUNT_STORE],
+    lgenerator: [u8; ECC_BYTE_COUNT_STORE],
+    remainder: [u8; ECC_BYTE_COUNT_STORE],
+    bytes_processed: u8,
+    field: PhantomData<F>,
+}
+
+impl<const ECC_BYTE_COUNT_STORE: usize, F: GaloisField<Elem = u8>> Encoder<ECC_BYTE_COUNT_STORE, F> {
+    /// Evaluated at compile time by the constructor, methods compute the ecc length as
+    /// `ECC_BYTE_COUNT_STORE - 1`
+    const STORE_NOT_EMPTY: () = assert!(ECC_BYTE_COUNT_STORE >= 1, "ECC_BYTE_COUNT_STORE must be at least 1");
+
+    /// Constructs a new `Encoder` and calculates generator polynomial of given `ecc_len`.
+    ///
+    /// Errors if `ecc_len` is not `ECC_BYTE_COUNT_STORE - 1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::legacy::Encoder;
+    ///
+    /// let mut encoder = Encoder::<9>::new(8).unwrap();
+    /// assert_eq!(reed_solomon::Encoder::<8>::new().encode(b"Hello").unwrap(), encoder.encode(b"Hello").unwrap());
+    /// ```
+    pub fn new(ecc_len: usize) -> Result<Self, EncoderError> {
+        if ecc_len + 1 != ECC_BYTE_COUNT_STORE {
+            return Err(EncoderError::EccLenMismatch);
+        }
+        let generator = field_generator_poly::<F>(ecc_len, 0, 1);
+        let mut store = [0; ECC_BYTE_COUNT_STORE];
+        store.copy_from_slice(&generator);
+        Ok(Self::new_with_precomputed_generator(&store))
+    }
+
+    /// Constructs a new `Encoder` from a precomputed generator polynomial.
+    ///
+    /// `ECC_BYTE_COUNT_STORE` of zero doesn't compile:
+    /// ```rust,compile_fail
+    /// # #![allow(deprecated)]
+    /// use reed_solomon::legacy::Encoder;
+    ///
+    /// let encoder = Encoder::<0>::new_with_precomputed_generator(&[]);
+    /// ```
+    pub fn new_with_precomputed_generator(generator: &[u8; ECC_BYTE_COUNT_STORE]) -> Self {
+        let () = Self::STORE_NOT_EMPTY;
+        Self {
+            lgenerator: log_generator::<F, ECC_BYTE_COUNT_STORE>(generator),
+            generator: *generator,
+            remainder: [0; ECC_BYTE_COUNT_STORE],
+            bytes_processed: 0,
+            field: PhantomData,
+        }
+    }
+
+    /// Same as [`crate::Encoder::encode`]
+    pub fn encode(&mut self, data: &[u8]) -> Result<Vec<u8, ECC_BYTE_COUNT_STORE>, EncoderError> {
+        let mut ecc = Vec::new();
+        unwrap_unchecked!(ecc.resize(ECC_BYTE_COUNT_STORE - 1, 0));
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(ecc)
+    }
+
+    /// Same as [`crate::Encoder::encode_to_slice`]
+    pub fn encode_to_slice(&self, data: &[u8], ecc_out: &mut [u8]) -> Result<(), EncoderError> {
+        let ecc_len = ECC_BYTE_COUNT_STORE - 1;
+        if ecc_out.len() != ecc_len {
+            return Err(EncoderError::EccLenMismatch);
+        }
+        if data.len() + ecc_len > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        ecc_out.fill(0);
+        lfsr_run::<F>(&self.generator[1..], &self.lgenerator[1..], ecc_out, data);
+        Ok(())
+    }
+
+    /// Same as [`crate::Encoder::encode_buffer`]
+    pub fn encode_buffer(&self, data: &[u8]) -> Result<Buffer, EncoderError> {
+        let ecc_len = ECC_BYTE_COUNT_STORE - 1;
+        if data.len() + ecc_len > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        let mut buffer = Buffer::from_slice(data, data.len());
+        buffer.set_length(data.len() + ecc_len);
+        let (data, ecc) = buffer.split_at_mut(data.len());
+        self.encode_to_slice(data, ecc)?;
+        Ok(buffer)
+    }
+
+    /// Feeds a single byte into the streaming encoder.
+    ///
+    /// Returns the byte itself, followed by the error correction code once a full
+    /// block of `255 - ecc_len` bytes has been processed.
+    pub fn encode_single(&mut self, data: u8) -> Vec<u8, ECC_BYTE_COUNT_STORE> {
+        let ecc_len = ECC_BYTE_COUNT_STORE - 1;
+        lfsr_step::<F>(&self.generator[1..], &self.lgenerator[1..], &mut self.remainder[..ecc_len], data);
+
+        self.bytes_processed += 1;
+        if self.bytes_processed as usize == F::ORDER - ecc_len {
+            let mut ecc = unwrap_unchecked!(self.finalize());
+            unwrap_unchecked!(ecc.insert(0, data));
+            return ecc;
+        }
+
+        unwrap_unchecked!(Vec::from_slice(&[data]))
+    }
+
+    /// Finishes the current block and returns its error correction code.
+    ///
+    /// Errors if no bytes were fed since the last block boundary.
+    pub fn finalize(&mut self) -> Result<Vec<u8, ECC_BYTE_COUNT_STORE>, EncoderError> {
+        if self.bytes_processed == 0 {
+            return Err(EncoderError::NothingToFinalize);
+        }
+
+        let out = unwrap_unchecked!(Vec::from_slice(&self.remainder[..ECC_BYTE_COUNT_STORE - 1]));
+        self.reset();
+        Ok(out)
+    }
+
+    /// Discards any partially encoded block.
+    pub fn reset(&mut self) {
+        self.remainder = [0; ECC_BYTE_COUNT_STORE];
+        self.bytes_processed = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    #[test]
+    fn matches_encoder() {
+        crate::gf::init_test_tables();
+        let data: [u8; 300] = core::array::from_fn(|i| (i * 13) as u8);
+        let mut legacy = super::Encoder::<5>::new(4).unwrap();
+        let mut encoder = crate::Encoder::<4>::new();
+        assert_eq!(Err(crate::EncoderError::EccLenMismatch), super::Encoder::<5>::new(5).map(|_| ()));
+        assert_eq!(encoder.encode(&data[..100]).unwrap(), legacy.encode(&data[..100]).unwrap());
+
+        let mut stream = encoder.start();
+        let (mut expected, mut actual) = (Vec::new(), Vec::new());
+        for x in data.iter() {
+            expected.push(*x);
+            if let crate::StreamOut::BlockComplete { parity, .. } = stream.encode_single(*x) {
+                expected.extend_from_slice(&parity);
+            }
+            actual.extend_from_slice(&legacy.encode_single(*x));
+        }
+        expected.extend_from_slice(&stream.finalize().1.unwrap());
+        actual.extend_from_slice(&legacy.finalize().unwrap());
+        assert_eq!(expected, actual);
+    }
+}
@@ -10,17 +10,18 @@
 //! use reed_solomon::Decoder;
 //!
 //! fn main() {
+//!     # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
 //!     let data = b"Hello World!";
 //!
 //!     // Length of error correction code
 //!     let ecc_len = 8;
 //!
 //!     // Create encoder and decoder with
-//!     let mut enc = Encoder::<9>::new(ecc_len);
+//!     let mut enc = Encoder::<8>::new();
 //!     let dec = Decoder::new(ecc_len);
 //!
 //!     // Encode data
-//!     let encoded = enc.encode(&data[..]);
+//!     let encoded = enc.encode(&data[..]).unwrap();
 //!     let mut corrupted = Vec::from(data);
 //!     corrupted.extend_from_slice(&encoded);
 //!
@@ -44,6 +45,48 @@
 //! }
 //! ```
 //!
+//! # Field
+//! By default codes work over GF(2^8) generated by primitive polynomial 0x11d. Other fields
+//! (e.g. 0x187 used by CCSDS) are selected with a type parameter implementing `gf::GaloisField`:
+//! `Encoder::<8, Gf2p8<0x187>>` and `Decoder::<Gf2p8<0x187>>::with_field(8)`. Their tables
+//! are built at compile time.
+//!
+//! # CCSDS
+//! Module `ccsds` converts symbols between the conventional and Berlekamp's dual basis and
+//! provides `ccsds::Encoder`, whose dual basis parity is bit-exact with the CCSDS telemetry code.
+//! `ccsds::Codec` encodes and decodes whole codeblocks with interleaving depth 1 to 8, and
+//! `ccsds::Randomizer` applies the pseudo-randomizer to them.
+//!
+//! # Presets
+//! Module `presets` collects the ecc length, generator roots and shortening of common standards:
+//! QR code, CCSDS RS(255, 223), DVB RS(204, 188), ATSC RS(207, 187) and G.709 RS(255, 239).
+//! `presets::qr::BlockStructure` splits, encodes and interleaves the blocks of a whole QR symbol.
+//!
+//! # Interleaving
+//! Module `interleave` spreads channel bursts over `D` codewords with a block interleaver,
+//! either on whole blocks or byte by byte on streams, or with a convolutional (Forney)
+//! interleaver. `presets::dvb::OuterEncoder` chains the latter with RS(204, 188) as in DVB.
+//!
+//! # Whitening
+//! Module `whitening` provides the PN9 whitening of sub-GHz radios, applied to the encoded
+//! frame after the encoder and removed before the decoder.
+//!
+//! # Framing
+//! Module `frame` wraps a payload into a self-describing `[len varint][payload][parity]` frame
+//! with `encode_frame` and checks and unwraps it with `decode_frame`.
+//!
+//! Library feature `cobs` adds module `cobs`, whose `CobsRsCodec` stuffs codewords with COBS
+//! into zero-delimited frames for UART links.
+//!
+//! # CIRC
+//! Module `circ` implements the cross-interleaved code of the compact disc: C2 RS(28, 24) and
+//! C1 RS(32, 28) with their delay lines, as a streaming frame encoder and decoder.
+//!
+//! # Product codes
+//! Module `product` protects a rectangular block with any row and column codes implementing
+//! `product::LineCode` and decodes it iteratively with a configurable number of passes.
+//! `presets::dvd` gives the (208, 192) x (182, 172) code of the DVD ECC block.
+//!
 //! # Unsafe
 //! This library uses some slices indexind that is boundary checked.
 //!
@@ -51,6 +94,79 @@
 //! then unsafe `Slice::get_inchecked()` would be utilized to improve speed where unchecked indexing
 //! is considered safe and LLVM cannot drop boundary checks.
 //!
+//! Library feature `forbid_unsafe` instead builds the crate with `#![forbid(unsafe_code)]`:
+//! all indexing is checked and architecture specific SIMD backends are left out. It can't be
+//! combined with `unsafe_indexing` or `external_tables`.
+//!
+//! # Alloc
+//! Library feature `alloc` enables parts that need heap memory, such as
+//! `BerlekampWelchDecoder` and `DynEncoder`, whose ecc length is chosen at runtime.
+//!
+//! # Serde
+//! Library feature `serde` implements `Serialize` and `Deserialize` for `Polynom` (a sequence of
+//! coefficients), `Buffer`, `Encoder` (its generator polynomial) and the `Decoder` configuration,
+//! e.g. to keep golden vectors in JSON fixtures.
+//!
+//! # Random data
+//! Library feature `rand` adds module `random` with generators of random polynomials, messages
+//! and error patterns of bounded weight for property tests and channel simulations. They draw
+//! from any `FnMut() -> u64`, so the `rand` crate itself is not a dependency.
+//!
+//! # Parallel encoding
+//! Library feature `std` adds `BlockEncoder::encode_parallel`, which encodes the codewords of a
+//! long message on all available cores with scoped threads.
+//!
+//! # 16-bit symbols
+//! With `alloc` feature `Encoder16` and `Decoder16` work over GF(2^16), so a single codeword
+//! can be up to 65535 symbols long instead of 255, e.g. to protect a whole 64 KiB storage block.
+//! Their lookup tables take 384 KiB.
+//!
+//! For hundreds or thousands of parity symbols `FftEncoder16` encodes in `O(n log ecc_len)` with
+//! the additive FFT (as Leopard-RS does). It produces an evaluation based code, not the BCH code
+//! of `Encoder16`, so its parity can't be checked by `Decoder16`.
+//!
+//! # 4-bit symbols
+//! `Encoder4` and `Decoder4` work over GF(2^4) with codewords of at most 15 symbols, which suits
+//! very short frames such as RFID tags or sensor beacons. `pack_nibbles` and `unpack_nibbles`
+//! convert symbols to and from two-per-byte form.
+//!
+//! # List decoding
+//! Experimental library feature `list_decoder` enables `ListDecoder`, which implements
+//! Guruswami-Sudan list decoding. It returns every codeword close enough to the received block
+//! and can correct more than `ecc_len / 2` errors of low-rate codes; an outer check (e.g. CRC)
+//! picks the right candidate. It needs heap memory and is slow for long blocks.
+//!
+//! # External tables
+//! By default EXP and LOG lookup tables (768 bytes) are embedded into the binary.
+//! With library feature `external_tables` they are left out and the application registers its own
+//! copy (e.g. located in external QSPI flash) with `init_tables()`, which validates them.
+//! Encoding or decoding before the tables are registered panics.
+//!
+//! # Compact tables
+//! Library feature `compact_tables` halves the EXP table to 256 bytes at the cost of an index wrap
+//! in every multiplication, which matters on parts with just a few KiB of flash.
+//!
+//! # Multiplication table
+//! Library feature `mul_table` adds a 64 KiB table of all products in the default field, so that
+//! multiplication is a single lookup without a branch on zero. It trades memory for speed on
+//! desktop and server machines; the table is embedded even with `external_tables`.
+//!
+//! # Constant time
+//! Library feature `constant_time` replaces table lookups in the GF(2^8) arithmetics, `Encoder`
+//! and slice operations with branch-free shift-and-add multiplication, so that timing and cache
+//! state don't depend on the processed symbols. It's meant for code-based cryptography, where
+//! secret dependent table indices are unacceptable, and makes arithmetics several times slower.
+//! `GfTables`, GF(2^4) and GF(2^16) keep using tables.
+//!
+//! # SIMD
+//! Slice operations `gf::mul_slice` and `gf::mul_add_slice` pick the fastest backend supported
+//! by the CPU on first use: GFNI, AVX2, SSSE3 or `pclmulqdq` on x86 and NEON on ARM, falling back
+//! to table lookups. `gf::set_backend` overrides the choice, e.g. for benchmarking.
+//!
+//! # Portable SIMD
+//! On nightly Rust library feature `portable_simd` adds a slice multiplication backend built on
+//! `core::simd`, used where no architecture specific one is available, e.g. on WASM with SIMD128.
+//!
 //! # Bandwidth
 //! Software implementation is relatively slow because general purpose processors do not support
 //! Galois field arithmetic operations. For example, Galois field multiply requires test for 0,
@@ -160,31 +276,87 @@
 //!   </tr>
 //! </table>
 
-#![cfg_attr(feature = "dev", feature(plugin))]
-#![cfg_attr(feature = "dev", plugin(clippy))]
 #![warn(missing_docs, missing_debug_implementations,
         trivial_casts, trivial_numeric_casts,
         unstable_features)]
 
 #![no_std]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd), allow(unstable_features))]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate heapless;
 
+#[cfg(all(feature = "forbid_unsafe", feature = "unsafe_indexing"))]
+compile_error!("features `forbid_unsafe` and `unsafe_indexing` are mutually exclusive");
+#[cfg(all(feature = "forbid_unsafe", feature = "external_tables"))]
+compile_error!("`external_tables` needs unsafe code and can't be used with `forbid_unsafe`");
+
 const POLYNOMIAL_MAX_LENGTH: usize = 256;
 
 #[macro_use]
 mod macros;
-mod gf;
+pub mod gf;
 mod encoder;
+pub mod legacy;
+pub mod ccsds;
+pub mod presets;
+pub mod interleave;
+pub mod whitening;
+pub mod frame;
+#[cfg(feature = "cobs")]
+pub mod cobs;
 #[cfg(feature = "decoder")]
-mod decoder;
+pub mod circ;
+#[cfg(feature = "decoder")]
+pub mod product;
+mod iter;
+mod block;
+mod slim;
+#[cfg(feature = "alloc")]
+mod dyn_encoder;
 #[cfg(feature = "decoder")]
+mod decoder;
 mod buffer;
+#[cfg(all(feature = "decoder", feature = "alloc"))]
+mod berlekamp_welch;
+#[cfg(feature = "list_decoder")]
+mod list_decoder;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "alloc")]
+mod rs16;
+#[cfg(feature = "alloc")]
+mod fft16;
+mod rs4;
 
 pub use encoder::*;
+pub use iter::{EncodeIterExt, RsEncoded};
+pub use block::{BlockEncoder, Codewords};
+#[cfg(feature = "std")]
+pub use block::BlockLayout;
+pub use slim::SlimEncoder;
+#[cfg(feature = "alloc")]
+pub use dyn_encoder::DynEncoder;
+pub use gf::poly::{PolySlice, Polynom};
 #[cfg(feature = "decoder")]
-pub use decoder::{Decoder,DecoderError};
-#[cfg(feature = "decoder")]
+pub use decoder::{BlockStatus,CorrectionReport,Decoder,DecoderError,KeyEquationSolver,StreamDecoder};
 pub use buffer::Buffer;
+#[cfg(all(feature = "decoder", feature = "alloc"))]
+pub use berlekamp_welch::BerlekampWelchDecoder;
+#[cfg(feature = "list_decoder")]
+pub use list_decoder::ListDecoder;
+pub use rs4::{pack_nibbles, unpack_nibbles, Encoder4};
+#[cfg(feature = "decoder")]
+pub use rs4::Decoder4;
+#[cfg(feature = "alloc")]
+pub use rs16::Encoder16;
+#[cfg(feature = "alloc")]
+pub use fft16::FftEncoder16;
+#[cfg(all(feature = "decoder", feature = "alloc"))]
+pub use rs16::Decoder16;
+#[cfg(feature = "external_tables")]
+pub use gf::external::{init_tables, TablesError};
@@ -0,0 +1,380 @@
+//! Guruswami-Sudan list decoder (experimental).
+//!
+//! Received block is viewed as a set of points `(b, y)` of the generalized Reed-Solomon code
+//! described in `berlekamp_welch`. A bivariate polynomial `Q(x, y)` passing through every point
+//! with multiplicity `m` is interpolated, then every message polynomial `f` with `y - f(x)`
+//! dividing `Q` is extracted with the Roth-Ruckenstein algorithm. Each candidate within the
+//! decoding radius is returned, so the radius may exceed half of the ecc length.
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::berlekamp_welch::{column_multiplier, evaluation_point};
+use crate::buffer::Buffer;
+use crate::gf;
+use crate::gf::poly::Polynom;
+
+/// Bivariate polynomial, `q[k][j]` is a coefficient of `x^j * y^k`.
+type Bivariate = Vec<Vec<u8>>;
+
+/// Reed-Solomon list decoder based on the Guruswami-Sudan algorithm.
+///
+/// Interpolation solves a linear system of `n * m * (m + 1) / 2` equations,
+/// so it is only practical for short blocks and small multiplicities.
+#[derive(Debug, Copy, Clone)]
+pub struct ListDecoder {
+    ecc_len: usize,
+    multiplicity: usize,
+}
+
+impl ListDecoder {
+    /// Constructs a new `ListDecoder` with interpolation multiplicity 1 (Sudan algorithm).
+    pub fn new(ecc_len: usize) -> Self {
+        Self::with_multiplicity(ecc_len, 1)
+    }
+
+    /// Constructs a new `ListDecoder` with the given interpolation multiplicity.
+    /// Higher multiplicity increases decoding radius for the price of speed.
+    pub fn with_multiplicity(ecc_len: usize, multiplicity: usize) -> Self {
+        assert!(multiplicity > 0);
+        ListDecoder { ecc_len, multiplicity }
+    }
+
+    /// Returns the number of errors guaranteed to be list-decoded in a `msg_len`
+    /// bytes long block with `erasures` erased bytes, or 0 if such a block can't be decoded.
+    pub fn radius(&self, msg_len: usize, erasures: usize) -> usize {
+        let known = msg_len.saturating_sub(erasures);
+        let data_len = msg_len.saturating_sub(self.ecc_len);
+        if msg_len > 255 || data_len == 0 || known < data_len {
+            return 0;
+        }
+
+        let (_, degree) = self.parameters(known, data_len);
+        (known - 1).saturating_sub(degree / self.multiplicity)
+    }
+
+    /// Returns every codeword within `radius()` errors of the received block, closest first.
+    ///
+    /// The list is empty when no codeword is close enough, or when the block is longer than
+    /// 255 bytes or shorter than the ecc length.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::Encoder;
+    /// use reed_solomon::ListDecoder;
+    ///
+    /// let mut encoder = Encoder::<12>::new();
+    /// let decoder = ListDecoder::new(12);
+    ///
+    /// let encoded = encoder.encode(&[1, 2, 3]).unwrap();
+    /// let mut message = vec![1, 2, 3];
+    /// message.extend_from_slice(&encoded[..]);
+    ///
+    /// // 8 errors is beyond the unique decoding radius of 6
+    /// for i in 0..8 {
+    ///     message[i + 2] ^= 0x55;
+    /// }
+    ///
+    /// let candidates = decoder.decode(&message, None);
+    /// assert!(candidates.iter().any(|c| c.data() == [1, 2, 3]));
+    /// ```
+    pub fn decode(&self, msg: &[u8], erase_pos: Option<&[u8]>) -> Vec<Buffer> {
+        let n = msg.len();
+        if n > 255 || n < self.ecc_len {
+            return Vec::new();
+        }
+
+        let data_len = n - self.ecc_len;
+        let erase_pos = erase_pos.unwrap_or(&[]);
+
+        let points: Vec<(usize, u8, u8)> = (0..n)
+            .filter(|p| !erase_pos.contains(&(*p as u8)))
+            .map(|p| {
+                let b = evaluation_point(n, p);
+                (p, b, gf::div(msg[p], column_multiplier(n, self.ecc_len, b)))
+            })
+            .collect();
+
+        if data_len == 0 || points.len() < data_len {
+            return Vec::new();
+        }
+
+        let radius = self.radius(n, n - points.len());
+        let q = match self.interpolate(&points, data_len) {
+            Some(q) => q,
+            None => return Vec::new(),
+        };
+
+        let mut found = Vec::new();
+        find_roots(q, data_len, &mut Vec::new(), &mut found);
+
+        let mut candidates: Vec<(usize, Buffer)> = Vec::new();
+        for f in found {
+            // Coefficients are lowest power first
+            let mut f: Polynom = Polynom::from(&f[..]).reverse();
+            if f.is_empty() {
+                f.push(0);
+            }
+
+            let mut codeword: Polynom = Polynom::with_length(n);
+            for (p, x) in codeword.iter_mut().enumerate() {
+                let b = evaluation_point(n, p);
+                *x = gf::mul(column_multiplier(n, self.ecc_len, b), f.eval(b));
+            }
+
+            let errors = points.iter().filter(|(p, _, _)| codeword[*p] != msg[*p]).count();
+            if errors <= radius && !candidates.iter().any(|(_, c)| c[..] == codeword[..]) {
+                candidates.push((errors, Buffer::from_polynom(codeword, data_len)));
+            }
+        }
+
+        candidates.sort_by_key(|(errors, _)| *errors);
+        candidates.into_iter().map(|(_, c)| c).collect()
+    }
+
+    /// Returns y-degree weight and (1, weight)-weighted degree of the interpolation polynomial.
+    fn parameters(&self, known: usize, data_len: usize) -> (usize, usize) {
+        let weight = core::cmp::max(data_len, 2) - 1;
+        let m = self.multiplicity;
+        let constraints = known * m * (m + 1) / 2;
+
+        let mut degree = 0;
+        while monomials(weight, degree).len() <= constraints {
+            degree += 1;
+        }
+
+        (weight, degree)
+    }
+
+    /// Finds nonzero `Q(x, y)` of bounded weighted degree, which has a zero of multiplicity `m`
+    /// at every point.
+    fn interpolate(&self, points: &[(usize, u8, u8)], data_len: usize) -> Option<Bivariate> {
+        let (weight, degree) = self.parameters(points.len(), data_len);
+        let unknowns = monomials(weight, degree);
+        let m = self.multiplicity;
+
+        // Every Hasse derivative of order (a, b) with a + b < m vanishes at every point
+        let mut system = Vec::new();
+        for (_, x, y) in points {
+            for a in 0..m {
+                for b in 0..(m - a) {
+                    let row: Vec<u8> = unknowns.iter()
+                        .map(|(j, k)| {
+                            if binomial(*j, a) && binomial(*k, b) {
+                                gf::mul(power(*x, j - a), power(*y, k - b))
+                            } else {
+                                0
+                            }
+                        })
+                        .collect();
+                    system.push(row);
+                }
+            }
+        }
+
+        let solution = null_vector(&mut system, unknowns.len())?;
+
+        let y_degree = degree / weight;
+        let mut q = vec![vec![0; degree + 1]; y_degree + 1];
+        for ((j, k), c) in unknowns.iter().zip(solution) {
+            q[*k][*j] = c;
+        }
+        Some(q)
+    }
+}
+
+/// Monomials `x^j * y^k` with `j + weight * k <= degree`.
+fn monomials(weight: usize, degree: usize) -> Vec<(usize, usize)> {
+    (0..=(degree / weight))
+        .flat_map(|k| (0..=(degree - weight * k)).map(move |j| (j, k)))
+        .collect()
+}
+
+/// `x^n`, unlike `gf::pow` it is correct for zero `x`.
+fn power(x: u8, n: usize) -> u8 {
+    match (x, n) {
+        (_, 0) => 1,
+        (0, _) => 0,
+        _ => gf::pow(x, n as i32),
+    }
+}
+
+/// Binomial coefficient `C(n, k)` in characteristic 2 (Lucas theorem).
+fn binomial(n: usize, k: usize) -> bool {
+    k <= n && n & k == k
+}
+
+/// Finds a nonzero solution of a homogeneous linear system with `cols` unknowns.
+fn null_vector(system: &mut [Vec<u8>], cols: usize) -> Option<Vec<u8>> {
+    let mut pivots = Vec::new();
+    let mut free = None;
+
+    for col in 0..cols {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..system.len()).find(|r| system[*r][col] != 0) else {
+            free.get_or_insert(col);
+            continue;
+        };
+        system.swap(rank, pivot);
+
+        let inv = gf::inverse(system[rank][col]);
+        for x in system[rank].iter_mut() {
+            *x = gf::mul(*x, inv);
+        }
+
+        let pivot_row = system[rank].clone();
+        for (r, row) in system.iter_mut().enumerate() {
+            let coef = row[col];
+            if r != rank && coef != 0 {
+                for (x, p) in row[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                    *x ^= gf::mul(*p, coef);
+                }
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    // Set the first free unknown to 1, the rest of free unknowns to 0
+    let free = free?;
+    let mut solution = vec![0; cols];
+    solution[free] = 1;
+    for (row, col) in pivots.iter().enumerate() {
+        if *col < free {
+            solution[*col] = system[row][free];
+        }
+    }
+
+    Some(solution)
+}
+
+/// Roth-Ruckenstein search of all `f` with `deg f < k` such that `y - f(x)` divides `Q(x, y)`.
+/// `prefix` holds already found lowest coefficients of `f`.
+fn find_roots(mut q: Bivariate, k: usize, prefix: &mut Vec<u8>, found: &mut Vec<Vec<u8>>) {
+    // Divide out the highest power of x
+    let shift = q.iter()
+        .filter_map(|row| row.iter().position(|c| *c != 0))
+        .min();
+    let Some(shift) = shift else {
+        return;
+    };
+    for row in q.iter_mut() {
+        row.drain(..shift);
+    }
+
+    for root in 0..=255u8 {
+        // Q(0, root)
+        let value = q.iter()
+            .rev()
+            .fold(0, |acc, row| gf::mul(acc, root) ^ row.first().copied().unwrap_or(0));
+        if value != 0 {
+            continue;
+        }
+
+        prefix.push(root);
+        if prefix.len() == k {
+            found.push(prefix.clone());
+        } else {
+            find_roots(substitute(&q, root), k, prefix, found);
+        }
+        prefix.pop();
+    }
+}
+
+/// Returns `Q(x, x * y + root)`.
+fn substitute(q: &Bivariate, root: u8) -> Bivariate {
+    let width = q.iter().map(|row| row.len()).max().unwrap_or(0) + q.len();
+    let mut result = vec![vec![0; width]; q.len()];
+
+    for (k, row) in q.iter().enumerate() {
+        for b in (0..=k).filter(|b| binomial(k, *b)) {
+            let factor = power(root, k - b);
+            for (j, c) in row.iter().enumerate() {
+                result[b][j + b] ^= gf::mul(*c, factor);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decoder, Encoder};
+
+    #[test]
+    fn null_vector() {
+        crate::gf::init_test_tables();
+        let mut system = vec![vec![1, 2, 3], vec![2, 4, 6]];
+        let x = super::null_vector(&mut system, 3).unwrap();
+        assert!(x.iter().any(|c| *c != 0));
+        for row in [[1, 2, 3], [2, 4, 6]] {
+            let sum = row.iter().zip(x.iter()).fold(0, |acc, (a, b)| acc ^ gf::mul(*a, *b));
+            assert_eq!(0, sum);
+        }
+    }
+
+    #[test]
+    fn radius() {
+        crate::gf::init_test_tables();
+        assert_eq!(8, ListDecoder::new(12).radius(15, 0));
+        assert_eq!(8, ListDecoder::with_multiplicity(12, 2).radius(15, 0));
+        assert_eq!(1, ListDecoder::new(2).radius(12, 0));
+        assert_eq!(0, ListDecoder::new(12).radius(10, 0));
+        assert_eq!(0, ListDecoder::new(12).radius(15, 20));
+        assert_eq!(0, ListDecoder::new(12).radius(256, 0));
+    }
+
+    #[test]
+    fn decode() {
+        crate::gf::init_test_tables();
+        let msg = [0x12, 0x00, 0x56];
+        let encoded = Encoder::<12>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        for m in 1..3 {
+            let decoder = ListDecoder::with_multiplicity(12, m);
+            assert_eq!(orig, decoder.decode(&orig, None)[0][..]);
+
+            for errors in 0..=8 {
+                let mut corrupted = orig.clone();
+                for i in 0..errors {
+                    corrupted[14 - i] ^= 0x0f ^ i as u8;
+                }
+
+                let candidates = decoder.decode(&corrupted, None);
+                assert!(candidates.iter().any(|c| c[..] == orig[..]));
+                if errors > 6 {
+                    assert!(Decoder::new(12).correct(&corrupted, None).is_err());
+                }
+            }
+        }
+
+        let decoder = ListDecoder::new(12);
+        assert!(decoder.decode(&orig[..10], None).is_empty());
+        assert!(decoder.decode(&[0; 256], None).is_empty());
+    }
+
+    #[test]
+    fn decode_erasures() {
+        crate::gf::init_test_tables();
+        let msg = [1, 2, 3, 4];
+        let encoded = Encoder::<10>::new().encode(&msg[..]).unwrap();
+        let mut orig = Vec::new();
+        orig.extend_from_slice(&msg[..]);
+        orig.extend_from_slice(&encoded[..]);
+
+        let mut corrupted = orig.clone();
+        let erase_pos = [0, 5];
+        for x in erase_pos.iter() {
+            corrupted[*x as usize] = 0;
+        }
+        corrupted[3] = 0xff;
+        corrupted[9] = 0xff;
+
+        let candidates = ListDecoder::new(10).decode(&corrupted, Some(&erase_pos));
+        assert_eq!(orig, candidates[0][..]);
+    }
+}
@@ -1,20 +1,37 @@
+/// Constructs a [`Polynom`](crate::Polynom) from its coefficients, highest power first, like
+/// `vec!`. The field is inferred, so a type annotation is usually needed.
+///
+/// It expands to [`Polynom::from_array`](crate::Polynom::from_array) and works in `const` items,
+/// where more than 256 coefficients fail to compile.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{polynom, Polynom};
+///
+/// // x^2 + 3x + 2
+/// const GENERATOR: Polynom = polynom![1, 3, 2];
+/// let zero: Polynom = polynom![0; 4];
+///
+/// assert_eq!([1, 3, 2], *GENERATOR);
+/// assert!(zero.is_zero());
+/// ```
+#[macro_export]
 macro_rules! polynom {
-    [$value:expr; $count:expr] => {{
-        let array = [$value; $count];
-        $crate::gf::poly::Polynom::from(&array[..])
-    }}; 
+    [$value:expr; $count:expr] => {
+        $crate::gf::poly::Polynom::from_array([$value; $count])
+    };
 
-    [$( $value:expr ),* ] => {{
-        let array = [$($value, )*];
-        $crate::gf::poly::Polynom::from(&array[..])
-    }};
+    [$( $value:expr ),* $(,)?] => {
+        $crate::gf::poly::Polynom::from_array([$($value, )*])
+    };
 }
 
+#[cfg(not(feature = "forbid_unsafe"))]
 macro_rules! uncheck {
     ($array:ident[$index:expr]) => {
         if cfg!(feature = "unsafe_indexing") {
             unsafe {
-                *$array.get_unchecked($index)
+                *$array.as_ptr().add($index)
             }
         } else {
             $array[$index]
@@ -22,6 +39,14 @@ macro_rules! uncheck {
     }
 }
 
+#[cfg(feature = "forbid_unsafe")]
+macro_rules! uncheck {
+    ($array:ident[$index:expr]) => {
+        $array[$index]
+    }
+}
+
+#[cfg(not(feature = "forbid_unsafe"))]
 macro_rules! uncheck_mut {
     ($array:ident[$index:expr]) => {
         * if cfg!(feature = "unsafe_indexing") {
@@ -33,3 +58,37 @@ macro_rules! uncheck_mut {
         }
     }
 }
+
+#[cfg(feature = "forbid_unsafe")]
+macro_rules! uncheck_mut {
+    ($array:ident[$index:expr]) => {
+        $array[$index]
+    }
+}
+
+/// Unwraps a result which can't be an error, checked only with `forbid_unsafe`
+#[cfg(not(feature = "forbid_unsafe"))]
+macro_rules! unwrap_unchecked {
+    ($result:expr) => {
+        unsafe { $result.unwrap_unchecked() }
+    }
+}
+
+#[cfg(feature = "forbid_unsafe")]
+macro_rules! unwrap_unchecked {
+    ($result:expr) => {
+        $result.unwrap()
+    }
+}
+
+/// Defines a function reading the default field tables, which is `const` unless the tables
+/// are registered at runtime with `external_tables`
+macro_rules! table_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        #[cfg(not(feature = "external_tables"))]
+        $(#[$attr])* $vis const fn $($rest)*
+
+        #[cfg(feature = "external_tables")]
+        $(#[$attr])* $vis fn $($rest)*
+    };
+}
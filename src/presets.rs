@@ -0,0 +1,665 @@
+//! Parameters of Reed-Solomon codes used by common standards.
+//!
+//! Every submodule gives the ecc length, the roots of the generator polynomial and the
+//! shortening of one profile, along with an encoder alias and constructors. Shortened codes
+//! are the full 255 byte code with leading data bytes fixed to zero and not transmitted, which
+//! the encoder and decoder handle as a shorter message.
+//!
+//! | Profile | Code          | Field | `fcr` | `prim` |
+//! |---------|---------------|-------|-------|--------|
+//! | [`qr`]    | RS(n, n - e)  | 0x11d | 0     | 1      |
+//! | [`ccsds`] | RS(255, 223)  | 0x187 | 112   | 11     |
+//! | [`dvb`]   | RS(204, 188)  | 0x11d | 0     | 1      |
+//! | [`atsc`]  | RS(207, 187)  | 0x11d | 0     | 1      |
+//! | [`g709`]  | RS(255, 239)  | 0x11d | 0     | 1      |
+//! | `dvd`     | RS(182, 172) x RS(208, 192) | 0x11d | 0 | 1 |
+
+/// QR code (ISO/IEC 18004), the ecc length of a block depends on the version and EC level, see
+/// [`qr::BlockStructure`] for the split of a whole symbol into blocks
+pub mod qr {
+    use crate::encoder::{field_generator_poly, lfsr_run, EncoderError};
+    use crate::gf::{GaloisField, Gf2p8};
+    #[cfg(feature = "decoder")]
+    use crate::Decoder;
+
+    /// Encoder of a QR block with `ECC_LEN` ecc codewords
+    pub type Encoder<const ECC_LEN: usize> = crate::Encoder<ECC_LEN>;
+
+    /// Constructs an encoder of a QR block with `ECC_LEN` ecc codewords
+    pub fn encoder<const ECC_LEN: usize>() -> Encoder<ECC_LEN> {
+        Encoder::new()
+    }
+
+    /// Constructs a decoder of QR blocks with `ecc_len` ecc codewords
+    #[cfg(feature = "decoder")]
+    pub fn decoder(ecc_len: usize) -> Decoder {
+        Decoder::new(ecc_len)
+    }
+
+    /// Error correction level of a symbol
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum EcLevel {
+        /// Recovers about 7% of the codewords
+        L,
+        /// Recovers about 15% of the codewords
+        M,
+        /// Recovers about 25% of the codewords
+        Q,
+        /// Recovers about 30% of the codewords
+        H,
+    }
+
+    /// Ecc codewords of every block, by EC level and version
+    const ECC_CODEWORDS_PER_BLOCK: [[u8; 40]; 4] = [
+        [7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28,
+         28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+        [10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26,
+         26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28],
+        [13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30,
+         28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+        [17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28,
+         30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30],
+    ];
+
+    /// Number of blocks, by EC level and version
+    const BLOCKS: [[u8; 40]; 4] = [
+        [1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8,
+         8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25],
+        [1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16,
+         17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49],
+        [1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20,
+         23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68],
+        [1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25,
+         25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81],
+    ];
+
+    /// Largest ecc length of a block
+    const MAX_ECC_LEN: usize = 30;
+
+    /// Split of a symbol's codewords into blocks: `short_blocks` blocks with `short_data_len` data
+    /// codewords followed by `long_blocks` blocks with one more, all with `ecc_len` ecc codewords
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::presets::qr::{BlockStructure, EcLevel};
+    ///
+    /// let blocks = BlockStructure::new(1, EcLevel::M);
+    /// let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+    /// let mut codewords = [0; 26];
+    /// blocks.encode(&data, &mut codewords).unwrap();
+    /// assert_eq!([196, 35, 39, 119, 235, 215, 231, 226, 93, 23], codewords[16..]);
+    /// ```
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct BlockStructure {
+        /// Ecc codewords of every block
+        pub ecc_len: usize,
+        /// Blocks of the first group
+        pub short_blocks: usize,
+        /// Data codewords of a block of the first group
+        pub short_data_len: usize,
+        /// Blocks of the second group, holding `short_data_len + 1` data codewords each
+        pub long_blocks: usize,
+    }
+
+    impl BlockStructure {
+        /// Looks up the block structure of a symbol
+        ///
+        /// # Panics
+        /// If `version` isn't in `1..=40`.
+        pub fn new(version: u8, level: EcLevel) -> Self {
+            assert!((1..=40).contains(&version), "QR version must be in 1..=40");
+            let v = version as usize;
+
+            // Modules left for codewords once function patterns and format/version info are
+            // taken out, every codeword taking 8 and the remainder bits being dropped
+            let mut modules = (16 * v + 128) * v + 64;
+            if v >= 2 {
+                let align = v / 7 + 2;
+                modules -= (25 * align - 10) * align - 55;
+                if v >= 7 {
+                    modules -= 36;
+                }
+            }
+            let total = modules / 8;
+
+            let ecc_len = ECC_CODEWORDS_PER_BLOCK[level as usize][v - 1] as usize;
+            let blocks = BLOCKS[level as usize][v - 1] as usize;
+            let long_blocks = total % blocks;
+            BlockStructure {
+                ecc_len,
+                short_blocks: blocks - long_blocks,
+                short_data_len: total / blocks - ecc_len,
+                long_blocks,
+            }
+        }
+
+        /// Number of blocks
+        pub fn blocks(&self) -> usize {
+            self.short_blocks + self.long_blocks
+        }
+
+        /// Data codewords of the symbol
+        pub fn data_len(&self) -> usize {
+            self.blocks() * self.short_data_len + self.long_blocks
+        }
+
+        /// Data and ecc codewords of the symbol
+        pub fn codeword_len(&self) -> usize {
+            self.data_len() + self.blocks() * self.ecc_len
+        }
+
+        /// Splits `data` into blocks, encodes every block and writes the interleaved data
+        /// codewords followed by the interleaved ecc codewords to `out`: the first codeword of
+        /// every block, then the second of every block and so on, with the extra data codewords
+        /// of the long blocks last.
+        ///
+        /// Errors if `data` isn't [`data_len`](Self::data_len) or `out` isn't
+        /// [`codeword_len`](Self::codeword_len) long.
+        pub fn encode(&self, data: &[u8], out: &mut [u8]) -> Result<(), EncoderError> {
+            if data.len() != self.data_len() {
+                return Err(EncoderError::InvalidBlockLength);
+            }
+            if out.len() != self.codeword_len() {
+                return Err(EncoderError::BufferLenMismatch);
+            }
+
+            let generator = field_generator_poly::<Gf2p8>(self.ecc_len, 0, 1);
+            let mut lgenerator = [0; MAX_ECC_LEN];
+            for (l, g) in lgenerator.iter_mut().zip(generator[1..].iter()) {
+                *l = if *g == 0 { 0 } else { <Gf2p8>::log(*g) as u8 };
+            }
+
+            let blocks = self.blocks();
+            let (data_out, ecc_out) = out.split_at_mut(data.len());
+            let mut rest = data;
+            for b in 0..blocks {
+                let long = b >= self.short_blocks;
+                let (block, tail) = rest.split_at(self.short_data_len + long as usize);
+                rest = tail;
+
+                for (i, x) in block[..self.short_data_len].iter().enumerate() {
+                    data_out[i * blocks + b] = *x;
+                }
+                if long {
+                    data_out[self.short_data_len * blocks + b - self.short_blocks] = block[self.short_data_len];
+                }
+
+                let mut ecc = [0; MAX_ECC_LEN];
+                let ecc = &mut ecc[..self.ecc_len];
+                lfsr_run::<Gf2p8>(&generator[1..], &lgenerator[..self.ecc_len], ecc, block);
+                for (i, x) in ecc.iter().enumerate() {
+                    ecc_out[i * blocks + b] = *x;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// CCSDS telemetry RS(255, 223), see also [`crate::ccsds`] for the dual basis conversion
+pub mod ccsds {
+    #[cfg(feature = "decoder")]
+    use crate::ccsds::{fcr, Field, PRIM};
+    #[cfg(feature = "decoder")]
+    use crate::Decoder;
+
+    /// Ecc bytes of a codeblock
+    pub const ECC_LEN: usize = 32;
+    /// Data bytes of an unshortened codeblock
+    pub const DATA_LEN: usize = 223;
+
+    /// Encoder taking and producing dual basis symbols
+    pub type Encoder = crate::ccsds::Encoder<ECC_LEN>;
+
+    /// Codec of interleaved codeblocks
+    pub type Codec = crate::ccsds::Codec<ECC_LEN>;
+
+    /// Constructs an encoder taking and producing dual basis symbols
+    pub fn encoder() -> Encoder {
+        Encoder::new()
+    }
+
+    /// Constructs a decoder of conventional basis codeblocks, convert the received symbols with
+    /// [`from_dual_basis`](crate::ccsds::from_dual_basis) first
+    #[cfg(feature = "decoder")]
+    pub fn decoder() -> Decoder<Field> {
+        Decoder::<Field>::with_field(ECC_LEN).fcr(fcr(ECC_LEN)).prim(PRIM)
+    }
+}
+
+/// DVB (ETSI EN 300 468 / EN 300 744) RS(204, 188), shortened from RS(255, 239) by 51 bytes,
+/// protecting 188 byte MPEG transport stream packets, and the convolutional interleaver of
+/// depth 12 completing the outer coding
+pub mod dvb {
+    use crate::interleave::{ConvolutionalDeinterleaver, ConvolutionalInterleaver};
+    #[cfg(feature = "decoder")]
+    use crate::Decoder;
+
+    /// Ecc bytes of a packet
+    pub const ECC_LEN: usize = 16;
+    /// Data bytes of a packet
+    pub const DATA_LEN: usize = 188;
+    /// Bytes of an encoded packet
+    pub const CODEWORD_LEN: usize = DATA_LEN + ECC_LEN;
+
+    /// Encoder of transport stream packets
+    pub type Encoder = crate::Encoder<ECC_LEN>;
+
+    /// Constructs an encoder of transport stream packets
+    pub fn encoder() -> Encoder {
+        Encoder::new()
+    }
+
+    /// Constructs a decoder of encoded transport stream packets
+    #[cfg(feature = "decoder")]
+    pub fn decoder() -> Decoder {
+        Decoder::new(ECC_LEN)
+    }
+
+    /// Codec of whole transport stream packets, the 51 bytes of shortening are implicit
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::presets::dvb::Dvb204_188;
+    ///
+    /// let codec = Dvb204_188::new();
+    /// let mut packet = [0xff; 188];
+    /// packet[0] = 0x47;
+    ///
+    /// let mut encoded = codec.encode(&packet);
+    /// encoded[10] = 0;
+    /// encoded[200] = 0;
+    /// assert_eq!(packet, codec.decode(&encoded).unwrap());
+    /// ```
+    #[derive(Debug)]
+    pub struct Dvb204_188 {
+        encoder: Encoder,
+        #[cfg(feature = "decoder")]
+        decoder: Decoder,
+    }
+
+    impl Dvb204_188 {
+        /// Constructs a new codec
+        pub fn new() -> Self {
+            Dvb204_188 {
+                encoder: encoder(),
+                #[cfg(feature = "decoder")]
+                decoder: decoder(),
+            }
+        }
+
+        /// Encodes a 188 byte packet, sync byte included, into 204 bytes
+        pub fn encode(&self, packet: &[u8; DATA_LEN]) -> [u8; CODEWORD_LEN] {
+            let mut codeword = [0; CODEWORD_LEN];
+            let (data, ecc) = codeword.split_at_mut(DATA_LEN);
+            data.copy_from_slice(packet);
+            unwrap_unchecked!(self.encoder.encode_to_slice(packet, ecc));
+            codeword
+        }
+
+        /// Corrects up to 8 damaged bytes of an encoded packet and returns the packet
+        #[cfg(feature = "decoder")]
+        pub fn decode(&self, codeword: &[u8; CODEWORD_LEN]) -> Result<[u8; DATA_LEN], crate::DecoderError> {
+            let mut codeword = *codeword;
+            self.correct_in_place(&mut codeword)?;
+            let mut packet = [0; DATA_LEN];
+            packet.copy_from_slice(&codeword[..DATA_LEN]);
+            Ok(packet)
+        }
+
+        /// Corrects an encoded packet in place and returns the number of corrected bytes
+        #[cfg(feature = "decoder")]
+        pub fn correct_in_place(&self, codeword: &mut [u8; CODEWORD_LEN]) -> Result<usize, crate::DecoderError> {
+            self.decoder.correct_in_place(codeword, None)
+        }
+    }
+
+    impl Default for Dvb204_188 {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Branches of the convolutional interleaver
+    pub const INTERLEAVE_DEPTH: usize = 12;
+    /// Bytes of a delay line cell of the convolutional interleaver
+    pub const INTERLEAVE_CELL: usize = 17;
+    /// Packets between [`OuterEncoder`] and [`OuterDecoder`]
+    pub const DELAY_PACKETS: usize = Interleaver::DELAY / CODEWORD_LEN;
+
+    /// Convolutional interleaver following the RS encoder
+    pub type Interleaver = ConvolutionalInterleaver<INTERLEAVE_DEPTH, INTERLEAVE_CELL>;
+    /// Convolutional deinterleaver preceding the RS decoder
+    pub type Deinterleaver = ConvolutionalDeinterleaver<INTERLEAVE_DEPTH, INTERLEAVE_CELL>;
+
+    /// Outer coding of the transmitter: RS(204, 188) followed by the convolutional interleaver.
+    /// Sync bytes always go through the undelayed branch 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::presets::dvb::{OuterDecoder, OuterEncoder, DELAY_PACKETS};
+    ///
+    /// let mut encoder = OuterEncoder::new();
+    /// let mut decoder = OuterDecoder::new();
+    ///
+    /// for n in 0..DELAY_PACKETS + 5 {
+    ///     let mut channel = encoder.encode(&[n as u8; 188]);
+    ///     // Burst of 48 bytes, 4 bytes in each of 12 packets after deinterleaving
+    ///     if n == 3 {
+    ///         channel[100..148].fill(0);
+    ///     }
+    ///     let packet = decoder.decode(&channel).unwrap();
+    ///     if n >= DELAY_PACKETS {
+    ///         assert_eq!([(n - DELAY_PACKETS) as u8; 188], packet);
+    ///     }
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub struct OuterEncoder {
+        codec: Dvb204_188,
+        interleaver: Interleaver,
+    }
+
+    impl OuterEncoder {
+        /// Constructs an encoder with empty delay lines
+        pub fn new() -> Self {
+            OuterEncoder {
+                codec: Dvb204_188::new(),
+                interleaver: Interleaver::new(),
+            }
+        }
+
+        /// Encodes and interleaves the next packet
+        pub fn encode(&mut self, packet: &[u8; DATA_LEN]) -> [u8; CODEWORD_LEN] {
+            let mut encoded = self.codec.encode(packet);
+            for x in encoded.iter_mut() {
+                *x = self.interleaver.push(*x);
+            }
+            encoded
+        }
+    }
+
+    impl Default for OuterEncoder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Outer decoding of the receiver: the convolutional deinterleaver followed by RS(204, 188)
+    #[cfg(feature = "decoder")]
+    #[derive(Debug)]
+    pub struct OuterDecoder {
+        codec: Dvb204_188,
+        deinterleaver: Deinterleaver,
+    }
+
+    #[cfg(feature = "decoder")]
+    impl OuterDecoder {
+        /// Constructs a decoder with empty delay lines, the first [`DELAY_PACKETS`] packets are
+        /// zero
+        pub fn new() -> Self {
+            OuterDecoder {
+                codec: Dvb204_188::new(),
+                deinterleaver: Deinterleaver::new(),
+            }
+        }
+
+        /// Deinterleaves the next received packet and returns the corrected packet
+        /// [`DELAY_PACKETS`] behind. Its errors are passed on, not the received packet.
+        pub fn decode(&mut self, channel: &[u8; CODEWORD_LEN]) -> Result<[u8; DATA_LEN], crate::DecoderError> {
+            let mut codeword = [0; CODEWORD_LEN];
+            for (x, y) in codeword.iter_mut().zip(channel.iter()) {
+                *x = self.deinterleaver.push(*y);
+            }
+            self.codec.decode(&codeword)
+        }
+    }
+
+    #[cfg(feature = "decoder")]
+    impl Default for OuterDecoder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+/// ATSC (A/53 Part 2) RS(207, 187), shortened from RS(255, 235) by 48 bytes
+pub mod atsc {
+    #[cfg(feature = "decoder")]
+    use crate::Decoder;
+
+    /// Ecc bytes of a segment
+    pub const ECC_LEN: usize = 20;
+    /// Data bytes of a segment
+    pub const DATA_LEN: usize = 187;
+    /// Bytes of an encoded segment
+    pub const CODEWORD_LEN: usize = DATA_LEN + ECC_LEN;
+
+    /// Encoder of data segments
+    pub type Encoder = crate::Encoder<ECC_LEN>;
+
+    /// Constructs an encoder of data segments
+    pub fn encoder() -> Encoder {
+        Encoder::new()
+    }
+
+    /// Constructs a decoder of encoded data segments
+    #[cfg(feature = "decoder")]
+    pub fn decoder() -> Decoder {
+        Decoder::new(ECC_LEN)
+    }
+}
+
+/// ITU-T G.709 OTN forward error correction RS(255, 239)
+pub mod g709 {
+    #[cfg(feature = "decoder")]
+    use crate::Decoder;
+
+    /// Ecc bytes of a codeword
+    pub const ECC_LEN: usize = 16;
+    /// Data bytes of a codeword
+    pub const DATA_LEN: usize = 239;
+    /// Bytes of a codeword
+    pub const CODEWORD_LEN: usize = DATA_LEN + ECC_LEN;
+
+    /// Encoder of OTU row codewords
+    pub type Encoder = crate::Encoder<ECC_LEN>;
+
+    /// Constructs an encoder of OTU row codewords
+    pub fn encoder() -> Encoder {
+        Encoder::new()
+    }
+
+    /// Constructs a decoder of OTU row codewords
+    #[cfg(feature = "decoder")]
+    pub fn decoder() -> Decoder {
+        Decoder::new(ECC_LEN)
+    }
+}
+
+/// DVD ECC block (ECMA-267): 16 sectors as 192 rows of 172 bytes, rows protected by the inner
+/// PI RS(182, 172) code and columns by the outer PO RS(208, 192) code
+#[cfg(feature = "decoder")]
+pub mod dvd {
+    /// Data rows of an ECC block
+    pub const DATA_ROWS: usize = 192;
+    /// Data bytes of a row
+    pub const DATA_COLS: usize = 172;
+    /// PI ecc bytes of a row
+    pub const PI_LEN: usize = 10;
+    /// PO ecc bytes of a column
+    pub const PO_LEN: usize = 16;
+    /// Bytes of an encoded ECC block
+    pub const BLOCK_LEN: usize = (DATA_ROWS + PO_LEN) * (DATA_COLS + PI_LEN);
+
+    use crate::product::RsCode;
+
+    /// Product code of an ECC block
+    pub type ProductCode = crate::product::ProductCode<RsCode<PI_LEN>, RsCode<PO_LEN>>;
+
+    /// Constructs the product code of an ECC block
+    pub fn product_code() -> ProductCode {
+        ProductCode::new(RsCode::new(), RsCode::new(), DATA_ROWS, DATA_COLS)
+    }
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    use super::*;
+
+    fn roundtrip<const ECC_LEN: usize>(encoder: crate::Encoder<ECC_LEN>, decoder: crate::Decoder, data_len: usize) {
+        let data: std::vec::Vec<u8> = (0..data_len).map(|i| (i * 31 + 7) as u8).collect();
+        let mut codeword = encoder.encode_buffer(&data).unwrap();
+        assert_eq!(data_len + ECC_LEN, codeword.len());
+
+        for i in 0..ECC_LEN / 2 {
+            codeword[i * 5] ^= 0x5a;
+        }
+        assert_eq!(&data[..], decoder.correct(&codeword, None).unwrap().data());
+    }
+
+    #[test]
+    fn profiles() {
+        crate::gf::init_test_tables();
+        // Well-known generator of the DVB code, shared by G.709
+        assert_eq!(
+            [59, 13, 104, 189, 68, 209, 30, 8, 163, 65, 41, 229, 98, 50, 36, 59],
+            dvb::encoder().generator()
+        );
+
+        roundtrip(qr::encoder::<26>(), qr::decoder(26), 44);
+        roundtrip(dvb::encoder(), dvb::decoder(), dvb::DATA_LEN);
+        roundtrip(atsc::encoder(), atsc::decoder(), atsc::DATA_LEN);
+        roundtrip(g709::encoder(), g709::decoder(), g709::DATA_LEN);
+        assert_eq!(204, dvb::CODEWORD_LEN);
+        assert_eq!(207, atsc::CODEWORD_LEN);
+        assert_eq!(255, g709::CODEWORD_LEN);
+
+        let data = [3; ccsds::DATA_LEN];
+        let parity = ccsds::encoder().encode(&data).unwrap();
+        let mut block = std::vec::Vec::from(&data[..]);
+        block.extend_from_slice(&parity);
+        let block: std::vec::Vec<u8> = block.iter().map(|x| crate::ccsds::from_dual_basis(*x)).collect();
+        assert!(!ccsds::decoder().is_corrupted(&block));
+    }
+
+    #[test]
+    fn qr_blocks() {
+        crate::gf::init_test_tables();
+        use qr::{BlockStructure, EcLevel};
+
+        // Data codeword capacities of ISO/IEC 18004 table 7
+        for (version, level, data_len, codeword_len) in [
+            (1, EcLevel::L, 19, 26),
+            (1, EcLevel::H, 9, 26),
+            (10, EcLevel::M, 216, 346),
+            (10, EcLevel::H, 122, 346),
+            (20, EcLevel::Q, 485, 1085),
+            (40, EcLevel::L, 2956, 3706),
+            (40, EcLevel::M, 2334, 3706),
+            (40, EcLevel::H, 1276, 3706),
+        ] {
+            let blocks = BlockStructure::new(version, level);
+            assert_eq!(data_len, blocks.data_len());
+            assert_eq!(codeword_len, blocks.codeword_len());
+        }
+
+        let blocks = BlockStructure::new(5, EcLevel::Q);
+        assert_eq!(BlockStructure { ecc_len: 18, short_blocks: 2, short_data_len: 15, long_blocks: 2 }, blocks);
+
+        let data: std::vec::Vec<u8> = (0..62).collect();
+        let mut out = [0; 134];
+        blocks.encode(&data, &mut out).unwrap();
+        assert_eq!([0, 15, 30, 46, 1, 16, 31, 47], out[..8]);
+        assert_eq!([14, 29, 44, 60, 45, 61], out[56..62]);
+
+        let mut encoder = qr::encoder::<18>();
+        let starts = [0, 15, 30, 46];
+        for (b, start) in starts.iter().enumerate() {
+            let len = if b < 2 { 15 } else { 16 };
+            let ecc = encoder.encode(&data[*start..start + len]).unwrap();
+            for (i, x) in ecc.iter().enumerate() {
+                assert_eq!(*x, out[62 + i * 4 + b]);
+            }
+        }
+
+        assert_eq!(Err(crate::EncoderError::InvalidBlockLength), blocks.encode(&data[1..], &mut out));
+        assert_eq!(Err(crate::EncoderError::BufferLenMismatch), blocks.encode(&data, &mut out[1..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn qr_version() {
+        qr::BlockStructure::new(41, qr::EcLevel::L);
+    }
+
+    #[test]
+    fn dvd() {
+        crate::gf::init_test_tables();
+        let code = dvd::product_code();
+        assert_eq!((208, 182, dvd::BLOCK_LEN), (code.rows(), code.cols(), code.block_len()));
+
+        let mut block = std::vec![0; dvd::BLOCK_LEN];
+        for (i, x) in block.iter_mut().enumerate() {
+            if i / code.cols() < dvd::DATA_ROWS && i % code.cols() < dvd::DATA_COLS {
+                *x = (i * 13) as u8;
+            }
+        }
+        code.encode(&mut block).unwrap();
+
+        // A burst of 6 whole rows
+        let mut corrupted = block.clone();
+        corrupted[20 * 182..26 * 182].fill(0xaa);
+        assert!(code.decode(&mut corrupted).is_ok());
+        assert_eq!(block, corrupted);
+    }
+
+    #[test]
+    fn dvb204_188() {
+        crate::gf::init_test_tables();
+        use crate::gf::Gf2p8;
+        use dvb::{Dvb204_188, CODEWORD_LEN, DATA_LEN};
+
+        let codec = Dvb204_188::new();
+        assert_eq!([0; CODEWORD_LEN], codec.encode(&[0; DATA_LEN]));
+
+        // Null packet: PID 0x1fff, payload only, 0xff stuffing
+        let mut packet = [0xff; DATA_LEN];
+        packet[..4].copy_from_slice(&[0x47, 0x1f, 0xff, 0x10]);
+        let encoded = codec.encode(&packet);
+        assert_eq!(packet[..], encoded[..DATA_LEN]);
+
+        // The mother RS(255, 239) codeword, with the 51 shortened bytes put back in front as
+        // zeros, must vanish at all 16 roots 2^0..2^15
+        let mut mother = [0; 255];
+        mother[51..].copy_from_slice(&encoded);
+        for i in 0..16 {
+            let root = <Gf2p8>::pow(2, i);
+            assert_eq!(0, mother.iter().fold(0, |y, x| <Gf2p8>::mul(y, root) ^ x));
+        }
+
+        let mut corrupted = encoded;
+        for i in 0..8 {
+            corrupted[i * 25 + 3] ^= 0xa5;
+        }
+        assert_eq!(packet, codec.decode(&corrupted).unwrap());
+        assert_eq!(8, codec.correct_in_place(&mut corrupted).unwrap());
+        assert_eq!(encoded, corrupted);
+
+        corrupted[0] ^= 1;
+        for i in 0..8 {
+            corrupted[i * 25 + 4] ^= 0x11;
+        }
+        assert!(codec.decode(&corrupted).is_err());
+
+        // The interleaver delays by 11 whole packets, the sync byte stays in place
+        assert_eq!(11, dvb::DELAY_PACKETS);
+        let mut encoder = dvb::OuterEncoder::new();
+        for _ in 0..3 {
+            assert_eq!(0x47, encoder.encode(&packet)[0]);
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! Two-dimensional product codes: a rectangular block whose rows are codewords of a row code
+//! and whose columns are codewords of a column code, such as the DVD ECC block
+//! ([`presets::dvd`](crate::presets::dvd)).
+//!
+//! A block of `rows() x cols()` bytes is stored row by row. Data occupies the top left
+//! `data_rows x data_cols` corner, every data row is followed by its row parity and the last
+//! rows hold the column parity of all columns, the row parity columns included.
+//!
+//! Decoding alternates between rows and columns. Rows which can't be corrected are passed to the
+//! column decoder as erasures and the other way round, so error patterns beyond the reach of
+//! either code alone are usually cleaned up within a few passes.
+use crate::gf::{GaloisField, Gf2p8};
+use crate::{Decoder, DecoderError, Encoder, EncoderError};
+
+/// Default number of alternating row and column passes
+const PASSES: usize = 4;
+
+/// Code of the rows or the columns of a [`ProductCode`]
+pub trait LineCode {
+    /// Field of the symbols, the same for rows and columns so that the column parity rows are
+    /// row codewords too
+    type Field: GaloisField<Elem = u8>;
+
+    /// Ecc bytes of a line
+    fn ecc_len(&self) -> usize;
+
+    /// Writes the ecc of `data` to `ecc`, which is [`ecc_len`](Self::ecc_len) long
+    fn encode_line(&self, data: &[u8], ecc: &mut [u8]) -> Result<(), EncoderError>;
+
+    /// Corrects a line in place given the positions of erased bytes and returns the number of
+    /// changed bytes
+    fn correct_line(&self, line: &mut [u8], erasures: &[u8]) -> Result<usize, DecoderError>;
+}
+
+/// Reed-Solomon [`LineCode`] with `ECC_LEN` ecc bytes over field `F`
+#[derive(Debug)]
+pub struct RsCode<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    encoder: Encoder<ECC_LEN, F>,
+    decoder: Decoder<F>,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> RsCode<ECC_LEN, F> {
+    /// Constructs a code with generator roots `2^0, .., 2^(ECC_LEN - 1)`
+    pub fn new() -> Self {
+        Self::with_roots(0, 1)
+    }
+
+    /// Constructs a code with the generator roots of [`Encoder::with_roots`]
+    ///
+    /// # Panics
+    /// If `2^prim` doesn't generate the whole field.
+    pub fn with_roots(fcr: u8, prim: u8) -> Self {
+        RsCode {
+            encoder: Encoder::with_roots(fcr, prim),
+            decoder: Decoder::<F>::with_field(ECC_LEN).fcr(fcr).prim(prim),
+        }
+    }
+
+    /// Caps the corrections of a line like [`Decoder::limit_corrections`], lines needing more
+    /// are treated as failed and become erasures of the other dimension
+    pub fn limit_corrections(mut self, max_corrections: usize) -> Self {
+        self.decoder = self.decoder.limit_corrections(max_corrections);
+        self
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Default for RsCode<ECC_LEN, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> LineCode for RsCode<ECC_LEN, F> {
+    type Field = F;
+
+    fn ecc_len(&self) -> usize {
+        ECC_LEN
+    }
+
+    fn encode_line(&self, data: &[u8], ecc: &mut [u8]) -> Result<(), EncoderError> {
+        self.encoder.encode_to_slice(data, ecc)
+    }
+
+    fn correct_line(&self, line: &mut [u8], erasures: &[u8]) -> Result<usize, DecoderError> {
+        let report = self.decoder.correct_with_report(line, Some(erasures))?;
+        // Erasures which turn out to be right don't count
+        Ok(report.magnitudes().iter().filter(|x| **x != 0).count())
+    }
+}
+
+/// Product of a row code `R` and a column code `C` over the same field, their ecc lengths and
+/// generator roots may differ
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::product::{ProductCode, RsCode};
+///
+/// let code = ProductCode::new(RsCode::<4>::new(), RsCode::<4>::new(), 10, 20);
+/// let mut block = vec![0; code.block_len()];
+/// for r in 0..10 {
+///     block[r * code.cols()..r * code.cols() + 20].fill(r as u8 + 1);
+/// }
+/// code.encode(&mut block).unwrap();
+///
+/// // A whole row and a whole column lost
+/// let mut corrupted = block.clone();
+/// corrupted[3 * code.cols()..4 * code.cols()].fill(0);
+/// for r in 0..code.rows() {
+///     corrupted[r * code.cols() + 5] ^= 0xff;
+/// }
+/// assert!(code.decode(&mut corrupted).is_ok());
+/// assert_eq!(block, corrupted);
+/// ```
+#[derive(Debug)]
+pub struct ProductCode<R: LineCode, C: LineCode<Field = R::Field>> {
+    row_code: R,
+    col_code: C,
+    data_rows: usize,
+    data_cols: usize,
+    passes: usize,
+}
+
+impl<R: LineCode, C: LineCode<Field = R::Field>> ProductCode<R, C> {
+    /// Constructs a product code protecting `data_rows` rows of `data_cols` bytes.
+    ///
+    /// # Panics
+    /// If a row or a column doesn't fit a codeword together with its ecc.
+    pub fn new(row_code: R, col_code: C, data_rows: usize, data_cols: usize) -> Self {
+        assert!(data_cols > 0 && data_cols + row_code.ecc_len() <= 255, "row doesn't fit a codeword");
+        assert!(data_rows > 0 && data_rows + col_code.ecc_len() <= 255, "column doesn't fit a codeword");
+        ProductCode {
+            row_code,
+            col_code,
+            data_rows,
+            data_cols,
+            passes: PASSES,
+        }
+    }
+
+    /// Sets the maximal number of row and column passes of [`decode`](Self::decode), 4 by
+    /// default. A single pass corrects rows and then columns once.
+    ///
+    /// # Panics
+    /// If `passes` is zero.
+    pub fn passes(mut self, passes: usize) -> Self {
+        assert!(passes > 0, "at least one pass is needed");
+        self.passes = passes;
+        self
+    }
+
+    /// Data rows of a block
+    pub fn data_rows(&self) -> usize {
+        self.data_rows
+    }
+
+    /// Data bytes of a row
+    pub fn data_cols(&self) -> usize {
+        self.data_cols
+    }
+
+    /// Rows of a block, column parity included
+    pub fn rows(&self) -> usize {
+        self.data_rows + self.col_code.ecc_len()
+    }
+
+    /// Bytes of a row, row parity included
+    pub fn cols(&self) -> usize {
+        self.data_cols + self.row_code.ecc_len()
+    }
+
+    /// Bytes of a block
+    pub fn block_len(&self) -> usize {
+        self.rows() * self.cols()
+    }
+
+    /// Computes the row and column parity of a block whose data corner is filled in.
+    ///
+    /// Errors with `InvalidBlockLength` if `block` isn't [`block_len`](Self::block_len) long.
+    pub fn encode(&self, block: &mut [u8]) -> Result<(), EncoderError> {
+        if block.len() != self.block_len() {
+            return Err(EncoderError::InvalidBlockLength);
+        }
+        let cols = self.cols();
+
+        for row in block.chunks_exact_mut(cols).take(self.data_rows) {
+            let (data, ecc) = row.split_at_mut(self.data_cols);
+            self.row_code.encode_line(data, ecc)?;
+        }
+
+        let mut column = [0; 255];
+        let mut ecc = [0; 255];
+        let ecc = &mut ecc[..self.col_code.ecc_len()];
+        for c in 0..cols {
+            for (x, y) in column.iter_mut().zip(block[c..].iter().step_by(cols)) {
+                *x = *y;
+            }
+            self.col_code.encode_line(&column[..self.data_rows], ecc)?;
+            for (x, y) in block[self.data_rows * cols + c..].iter_mut().step_by(cols).zip(ecc.iter()) {
+                *x = *y;
+            }
+        }
+        Ok(())
+    }
+
+    /// Corrects a block in place by alternating row and column passes and returns the number of
+    /// corrected bytes. Errors if the block isn't clean after the last pass, in which case the
+    /// corrections made so far are kept.
+    ///
+    /// # Panics
+    /// If `block` isn't [`block_len`](Self::block_len) long.
+    pub fn decode(&self, block: &mut [u8]) -> Result<usize, DecoderError> {
+        assert_eq!(self.block_len(), block.len(), "block length doesn't match the code");
+        let (rows, cols) = (self.rows(), self.cols());
+
+        let mut failed_rows = [false; 255];
+        let mut failed_cols = [false; 255];
+        let mut corrected = 0;
+        for _ in 0..self.passes {
+            let mut changed = false;
+
+            let erasures = positions(&failed_cols[..cols], self.row_code.ecc_len());
+            for (r, row) in block.chunks_exact_mut(cols).enumerate() {
+                let fixed = self.row_code.correct_line(row, &erasures);
+                failed_rows[r] = fixed.is_err();
+                changed |= fixed.unwrap_or(0) > 0;
+                corrected += fixed.unwrap_or(0);
+            }
+
+            let erasures = positions(&failed_rows[..rows], self.col_code.ecc_len());
+            let mut column = [0; 255];
+            for (c, failed) in failed_cols[..cols].iter_mut().enumerate() {
+                for (x, y) in column.iter_mut().zip(block[c..].iter().step_by(cols)) {
+                    *x = *y;
+                }
+                let fixed = self.col_code.correct_line(&mut column[..rows], &erasures);
+                *failed = fixed.is_err();
+                if fixed.unwrap_or(0) > 0 {
+                    changed = true;
+                    corrected += fixed.unwrap_or(0);
+                    for (x, y) in block[c..].iter_mut().step_by(cols).zip(column.iter()) {
+                        *x = *y;
+                    }
+                }
+            }
+
+            if !changed && !failed_rows[..rows].contains(&true) && !failed_cols[..cols].contains(&true) {
+                return Ok(corrected);
+            }
+        }
+        Err(DecoderError::TooManyErrors)
+    }
+}
+
+/// Positions of the set `flags`, or none if there are more than `ecc_len` of them
+fn positions(flags: &[bool], ecc_len: usize) -> heapless::Vec<u8, 255> {
+    let mut positions = heapless::Vec::new();
+    if flags.iter().filter(|f| **f).count() <= ecc_len {
+        for (i, _) in flags.iter().enumerate().filter(|(_, f)| **f) {
+            unwrap_unchecked!(positions.push(i as u8));
+        }
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Code = ProductCode<RsCode<4>, RsCode<6>>;
+
+    fn code() -> Code {
+        ProductCode::new(RsCode::new(), RsCode::new(), 12, 30)
+    }
+
+    fn block(code: &Code) -> std::vec::Vec<u8> {
+        let mut block = std::vec![0; code.block_len()];
+        for r in 0..code.data_rows() {
+            for c in 0..code.data_cols() {
+                block[r * code.cols() + c] = (r * 31 + c * 7 + 1) as u8;
+            }
+        }
+        code.encode(&mut block).unwrap();
+        block
+    }
+
+    #[test]
+    fn encode() {
+        crate::gf::init_test_tables();
+        let code = code();
+        assert_eq!((18, 34, 612), (code.rows(), code.cols(), code.block_len()));
+        let block = block(&code);
+
+        let rows = Decoder::new(4);
+        let cols = Decoder::new(6);
+        for row in block.chunks(code.cols()) {
+            assert!(!rows.is_corrupted(row));
+        }
+        for c in 0..code.cols() {
+            let column: std::vec::Vec<u8> = block[c..].iter().step_by(code.cols()).copied().collect();
+            assert!(!cols.is_corrupted(&column));
+        }
+
+        assert_eq!(Err(EncoderError::InvalidBlockLength), code.encode(&mut [0; 611]));
+    }
+
+    #[test]
+    fn decode() {
+        crate::gf::init_test_tables();
+        let code = code();
+        let block = block(&code);
+
+        let mut clean = block.clone();
+        assert_eq!(Ok(0), code.decode(&mut clean));
+
+        // Neither rows 0..5 nor columns 0..5 alone can be corrected, together with erasures
+        // of the failed rows they can
+        let mut corrupted = block.clone();
+        for r in 0..5 {
+            for c in 0..5 {
+                corrupted[r * code.cols() + c] ^= 0x3c;
+            }
+        }
+        assert_eq!(Ok(25), code.decode(&mut corrupted.clone()));
+
+        // Corrected by the first pass, but only a second one confirms the block is clean
+        let code = code.passes(1);
+        assert_eq!(Err(DecoderError::TooManyErrors), code.decode(&mut corrupted));
+        assert_eq!(block, corrupted);
+
+        let mut corrupted = block.clone();
+        for r in 0..10 {
+            for c in 0..10 {
+                corrupted[r * code.cols() + c] ^= 0x3c;
+            }
+        }
+        assert_eq!(Err(DecoderError::TooManyErrors), code.decode(&mut corrupted));
+    }
+
+    #[test]
+    fn mixed_codes() {
+        crate::gf::init_test_tables();
+        let columns = RsCode::<8>::with_roots(120, 7).limit_corrections(6);
+        let code = ProductCode::new(RsCode::<2>::with_roots(1, 1), columns, 40, 16).passes(2);
+        let mut block = std::vec![0; code.block_len()];
+        for r in 0..40 {
+            block[r * 18..r * 18 + 16].fill(r as u8);
+        }
+        code.encode(&mut block).unwrap();
+
+        // The column parity rows are row codewords as well
+        let rows = Decoder::new(2).fcr(1);
+        assert!(block.chunks(18).all(|row| !rows.is_corrupted(row)));
+
+        let mut corrupted = block.clone();
+        corrupted[5 * 18..9 * 18].fill(0xee);
+        assert_eq!(Ok(4 * 18), code.decode(&mut corrupted));
+        assert_eq!(block, corrupted);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_wide() {
+        ProductCode::new(RsCode::<4>::new(), RsCode::<4>::new(), 10, 252);
+    }
+}
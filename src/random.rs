@@ -0,0 +1,177 @@
+//! Random polynomials, messages and error patterns for property tests and channel simulations.
+//!
+//! Generators take any [`RandomSource`]: a closure such as `|| rng.next_u64()` plugs in a `rand`
+//! generator, [`XorShift64`] is a small seedable one for reproducible runs.
+use heapless::Vec;
+use crate::gf::poly::Polynom;
+use crate::gf::GaloisField;
+
+/// Source of uniformly distributed 64-bit words
+pub trait RandomSource {
+    /// Returns the next random word
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<T: FnMut() -> u64> RandomSource for T {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Marsaglia's xorshift generator, fast and reproducible but not cryptographically secure
+#[derive(Debug, Copy, Clone)]
+pub struct XorShift64(u64);
+
+impl XorShift64 {
+    /// Constructs a generator from `seed`, a zero seed is replaced by a fixed non-zero one
+    pub const fn new(seed: u64) -> Self {
+        XorShift64(if seed == 0 { 0x9e37_79b9_7f4a_7c15 } else { seed })
+    }
+}
+
+impl RandomSource for XorShift64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Random number in `0..n`, `n` must not be zero
+#[inline]
+fn below<R: RandomSource>(rng: &mut R, n: usize) -> usize {
+    // Upper half of the product, unlike `%` it doesn't favour small numbers
+    ((rng.next_u64() as u128 * n as u128) >> 64) as usize
+}
+
+/// Random non-zero byte
+#[inline]
+fn nonzero<R: RandomSource>(rng: &mut R) -> u8 {
+    below(rng, 255) as u8 + 1
+}
+
+/// Random polynomial of degree `degree`, i.e. with a non-zero leading coefficient
+///
+/// # Panics
+/// If `degree` is 256 or more.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::gf::Gf2p8;
+/// use reed_solomon::random::{random_polynom, XorShift64};
+///
+/// let poly = random_polynom::<Gf2p8, _>(&mut XorShift64::new(7), 10);
+/// assert_eq!(Some(10), poly.degree());
+/// ```
+pub fn random_polynom<F: GaloisField<Elem = u8>, R: RandomSource>(rng: &mut R, degree: usize) -> Polynom<F> {
+    let mut poly = Polynom::with_length(degree + 1);
+    random_message(rng, &mut poly);
+    poly[0] = nonzero(rng);
+    poly
+}
+
+/// Fills `out` with random bytes
+pub fn random_message<R: RandomSource>(rng: &mut R, out: &mut [u8]) {
+    for chunk in out.chunks_mut(8) {
+        let word = rng.next_u64().to_le_bytes();
+        chunk.copy_from_slice(&word[..chunk.len()]);
+    }
+}
+
+/// Corrupts up to `max_weight` distinct random positions of `codeword` with non-zero error
+/// magnitudes and returns the corrupted positions, e.g. to pass some of them as erasures.
+/// The weight is uniform in `0..=max_weight`.
+///
+/// # Panics
+/// If `codeword` is longer than 255 bytes or shorter than `max_weight`.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::{Decoder, Encoder};
+/// use reed_solomon::random::{random_error_pattern, random_message, XorShift64};
+///
+/// let mut rng = XorShift64::new(1);
+/// let decoder = Decoder::new(8);
+///
+/// for _ in 0..100 {
+///     let mut data = [0; 32];
+///     random_message(&mut rng, &mut data);
+///     let mut codeword = Encoder::<8>::new().encode_buffer(&data).unwrap();
+///
+///     random_error_pattern(&mut rng, &mut codeword, 4);
+///     assert_eq!(&data[..], decoder.correct(&codeword, None).unwrap().data());
+/// }
+/// ```
+pub fn random_error_pattern<R: RandomSource>(rng: &mut R, codeword: &mut [u8], max_weight: usize) -> Vec<u8, 255> {
+    assert!(codeword.len() <= 255, "codeword is longer than 255 bytes");
+    assert!(max_weight <= codeword.len(), "more errors than codeword bytes");
+
+    // Partial Fisher-Yates shuffle of the positions
+    let mut positions = [0u8; 255];
+    for (i, pos) in positions.iter_mut().enumerate() {
+        *pos = i as u8;
+    }
+    let weight = below(rng, max_weight + 1);
+    let mut corrupted = Vec::new();
+    for i in 0..weight {
+        let j = i + below(rng, codeword.len() - i);
+        positions.swap(i, j);
+        codeword[positions[i] as usize] ^= nonzero(rng);
+        unwrap_unchecked!(corrupted.push(positions[i]));
+    }
+    corrupted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf::Gf2p8;
+
+    #[test]
+    fn generators() {
+        let mut rng = XorShift64::new(0);
+        assert_eq!(XorShift64::new(0).next_u64(), rng.next_u64());
+
+        for degree in [0, 1, 100, 255] {
+            let poly = random_polynom::<Gf2p8, _>(&mut rng, degree);
+            assert_eq!(Some(degree), poly.degree());
+        }
+
+        let mut message = [0; 37];
+        random_message(&mut rng, &mut message);
+        assert!(message.iter().any(|x| *x != 0));
+
+        let mut counter = 0u64;
+        let mut source = || {
+            counter += 1;
+            counter << 60
+        };
+        assert_eq!(1, below(&mut source, 16));
+        assert_eq!(2, below(&mut source, 16));
+    }
+
+    #[test]
+    fn error_pattern() {
+        let mut rng = XorShift64::new(42);
+        let mut max_seen = 0;
+        for _ in 0..200 {
+            let mut codeword = [0; 20];
+            let corrupted = random_error_pattern(&mut rng, &mut codeword, 5);
+            max_seen = max_seen.max(corrupted.len());
+
+            assert!(corrupted.len() <= 5);
+            assert_eq!(corrupted.len(), codeword.iter().filter(|x| **x != 0).count());
+            for pos in corrupted.iter() {
+                assert_ne!(0, codeword[*pos as usize]);
+            }
+        }
+        assert_eq!(5, max_seen);
+
+        let mut codeword = [0; 255];
+        assert_eq!(0, random_error_pattern(&mut rng, &mut codeword, 0).len());
+    }
+}
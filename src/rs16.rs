@@ -0,0 +1,334 @@
+//! Reed-Solomon codec over GF(2^16).
+//!
+//! Same code construction as `Encoder` and `Decoder` (generator roots `2^0..2^(ecc_len - 1)`),
+//! but with 16-bit symbols, so a codeword can be up to 65535 symbols (~128 KiB) long.
+//! Polynomials are kept in heap allocated vectors, highest power first.
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::gf::gf16;
+#[cfg(feature = "decoder")]
+use crate::decoder::DecoderError;
+
+/// Reed-Solomon BCH encoder with 16-bit symbols
+#[derive(Debug, Clone)]
+pub struct Encoder16 {
+    generator: Vec<u16>,
+}
+
+impl Encoder16 {
+    /// Constructs a new `Encoder16` producing `ecc_len` ecc symbols.
+    pub fn new(ecc_len: usize) -> Self {
+        assert!(ecc_len < gf16::ORDER);
+
+        let mut generator = vec![1];
+        for i in 0..ecc_len {
+            generator = mul(&generator, &[1, gf16::pow(2, i as i64)]);
+        }
+
+        Encoder16 { generator }
+    }
+
+    /// Encodes passed `&[u16]` slice and returns `ecc_len` ecc symbols to append to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Encoder16;
+    ///
+    /// let encoder = Encoder16::new(4);
+    ///
+    /// let ecc = encoder.encode(&[1000, 2000, 3000, 4000]);
+    /// assert_eq!(4, ecc.len());
+    /// ```
+    pub fn encode(&self, data: &[u16]) -> Vec<u16> {
+        let ecc_len = self.generator.len() - 1;
+        assert!(data.len() + ecc_len <= gf16::ORDER);
+
+        // Remainder of data(x) * x^ecc_len / generator(x)
+        let mut ecc = vec![0; ecc_len];
+        for x in data.iter() {
+            let coef = x ^ ecc[0];
+            ecc.rotate_left(1);
+            ecc[ecc_len - 1] = 0;
+            if coef != 0 {
+                for (e, g) in ecc.iter_mut().zip(self.generator[1..].iter()) {
+                    *e ^= gf16::mul(*g, coef);
+                }
+            }
+        }
+
+        ecc
+    }
+}
+
+/// Reed-Solomon BCH decoder with 16-bit symbols
+#[cfg(feature = "decoder")]
+#[derive(Debug, Copy, Clone)]
+pub struct Decoder16 {
+    ecc_len: usize,
+}
+
+#[cfg(feature = "decoder")]
+impl Decoder16 {
+    /// Constructs a new `Decoder16` for codewords with `ecc_len` ecc symbols.
+    pub fn new(ecc_len: usize) -> Self {
+        Decoder16 { ecc_len }
+    }
+
+    /// Decodes block-encoded message and returns corrected copy of it,
+    /// data is the first `msg.len() - ecc_len` symbols.
+    ///
+    /// `erase_pos` are positions of symbols known to be damaged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Decoder16, Encoder16};
+    ///
+    /// let encoder = Encoder16::new(4);
+    /// let decoder = Decoder16::new(4);
+    ///
+    /// let data: Vec<u16> = (0..1000).collect();
+    /// let mut message = data.clone();
+    /// message.extend_from_slice(&encoder.encode(&data));
+    ///
+    /// // Corrupt message
+    /// message[10] = 0;
+    /// message[900] = 0xffff;
+    ///
+    /// let corrected = decoder.correct(&message, None).unwrap();
+    /// assert_eq!(&data[..], &corrected[..1000]);
+    /// ```
+    pub fn correct(&self, msg: &[u16], erase_pos: Option<&[usize]>) -> Result<Vec<u16>, DecoderError> {
+        let mut msg = Vec::from(msg);
+        for e_pos in erase_pos.unwrap_or(&[]) {
+            msg[*e_pos] = 0;
+        }
+
+        self.correct_in_place(&mut msg, erase_pos)?;
+        Ok(msg)
+    }
+
+    /// Corrects block-encoded message in place and returns the number of corrected symbols.
+    ///
+    /// Message is left untouched if it can't be corrected.
+    pub fn correct_in_place(&self, msg: &mut [u16], erase_pos: Option<&[usize]>) -> Result<usize, DecoderError> {
+        let n = msg.len();
+        assert!(n <= gf16::ORDER);
+
+        let erase_pos = erase_pos.unwrap_or(&[]);
+        if erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        let synd = self.syndromes(msg);
+        if synd.iter().all(|x| *x == 0) {
+            return Ok(0);
+        }
+
+        // Errors are located from Forney syndromes, which have erasures cancelled out
+        let mut fsynd = synd.clone();
+        for pos in erase_pos.iter() {
+            let x = gf16::pow(2, (n - 1 - pos) as i64);
+            for j in 0..(fsynd.len() - 1) {
+                fsynd[j] = gf16::mul(fsynd[j], x) ^ fsynd[j + 1];
+            }
+        }
+
+        let err_loc = berlekamp_massey(&fsynd[..self.ecc_len - erase_pos.len()]);
+        let errors = err_loc.len() - 1;
+        if 2 * errors + erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        // Chien search
+        let mut err_pos: Vec<usize> = (0..n)
+            .filter(|i| eval(&err_loc, gf16::pow(2, -((n - 1 - i) as i64))) == 0)
+            .collect();
+        if err_pos.len() != errors {
+            return Err(DecoderError::TooManyErrors);
+        }
+        err_pos.extend_from_slice(erase_pos);
+
+        // Forney algorithm
+        let coef_pos: Vec<usize> = err_pos.iter().map(|p| n - 1 - p).collect();
+        let mut errata_loc = vec![1];
+        for c in coef_pos.iter() {
+            errata_loc = mul(&errata_loc, &[gf16::pow(2, *c as i64), 1]);
+        }
+
+        let synd_rev: Vec<u16> = synd.iter().rev().copied().collect();
+        let product = mul(&synd_rev, &errata_loc);
+        let err_eval = &product[product.len() - (errata_loc.len() - 1)..];
+
+        let mut err_loc_prime = vec![0; errata_loc.len() - 1];
+        for (i, x) in err_loc_prime.iter_mut().enumerate() {
+            if (errata_loc.len() - 1 - i) % 2 == 1 {
+                *x = errata_loc[i];
+            }
+        }
+
+        let mut magnitudes = Vec::with_capacity(coef_pos.len());
+        for c in coef_pos.iter() {
+            let x = gf16::pow(2, *c as i64);
+            let x_inv = gf16::inverse(x);
+
+            let denominator = eval(&err_loc_prime, x_inv);
+            if denominator == 0 {
+                return Err(DecoderError::TooManyErrors);
+            }
+            magnitudes.push(gf16::div(gf16::mul(x, eval(err_eval, x_inv)), denominator));
+        }
+
+        // The corrections must cancel out all syndromes
+        for (i, s) in synd.iter().enumerate() {
+            let x = gf16::pow(2, i as i64);
+            let e = coef_pos.iter().zip(magnitudes.iter()).fold(0, |acc, (c, magnitude)| {
+                acc ^ gf16::mul(*magnitude, gf16::pow(x, *c as i64))
+            });
+            if e != *s {
+                return Err(DecoderError::TooManyErrors);
+            }
+        }
+
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            msg[*pos] ^= magnitude;
+        }
+
+        Ok(magnitudes.len())
+    }
+
+    /// Performs fast corruption check.
+    pub fn is_corrupted(&self, msg: &[u16]) -> bool {
+        self.syndromes(msg).iter().any(|x| *x != 0)
+    }
+
+    /// Computes `ecc_len` syndromes of block-encoded message,
+    /// all of them are zero if the message is not damaged.
+    pub fn syndromes(&self, msg: &[u16]) -> Vec<u16> {
+        (0..self.ecc_len)
+            .map(|i| eval(msg, gf16::pow(2, i as i64)))
+            .collect()
+    }
+}
+
+/// Berlekamp-Massey algorithm, returns error locator polynomial for syndromes `synd`
+#[cfg(feature = "decoder")]
+fn berlekamp_massey(synd: &[u16]) -> Vec<u16> {
+    let mut err_loc = vec![1];
+    let mut old_loc = vec![1];
+
+    for k in 0..synd.len() {
+        let mut delta = synd[k];
+        for j in 1..err_loc.len() {
+            delta ^= gf16::mul(err_loc[err_loc.len() - 1 - j], synd[k - j]);
+        }
+
+        old_loc.push(0);
+
+        if delta != 0 {
+            if old_loc.len() > err_loc.len() {
+                let new_loc = scale(&old_loc, delta);
+                old_loc = scale(&err_loc, gf16::inverse(delta));
+                err_loc = new_loc;
+            }
+
+            err_loc = add(&err_loc, &scale(&old_loc, delta));
+        }
+    }
+
+    let shift = err_loc.iter().take_while(|&&v| v == 0).count();
+    err_loc.split_off(shift)
+}
+
+#[cfg(feature = "decoder")]
+fn scale(poly: &[u16], x: u16) -> Vec<u16> {
+    poly.iter().map(|px| gf16::mul(*px, x)).collect()
+}
+
+#[cfg(feature = "decoder")]
+fn add(lhs: &[u16], rhs: &[u16]) -> Vec<u16> {
+    let len = lhs.len().max(rhs.len());
+    let mut sum = vec![0; len];
+    for (s, x) in sum[len - lhs.len()..].iter_mut().zip(lhs.iter()) {
+        *s ^= x;
+    }
+    for (s, x) in sum[len - rhs.len()..].iter_mut().zip(rhs.iter()) {
+        *s ^= x;
+    }
+    sum
+}
+
+fn mul(lhs: &[u16], rhs: &[u16]) -> Vec<u16> {
+    let mut product = vec![0; lhs.len() + rhs.len() - 1];
+    for (j, y) in rhs.iter().enumerate() {
+        for (i, x) in lhs.iter().enumerate() {
+            product[i + j] ^= gf16::mul(*x, *y);
+        }
+    }
+    product
+}
+
+#[cfg(feature = "decoder")]
+fn eval(poly: &[u16], x: u16) -> u16 {
+    poly.iter().fold(0, |y, px| gf16::mul(y, x) ^ px)
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    use super::*;
+
+    fn codeword(data_len: usize, ecc_len: usize) -> Vec<u16> {
+        let data: Vec<u16> = (0..data_len).map(|i| (i * 7919) as u16).collect();
+        let mut msg = data.clone();
+        msg.extend_from_slice(&Encoder16::new(ecc_len).encode(&data));
+        msg
+    }
+
+    #[test]
+    fn encode() {
+        let msg = codeword(100, 8);
+        assert!(!Decoder16::new(8).is_corrupted(&msg));
+        assert!(Decoder16::new(8).syndromes(&msg).iter().all(|x| *x == 0));
+    }
+
+    #[test]
+    fn correct() {
+        let orig = codeword(40, 10);
+        let decoder = Decoder16::new(10);
+
+        for errors in 0..6 {
+            for erasures in 0..(11 - 2 * errors) {
+                let mut corrupted = orig.clone();
+                let erase_pos: Vec<usize> = (0..erasures).map(|x| x * 3 + 1).collect();
+                for x in erase_pos.iter() {
+                    corrupted[*x] = 0;
+                }
+                for i in 0..errors {
+                    corrupted[45 - i * 2] ^= 0xa5a5;
+                }
+
+                assert_eq!(orig, decoder.correct(&corrupted, Some(&erase_pos)).unwrap());
+            }
+        }
+
+        let mut corrupted = orig.clone();
+        for i in 0..6 {
+            corrupted[i * 5] ^= 0x1111;
+        }
+        assert!(decoder.correct_in_place(&mut corrupted, None).is_err());
+    }
+
+    #[test]
+    fn long_codeword() {
+        // 64 KiB of data in a single codeword
+        let orig = codeword(32768, 32);
+        let decoder = Decoder16::new(32);
+
+        let mut corrupted = orig.clone();
+        for i in 0..16 {
+            corrupted[i * 2000 + 13] ^= 0xbeef;
+        }
+
+        assert_eq!(16, decoder.correct_in_place(&mut corrupted, None).unwrap());
+        assert_eq!(orig, corrupted);
+    }
+}
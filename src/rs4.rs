@@ -0,0 +1,353 @@
+//! Reed-Solomon codec over GF(2^4).
+//!
+//! Same code construction as `Encoder` and `Decoder` (generator roots `2^0..2^(ecc_len - 1)`),
+//! but with 4-bit symbols, so a codeword is at most 15 symbols long. Symbols are passed one per
+//! byte; `pack_nibbles` and `unpack_nibbles` convert them to and from two-per-byte form.
+use heapless::Vec;
+use crate::gf::gf4;
+#[cfg(feature = "decoder")]
+use crate::decoder::DecoderError;
+
+/// Polynomial over GF(2^4), highest power first
+type Poly = Vec<u8, 32>;
+
+/// Reed-Solomon BCH encoder with 4-bit symbols
+#[derive(Debug, Clone)]
+pub struct Encoder4 {
+    generator: Poly,
+}
+
+impl Encoder4 {
+    /// Constructs a new `Encoder4` producing `ecc_len` ecc symbols.
+    pub fn new(ecc_len: usize) -> Self {
+        assert!(ecc_len < gf4::ORDER);
+
+        let mut generator = Poly::from_slice(&[1]).unwrap();
+        for i in 0..ecc_len {
+            generator = mul(&generator, &[1, gf4::pow(2, i as i32)]);
+        }
+
+        Encoder4 { generator }
+    }
+
+    /// Encodes passed symbols and returns `ecc_len` ecc symbols to append to them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Encoder4;
+    ///
+    /// let encoder = Encoder4::new(4);
+    ///
+    /// let ecc = encoder.encode(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    /// assert_eq!(4, ecc.len());
+    /// ```
+    pub fn encode(&self, data: &[u8]) -> Vec<u8, 16> {
+        let ecc_len = self.generator.len() - 1;
+        assert!(data.len() + ecc_len <= gf4::ORDER);
+
+        // Remainder of data(x) * x^ecc_len / generator(x)
+        let mut ecc: Vec<u8, 16> = Vec::new();
+        ecc.resize_default(ecc_len).unwrap();
+        if ecc_len == 0 {
+            return ecc;
+        }
+
+        for x in data.iter() {
+            debug_assert!(*x < 16, "symbol doesn't fit into a nibble");
+            let coef = x ^ ecc[0];
+            ecc.rotate_left(1);
+            ecc[ecc_len - 1] = 0;
+            if coef != 0 {
+                for (e, g) in ecc.iter_mut().zip(self.generator[1..].iter()) {
+                    *e ^= gf4::mul(*g, coef);
+                }
+            }
+        }
+
+        ecc
+    }
+}
+
+/// Reed-Solomon BCH decoder with 4-bit symbols
+#[cfg(feature = "decoder")]
+#[derive(Debug, Copy, Clone)]
+pub struct Decoder4 {
+    ecc_len: usize,
+}
+
+#[cfg(feature = "decoder")]
+impl Decoder4 {
+    /// Constructs a new `Decoder4` for codewords with `ecc_len` ecc symbols.
+    pub fn new(ecc_len: usize) -> Self {
+        Decoder4 { ecc_len }
+    }
+
+    /// Corrects block-encoded message in place and returns the number of corrected symbols.
+    ///
+    /// `erase_pos` are positions of symbols known to be damaged.
+    /// Message is left untouched if it can't be corrected.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Decoder4, Encoder4};
+    ///
+    /// let encoder = Encoder4::new(4);
+    /// let decoder = Decoder4::new(4);
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    /// let mut message = Vec::from(&data[..]);
+    /// message.extend_from_slice(&encoder.encode(&data));
+    ///
+    /// // Corrupt message
+    /// message[2] = 0;
+    /// message[9] = 15;
+    ///
+    /// assert_eq!(2, decoder.correct_in_place(&mut message, None).unwrap());
+    /// assert_eq!(&data[..], &message[..11]);
+    /// ```
+    pub fn correct_in_place(&self, msg: &mut [u8], erase_pos: Option<&[u8]>) -> Result<usize, DecoderError> {
+        let n = msg.len();
+        assert!(n <= gf4::ORDER);
+
+        let erase_pos = erase_pos.unwrap_or(&[]);
+        if erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        let synd = self.syndromes(msg);
+        if synd.iter().all(|x| *x == 0) {
+            return Ok(0);
+        }
+
+        // Errors are located from Forney syndromes, which have erasures cancelled out
+        let mut fsynd = synd.clone();
+        for pos in erase_pos.iter() {
+            let x = gf4::pow(2, (n - 1 - *pos as usize) as i32);
+            for j in 0..(fsynd.len() - 1) {
+                fsynd[j] = gf4::mul(fsynd[j], x) ^ fsynd[j + 1];
+            }
+        }
+
+        let err_loc = berlekamp_massey(&fsynd[..self.ecc_len - erase_pos.len()]);
+        let errors = err_loc.len() - 1;
+        if 2 * errors + erase_pos.len() > self.ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        // Chien search
+        let mut err_pos: Vec<u8, 16> = (0..n)
+            .filter(|i| eval(&err_loc, gf4::pow(2, -((n - 1 - i) as i32))) == 0)
+            .map(|i| i as u8)
+            .collect();
+        if err_pos.len() != errors {
+            return Err(DecoderError::TooManyErrors);
+        }
+        err_pos.extend_from_slice(erase_pos).map_err(|_| DecoderError::TooManyErrors)?;
+
+        // Forney algorithm
+        let coef_pos: Vec<u8, 16> = err_pos.iter().map(|p| (n - 1 - *p as usize) as u8).collect();
+        let mut errata_loc = Poly::from_slice(&[1]).unwrap();
+        for c in coef_pos.iter() {
+            errata_loc = mul(&errata_loc, &[gf4::pow(2, *c as i32), 1]);
+        }
+
+        let synd_rev: Poly = synd.iter().rev().copied().collect();
+        let product = mul(&synd_rev, &errata_loc);
+        let err_eval = &product[product.len() - (errata_loc.len() - 1)..];
+
+        let mut err_loc_prime: Poly = Vec::new();
+        for (i, x) in errata_loc[..errata_loc.len() - 1].iter().enumerate() {
+            let odd = (errata_loc.len() - 1 - i) % 2 == 1;
+            err_loc_prime.push(if odd { *x } else { 0 }).unwrap();
+        }
+
+        let mut magnitudes: Vec<u8, 16> = Vec::new();
+        for c in coef_pos.iter() {
+            let x = gf4::pow(2, *c as i32);
+            let x_inv = gf4::inverse(x);
+
+            let denominator = eval(&err_loc_prime, x_inv);
+            if denominator == 0 {
+                return Err(DecoderError::TooManyErrors);
+            }
+            magnitudes.push(gf4::div(gf4::mul(x, eval(err_eval, x_inv)), denominator)).unwrap();
+        }
+
+        // The corrections must cancel out all syndromes
+        for (i, s) in synd.iter().enumerate() {
+            let x = gf4::pow(2, i as i32);
+            let e = coef_pos.iter().zip(magnitudes.iter()).fold(0, |acc, (c, magnitude)| {
+                acc ^ gf4::mul(*magnitude, gf4::pow(x, *c as i32))
+            });
+            if e != *s {
+                return Err(DecoderError::TooManyErrors);
+            }
+        }
+
+        for (pos, magnitude) in err_pos.iter().zip(magnitudes.iter()) {
+            msg[*pos as usize] ^= magnitude;
+        }
+
+        Ok(magnitudes.len())
+    }
+
+    /// Performs fast corruption check.
+    pub fn is_corrupted(&self, msg: &[u8]) -> bool {
+        self.syndromes(msg).iter().any(|x| *x != 0)
+    }
+
+    /// Computes `ecc_len` syndromes of block-encoded message,
+    /// all of them are zero if the message is not damaged.
+    pub fn syndromes(&self, msg: &[u8]) -> Vec<u8, 16> {
+        (0..self.ecc_len)
+            .map(|i| eval(msg, gf4::pow(2, i as i32)))
+            .collect()
+    }
+}
+
+/// Packs 4-bit symbols two per byte, first symbol goes to the high nibble.
+/// Odd trailing symbol is padded with zero nibble.
+///
+/// Returns number of bytes written to `out`.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{pack_nibbles, unpack_nibbles};
+///
+/// let mut packed = [0; 2];
+/// assert_eq!(2, pack_nibbles(&[1, 2, 3], &mut packed));
+/// assert_eq!([0x12, 0x30], packed);
+///
+/// let mut symbols = [0; 4];
+/// assert_eq!(4, unpack_nibbles(&packed, &mut symbols));
+/// assert_eq!([1, 2, 3, 0], symbols);
+/// ```
+pub fn pack_nibbles(symbols: &[u8], out: &mut [u8]) -> usize {
+    let len = symbols.len().div_ceil(2);
+    for (byte, pair) in out[..len].iter_mut().zip(symbols.chunks(2)) {
+        debug_assert!(pair.iter().all(|x| *x < 16), "symbol doesn't fit into a nibble");
+        *byte = pair[0] << 4 | pair.get(1).copied().unwrap_or(0);
+    }
+    len
+}
+
+/// Unpacks bytes into 4-bit symbols, high nibble first.
+///
+/// Returns number of symbols written to `out`.
+pub fn unpack_nibbles(bytes: &[u8], out: &mut [u8]) -> usize {
+    let len = bytes.len() * 2;
+    for (pair, byte) in out[..len].chunks_mut(2).zip(bytes.iter()) {
+        pair[0] = byte >> 4;
+        pair[1] = byte & 0x0f;
+    }
+    len
+}
+
+/// Berlekamp-Massey algorithm, returns error locator polynomial for syndromes `synd`
+#[cfg(feature = "decoder")]
+fn berlekamp_massey(synd: &[u8]) -> Poly {
+    let mut err_loc = Poly::from_slice(&[1]).unwrap();
+    let mut old_loc = Poly::from_slice(&[1]).unwrap();
+
+    for k in 0..synd.len() {
+        let mut delta = synd[k];
+        for j in 1..err_loc.len() {
+            delta ^= gf4::mul(err_loc[err_loc.len() - 1 - j], synd[k - j]);
+        }
+
+        old_loc.push(0).unwrap();
+
+        if delta != 0 {
+            if old_loc.len() > err_loc.len() {
+                let new_loc = scale(&old_loc, delta);
+                old_loc = scale(&err_loc, gf4::inverse(delta));
+                err_loc = new_loc;
+            }
+
+            err_loc = add(&err_loc, &scale(&old_loc, delta));
+        }
+    }
+
+    let shift = err_loc.iter().take_while(|&&v| v == 0).count();
+    Poly::from_slice(&err_loc[shift..]).unwrap()
+}
+
+#[cfg(feature = "decoder")]
+fn scale(poly: &[u8], x: u8) -> Poly {
+    poly.iter().map(|px| gf4::mul(*px, x)).collect()
+}
+
+#[cfg(feature = "decoder")]
+fn add(lhs: &[u8], rhs: &[u8]) -> Poly {
+    let len = lhs.len().max(rhs.len());
+    let mut sum = Poly::new();
+    sum.resize_default(len).unwrap();
+    for (s, x) in sum[len - lhs.len()..].iter_mut().zip(lhs.iter()) {
+        *s ^= x;
+    }
+    for (s, x) in sum[len - rhs.len()..].iter_mut().zip(rhs.iter()) {
+        *s ^= x;
+    }
+    sum
+}
+
+fn mul(lhs: &[u8], rhs: &[u8]) -> Poly {
+    let mut product = Poly::new();
+    product.resize_default(lhs.len() + rhs.len() - 1).unwrap();
+    for (j, y) in rhs.iter().enumerate() {
+        for (i, x) in lhs.iter().enumerate() {
+            product[i + j] ^= gf4::mul(*x, *y);
+        }
+    }
+    product
+}
+
+#[cfg(feature = "decoder")]
+fn eval(poly: &[u8], x: u8) -> u8 {
+    poly.iter().fold(0, |y, px| gf4::mul(y, x) ^ px)
+}
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn correct() {
+        let data = [3, 1, 4, 1, 5, 9, 2, 6, 5];
+        let mut orig = Vec::from(&data[..]);
+        orig.extend_from_slice(&Encoder4::new(6).encode(&data));
+        let decoder = Decoder4::new(6);
+
+        assert_eq!(15, orig.len());
+        assert!(!decoder.is_corrupted(&orig));
+
+        for errors in 0..4 {
+            for erasures in 0..(7 - 2 * errors) {
+                let mut corrupted = orig.clone();
+                let erase_pos: Vec<u8> = (0..erasures as u8).map(|x| x * 2 + 1).collect();
+                for x in erase_pos.iter() {
+                    corrupted[*x as usize] = 0;
+                }
+                for i in 0..errors {
+                    corrupted[14 - i * 2] ^= 0xa;
+                }
+
+                decoder.correct_in_place(&mut corrupted, Some(&erase_pos)).unwrap();
+                assert_eq!(orig, corrupted);
+            }
+        }
+    }
+
+    #[test]
+    fn nibbles() {
+        let symbols = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let mut packed = [0; 8];
+        assert_eq!(8, pack_nibbles(&symbols, &mut packed));
+        assert_eq!([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0], packed);
+
+        let mut unpacked = [0; 16];
+        assert_eq!(16, unpack_nibbles(&packed, &mut unpacked));
+        assert_eq!(symbols, unpacked[..15]);
+    }
+}
@@ -0,0 +1,137 @@
+use core::marker::PhantomData;
+use heapless::Vec;
+use crate::encoder::{field_generator_poly, log_generator, monic_tail, power_inverse, Encoder, EncoderError};
+use crate::gf::{GaloisField, Gf2p8};
+
+/// Encoder keeping only the logarithms of its generator polynomial, i.e. half the state of
+/// [`Encoder`], for large `ECC_LEN` on parts with little RAM.
+///
+/// Produces the same ecc as [`Encoder`]. With `constant_time` every round converts the
+/// logarithms back, which makes it slower than [`Encoder`].
+#[derive(Debug)]
+pub struct SlimEncoder<const ECC_LEN: usize, F: GaloisField<Elem = u8> = Gf2p8> {
+    /// Logarithms of the generator polynomial coefficients after its leading 1
+    lgenerator: [u8; ECC_LEN],
+    field: PhantomData<F>,
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> SlimEncoder<ECC_LEN, F> {
+    /// Constructs a new `SlimEncoder` and calculates its generator polynomial.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+    /// use reed_solomon::{Encoder, SlimEncoder};
+    ///
+    /// let encoder = SlimEncoder::<128>::new();
+    /// assert_eq!(Encoder::<128>::new().encode(b"Hello").unwrap(), encoder.encode(b"Hello").unwrap());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_roots(0, 1)
+    }
+
+    /// Same as [`Encoder::with_roots`]
+    pub fn with_roots(fcr: u8, prim: u8) -> Self {
+        assert!(power_inverse(prim, F::ORDER).is_some(), "2^prim must be a primitive element");
+        let generator = field_generator_poly::<F>(ECC_LEN, fcr, prim);
+        Self::new_with_precomputed_generator(&generator)
+    }
+
+    table_fn! {
+        /// Same as [`Encoder::new_with_precomputed_generator`], `const` unless `external_tables`
+        /// is enabled.
+        ///
+        /// # Example
+        #[cfg_attr(not(feature = "external_tables"), doc = "```rust")]
+        #[cfg_attr(feature = "external_tables", doc = "```ignore")]
+        /// use reed_solomon::{SlimEncoder, ENCODE_GEN_128_ECC_BYTES};
+        ///
+        /// static ENCODER: SlimEncoder<128> = SlimEncoder::new_with_precomputed_generator(&ENCODE_GEN_128_ECC_BYTES);
+        /// ```
+        pub fn new_with_precomputed_generator(generator: &[u8]) -> Self {
+            Self {
+                lgenerator: log_generator::<F, ECC_LEN>(&monic_tail(generator)),
+                field: PhantomData,
+            }
+        }
+    }
+
+    /// Same as [`Encoder::encode`]
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8, ECC_LEN>, EncoderError> {
+        let mut ecc = [0; ECC_LEN];
+        self.encode_to_slice(data, &mut ecc)?;
+        Ok(unwrap_unchecked!(Vec::from_slice(&ecc)))
+    }
+
+    /// Same as [`Encoder::encode_to_slice`]
+    pub fn encode_to_slice(&self, data: &[u8], ecc_out: &mut [u8]) -> Result<(), EncoderError> {
+        if ecc_out.len() != ECC_LEN {
+            return Err(EncoderError::EccLenMismatch);
+        }
+        if data.len() + ECC_LEN > F::ORDER {
+            return Err(EncoderError::MessageTooLong);
+        }
+
+        ecc_out.fill(0);
+        if ECC_LEN == 0 {
+            return Ok(());
+        }
+        for x in data.iter() {
+            let coef = *x ^ ecc_out[0];
+            ecc_out.rotate_left(1);
+            ecc_out[ECC_LEN - 1] = 0;
+
+            if cfg!(feature = "constant_time") {
+                for (r, lgen) in ecc_out.iter_mut().zip(self.lgenerator.iter()) {
+                    *r ^= F::mul(coef, F::exp(*lgen as usize));
+                }
+            } else if coef != 0 {
+                let lcoef = F::log(coef);
+                for (r, lgen) in ecc_out.iter_mut().zip(self.lgenerator.iter()) {
+                    *r ^= F::exp(lcoef + *lgen as usize);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> Default for SlimEncoder<ECC_LEN, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ECC_LEN: usize, F: GaloisField<Elem = u8>> From<Encoder<ECC_LEN, F>> for SlimEncoder<ECC_LEN, F> {
+    fn from(encoder: Encoder<ECC_LEN, F>) -> Self {
+        let mut lgenerator = [0; ECC_LEN];
+        lgenerator.copy_from_slice(encoder.generator_log());
+        Self {
+            lgenerator,
+            field: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SlimEncoder;
+    use crate::Encoder;
+
+    #[test]
+    fn matches_encoder() {
+        crate::gf::init_test_tables();
+        let data: [u8; 127] = core::array::from_fn(|i| (i * 11) as u8);
+        let mut encoder = Encoder::<128>::new();
+        let expected = encoder.encode(&data).unwrap();
+
+        assert_eq!(expected, SlimEncoder::<128>::new().encode(&data).unwrap());
+        assert_eq!(expected, SlimEncoder::from(encoder).encode(&data).unwrap());
+        assert_eq!(128 * 2, core::mem::size_of::<Encoder<128>>());
+        assert_eq!(128, core::mem::size_of::<SlimEncoder<128>>());
+
+        let fcr = SlimEncoder::<8>::with_roots(112, 11).encode(&data[..40]).unwrap();
+        assert_eq!(Encoder::<8>::with_roots(112, 11).encode(&data[..40]).unwrap(), fcr);
+        assert_eq!(Err(crate::EncoderError::MessageTooLong), SlimEncoder::<128>::new().encode(&[0; 128]));
+    }
+}
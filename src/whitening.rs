@@ -0,0 +1,123 @@
+//! PN9 data whitening of sub-GHz radios (TI CC1xxx / CC2xxx, IEEE 802.15.4g SUN FSK).
+//!
+//! Whitening XORs the frame with the PN9 sequence `x^9 + x^5 + 1` to avoid long runs of equal
+//! bits on air. It goes after the RS encoder on the transmitter and before the decoder on the
+//! receiver, whitening and dewhitening being the same operation, so the decoder sees the same
+//! bytes the encoder produced and channel errors stay byte errors.
+
+/// Seed of the PN9 register, all ones
+pub const PN9_SEED: u16 = 0x1ff;
+
+/// PN9 whitening stage, carrying the register between calls so a frame can be passed in pieces
+///
+/// The low 8 bits of the register are XORed into each byte, then the register is clocked 8
+/// times. Bit 0 is the first bit of the sequence, which matches radios sending bytes least
+/// significant bit first as 802.15.4g does and the byte-wise whitening of TI radios.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "external_tables")] reed_solomon::gf::external::init_generated_tables();
+/// use reed_solomon::whitening::Pn9;
+/// use reed_solomon::{Decoder, Encoder};
+///
+/// let encoder = Encoder::<8>::new();
+/// let decoder = Decoder::new(8);
+///
+/// // Transmitter: data and parity are whitened together
+/// let mut frame = encoder.encode_buffer(b"sub-GHz frame").unwrap();
+/// Pn9::new().apply(&mut frame);
+/// assert_eq!([0xff ^ b's', 0xe1 ^ b'u'], frame[..2]);
+///
+/// // Receiver
+/// frame[3] ^= 0x40;
+/// Pn9::new().apply(&mut frame);
+/// assert_eq!(b"sub-GHz frame", decoder.correct(&frame, None).unwrap().data());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pn9 {
+    seed: u16,
+    state: u16,
+}
+
+impl Pn9 {
+    /// Constructs a whitening stage with the standard all ones seed
+    pub const fn new() -> Self {
+        Self::with_seed(PN9_SEED)
+    }
+
+    /// Constructs a whitening stage starting from the low 9 bits of `seed`, some radios
+    /// allow choosing it
+    ///
+    /// # Panics
+    /// If the low 9 bits of `seed` are zero, the register would never leave this state.
+    pub const fn with_seed(seed: u16) -> Self {
+        let seed = seed & 0x1ff;
+        assert!(seed != 0, "PN9 seed must not be zero");
+        Pn9 { seed, state: seed }
+    }
+
+    /// Next byte of the sequence
+    #[inline]
+    pub fn next_byte(&mut self) -> u8 {
+        let byte = self.state as u8;
+        for _ in 0..8 {
+            let feedback = (self.state ^ self.state >> 5) & 1;
+            self.state = self.state >> 1 | feedback << 8;
+        }
+        byte
+    }
+
+    /// Whitens or dewhitens the next bytes of a frame
+    pub fn apply(&mut self, bytes: &mut [u8]) {
+        for x in bytes.iter_mut() {
+            *x ^= self.next_byte();
+        }
+    }
+
+    /// Goes back to the seed for the next frame
+    pub fn reset(&mut self) {
+        self.state = self.seed;
+    }
+}
+
+impl Default for Pn9 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pn9() {
+        // First bytes of the sequence, as in TI design note DN509
+        let mut pn9 = Pn9::new();
+        let head: [u8; 8] = core::array::from_fn(|_| pn9.next_byte());
+        assert_eq!([0xff, 0xe1, 0x1d, 0x9a, 0xed, 0x85, 0x33, 0x24], head);
+
+        // Maximal length: the register comes back to the seed after 511 bits only, since 8
+        // and 511 are coprime, after 511 bytes
+        let mut pn9 = Pn9::new();
+        let period = (1..=511).find(|_| {
+            pn9.next_byte();
+            pn9.state == PN9_SEED
+        });
+        assert_eq!(Some(511), period);
+
+        let mut frame = [0x5a; 40];
+        let mut pn9 = Pn9::with_seed(0x1234);
+        pn9.apply(&mut frame[..15]);
+        pn9.apply(&mut frame[15..]);
+        pn9.reset();
+        pn9.apply(&mut frame);
+        assert_eq!([0x5a; 40], frame);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_seed() {
+        Pn9::with_seed(0x200);
+    }
+}
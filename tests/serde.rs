@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+extern crate reed_solomon;
+extern crate serde_json;
+
+use reed_solomon::{Buffer, Decoder, Encoder, KeyEquationSolver, Polynom};
+
+/// Registers the field tables, which aren't embedded with `external_tables`
+fn init_tables() {
+    #[cfg(feature = "external_tables")]
+    reed_solomon::gf::external::init_generated_tables();
+}
+
+#[test]
+fn polynom() {
+    let poly: Polynom = reed_solomon::polynom![1, 0, 255];
+    let json = serde_json::to_string(&poly).unwrap();
+    assert_eq!("[1,0,255]", json);
+    assert_eq!(poly, serde_json::from_str::<Polynom>(&json).unwrap());
+
+    let too_long = serde_json::to_string(&[0u8; 257].to_vec()).unwrap();
+    assert!(serde_json::from_str::<Polynom>(&too_long).is_err());
+    assert!(serde_json::from_str::<Polynom>("[256]").is_err());
+}
+
+#[test]
+fn buffer() {
+    init_tables();
+    let encoder = Encoder::<4>::new();
+    let buffer = encoder.encode_buffer(b"Hello").unwrap();
+    let json = serde_json::to_string(&buffer).unwrap();
+
+    let restored: Buffer = serde_json::from_str(&json).unwrap();
+    assert_eq!(buffer.data(), restored.data());
+    assert_eq!(buffer.ecc(), restored.ecc());
+}
+
+#[test]
+fn encoder() {
+    init_tables();
+    let mut encoder = Encoder::<4>::with_roots(112, 11);
+    let json = serde_json::to_string(&encoder).unwrap();
+
+    let mut restored: Encoder<4> = serde_json::from_str(&json).unwrap();
+    assert_eq!(encoder.encode(b"Hello").unwrap(), restored.encode(b"Hello").unwrap());
+
+    assert!(serde_json::from_str::<Encoder<5>>(&json).is_err());
+    assert!(serde_json::from_str::<Encoder<4>>("[2,0,0,0,0]").is_err());
+}
+
+#[test]
+fn decoder() {
+    let decoder = Decoder::with_solver(8, KeyEquationSolver::Euclidean).fcr(1).prim(11);
+    let json = serde_json::to_string(&decoder).unwrap();
+    assert_eq!(r#"{"ecc_len":8,"solver":"Euclidean","max_corrections":8,"fcr":1,"prim":11}"#, json);
+
+    let restored: Decoder = serde_json::from_str(&json).unwrap();
+    assert_eq!(json, serde_json::to_string(&restored).unwrap());
+
+    let bad_prim = json.replace(r#""prim":11"#, r#""prim":5"#);
+    assert!(serde_json::from_str::<Decoder>(&bad_prim).is_err());
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_range_loop, clippy::unnecessary_mut_passed)]
+
 extern crate reed_solomon;
 
 use reed_solomon::Encoder;
@@ -5,16 +7,23 @@ use reed_solomon::Decoder;
 
 const ECC_LEN: usize = 8;
 
+/// Registers the field tables, which aren't embedded with `external_tables`
+fn init_tables() {
+    #[cfg(feature = "external_tables")]
+    reed_solomon::gf::external::init_generated_tables();
+}
+
 #[test]
 fn helloworld() {
+    init_tables();
     let data = b"Hello, World!";
 
     // Create encoder and decoder
-    let mut enc = Encoder::<{ECC_LEN + 1}>::new(ECC_LEN);
+    let mut enc = Encoder::<ECC_LEN>::new();
     let dec = Decoder::new(ECC_LEN);
 
     // Encode data
-    let encoded = enc.encode(&data[..]);
+    let encoded = enc.encode(&data[..]).unwrap();
 
     // Simulate some transmission errors
     let mut corrupted = Vec::new();
@@ -32,14 +41,15 @@ fn helloworld() {
 
 #[test]
 fn with_erasures() {
+    init_tables();
     let data = b"Hello, World!";
 
     // Create encoder and decoder
-    let mut enc = Encoder::<{ECC_LEN + 1}>::new(ECC_LEN);
+    let mut enc = Encoder::<ECC_LEN>::new();
     let dec = Decoder::new(ECC_LEN);
 
     // Encode data
-    let encoded = enc.encode(&data[..]);
+    let encoded = enc.encode(&data[..]).unwrap();
 
     // Simulate some transmission errors
     let mut corrupted = Vec::new();